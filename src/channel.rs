@@ -1,5 +1,9 @@
 //! Channel types.
 
+pub mod video;
+
+pub use self::video::Video;
+
 use crate::{
     youtube::{
         self, browse,
@@ -10,6 +14,8 @@ use crate::{
 
 define_id! {
     24,
+    Channel,
+    "ChannelId",
     "An Id describing a [`Channel`]",
     [
         "channel/",
@@ -26,14 +32,49 @@ impl Id {
     }
 }
 
+/// Resolve a channel `@handle`, `c/name` or `user/name` to its [`Id`].
+///
+/// Full urls of those forms and urls containing a [`Id`] are accepted as
+/// well.
+pub(crate) async fn resolve(client: &Client, value: &str) -> crate::Result<Id> {
+    if let Ok(id) = value.parse() {
+        return Ok(id);
+    }
+
+    let url = if reqwest::Url::parse(value).is_ok() {
+        value.to_string()
+    } else {
+        const DOMAINS: &[&str] = &["www.youtube.com/", "m.youtube.com/", "youtube.com/"];
+
+        let path = DOMAINS
+            .iter()
+            .find_map(|domain| value.strip_prefix(domain))
+            .unwrap_or(value);
+        format!("https://www.youtube.com/{}", path.trim_start_matches('/'))
+    };
+
+    let response = client.api.resolve_url(url.clone()).await?;
+
+    response
+        .browse_id()
+        .and_then(|id| id.parse().ok())
+        .ok_or_else(|| crate::error::Youtube::NotFound(url).into())
+}
+
 /// A badge that a [`Channel`] can have
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub enum Badge {
     /// A artist that is verified
     VerifiedArtist,
 
     /// A channel that is verified
     Verified,
+
+    /// A badge this library does not know yet, with its style as reported by
+    /// YouTube, e.g. `BADGE_STYLE_TYPE_MEMBERS_ONLY`
+    Unknown(String),
 }
 
 impl Badge {
@@ -41,7 +82,7 @@ impl Badge {
         match badge.metadata_badge_renderer.style.as_str() {
             "BADGE_STYLE_TYPE_VERIFIED_ARTIST" => Self::VerifiedArtist,
             "BADGE_STYLE_TYPE_VERIFIED" => Self::Verified,
-            badge => unimplemented!("Unknown badge: '{}'", badge),
+            badge => Self::Unknown(badge.to_string()),
         }
     }
 }
@@ -51,6 +92,7 @@ impl Badge {
 pub struct Channel {
     client: Client,
     response: browse::channel::about::Root,
+    fetched_at: chrono::DateTime<chrono::Utc>,
 }
 
 impl Channel {
@@ -65,7 +107,29 @@ impl Channel {
 
         let response = response.into_std()?;
 
-        Ok(Self { client, response })
+        Ok(Self {
+            client,
+            response,
+            fetched_at: chrono::Utc::now(),
+        })
+    }
+
+    /// Build a [`Channel`] from the JSON `browse` response of its about page,
+    /// fetched elsewhere.
+    ///
+    /// The [`Client`] is only used by accessors that request more data, e.g.
+    /// [`Channel::uploads`].
+    #[cfg(feature = "unstable")]
+    pub fn from_parts(response: &str, client: Client) -> crate::Result<Self> {
+        let response: browse::channel::about::Result =
+            client.api.deserialize("browse", response)?;
+        let response = response.into_std()?;
+
+        Ok(Self {
+            client,
+            response,
+            fetched_at: chrono::Utc::now(),
+        })
     }
 
     fn contents(&self) -> &browse::channel::about::ChannelAboutFullMetadataRenderer {
@@ -90,11 +154,41 @@ impl Channel {
         self.header().channel_id
     }
 
+    /// Refetch a channel in place, e.g. to update its
+    /// [`subscribers`](Self::subscribers) and [`views`](Self::views).
+    ///
+    /// Returns the channel as it was before the refresh, so callers can
+    /// compare the two to find out what changed.
+    pub async fn refresh(&mut self) -> crate::Result<Self> {
+        let fresh = Self::get(self.client.clone(), self.id()).await?;
+        Ok(std::mem::replace(self, fresh))
+    }
+
     /// The name of the channel
     pub fn name(&self) -> &str {
         &self.header().title
     }
 
+    /// The handle of the channel including the `@`, e.g. `@LinusTechTips`.
+    ///
+    /// [`None`] for channels without a handle.
+    pub fn handle(&self) -> Option<&str> {
+        browse::channel::handle(
+            self.header(),
+            &self.response.metadata.channel_metadata_renderer,
+        )
+    }
+
+    /// The canonical vanity url of the channel, e.g.
+    /// `http://www.youtube.com/@LinusTechTips`
+    pub fn vanity_url(&self) -> Option<&str> {
+        self.response
+            .metadata
+            .channel_metadata_renderer
+            .vanity_channel_url
+            .as_deref()
+    }
+
     /// The description of the channel
     pub fn description(&self) -> &str {
         &self.contents().description.simple_text
@@ -108,22 +202,45 @@ impl Channel {
             .map(|x| x.simple_text.as_str())
     }
 
+    /// The date this channel joined YouTube
+    pub fn published(&self) -> Option<chrono::NaiveDate> {
+        // ("Joined ", "<DATE>")
+        crate::parse::date(&self.contents().joined_date_text.runs.1.text)
+    }
+
+    /// When this channel was fetched from YouTube.
+    pub fn fetched_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.fetched_at
+    }
+
     /// The views that this channel received
-    pub fn views(&self) -> u64 {
+    ///
+    /// [`None`] if the channel hides its stats, e.g. for topic channels.
+    pub fn views(&self) -> Option<u64> {
         self.contents().views()
     }
 
     /// The amount of subscribers this channel has.
     ///
-    /// With [`None`] either being, no subscribers or viewing the subscriber
-    /// count is disallowed.
+    /// [`None`] if the channel hides its subscriber count or it could not be
+    /// parsed.
     pub fn subscribers(&self) -> Option<u64> {
         self.header().subscribers()
     }
 
-    /// The avatar of the channel in various sizes
+    /// The avatar of the channel in various sizes, from smallest to largest
     pub fn avatar(&self) -> impl Iterator<Item = &crate::Thumbnail> {
-        self.header().avatar.thumbnails.iter()
+        // Both the header and the About page have the avatar
+        let mut avatar: Vec<_> = self
+            .header()
+            .avatar
+            .thumbnails
+            .iter()
+            .chain(&self.contents().avatar.thumbnails)
+            .collect();
+        avatar.sort();
+        avatar.dedup();
+        avatar.into_iter()
     }
 
     /// The banner of the channel in various sizes
@@ -136,15 +253,66 @@ impl Channel {
         self.header().badges.iter().map(Badge::from)
     }
 
-    /// The uploads of a channel in a [`Playlist`](crate::Playlist)
-    pub async fn uploads(
-        &self,
-    ) -> crate::Result<
-        impl futures_core::Stream<Item = Result<crate::playlist::Video, crate::playlist::video::Error>>,
-    > {
+    /// The uploads of a channel in a [`Playlist`](crate::Playlist), see
+    /// [`Playlist::videos`](crate::Playlist::videos)
+    pub async fn uploads(&self) -> crate::Result<crate::playlist::Videos> {
         Ok(self.client.playlist(self.id().uploads()).await?.videos())
     }
 
+    /// The [`Videos`](Video) of the Videos tab of a channel, newest first.
+    ///
+    /// Unlike [`Channel::uploads`], this does not go through the uploads
+    /// playlist, so it also works for channels where it is disabled and
+    /// includes when each video was published. If requesting a page of
+    /// videos fails, its error is yielded and the stream ends.
+    pub fn videos(&self) -> impl futures_core::Stream<Item = crate::Result<Video>> {
+        let client = self.client.clone();
+        let id = self.id();
+
+        async_stream::stream! {
+            let response: crate::Result<browse::channel::videos::Root> = client
+                .api
+                .browse::<browse::channel::videos::Result>(Browse::Channel {
+                    id,
+                    page: ChannelPage::Videos,
+                })
+                .await
+                .and_then(|x| x.into_std());
+            let mut items: Box<dyn Iterator<Item = browse::channel::videos::Item> + Send + Sync> =
+                match response {
+                    Ok(response) => Box::new(response.contents().clone().into_items()),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                };
+
+            while let Some(item) = items.next() {
+                match item {
+                    browse::channel::videos::Item::RichItemRenderer(item) => {
+                        if let browse::channel::videos::RichItemContent::VideoRenderer(video) = item.content {
+                            yield Ok(Video::new(client.clone(), video));
+                        }
+                    }
+                    browse::channel::videos::Item::Other => continue,
+                    browse::channel::videos::Item::ContinuationItemRenderer(continuation) => {
+                        let response: crate::Result<browse::channel::videos::Continuation> = client
+                            .api
+                            .browse(Browse::Continuation(continuation.get()))
+                            .await;
+                        match response {
+                            Ok(response) => items = Box::new(response.into_items()),
+                            Err(err) => {
+                                yield Err(err);
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     // TODO: Playlist
     // TODO: Channels
 }
@@ -154,6 +322,7 @@ impl std::fmt::Debug for Channel {
         f.debug_struct("Channel")
             .field("id", &self.id())
             .field("name", &self.name())
+            .field("handle", &self.handle())
             .field("description", &self.description())
             .field("country", &self.country())
             .field("views", &self.views())
@@ -172,3 +341,129 @@ impl PartialEq for Channel {
 }
 
 impl Eq for Channel {}
+
+/// The header of a [`Channel`].
+///
+/// This is obtained with [`Client::channel_header`](crate::Client::channel_header)
+/// and only contains the information shown at the top of a channel page.
+/// Fetching it skips the About page, so the response is smaller and less
+/// likely to break when YouTube changes the About layout. Use
+/// [`Header::upgrade`] or [`Client::channel`](crate::Client::channel) if the
+/// description, country or views are needed.
+#[derive(Clone)]
+pub struct Header {
+    client: Client,
+    response: browse::channel::HeaderOnly,
+}
+
+impl Header {
+    pub(crate) async fn get(client: Client, id: Id) -> crate::Result<Self> {
+        let response: browse::channel::HeaderResult = client
+            .api
+            .browse(Browse::Channel {
+                id,
+                page: ChannelPage::Home,
+            })
+            .await?;
+
+        let response = response.into_std()?;
+
+        Ok(Self { client, response })
+    }
+
+    fn header(&self) -> &browse::channel::C4TabbedHeaderRenderer {
+        &self.response.header.c4_tabbed_header_renderer
+    }
+
+    /// The [`Id`] of a channel
+    pub fn id(&self) -> Id {
+        self.header().channel_id
+    }
+
+    /// The name of the channel
+    pub fn name(&self) -> &str {
+        &self.header().title
+    }
+
+    /// The handle of the channel including the `@`, e.g. `@LinusTechTips`.
+    ///
+    /// [`None`] for channels without a handle.
+    pub fn handle(&self) -> Option<&str> {
+        browse::channel::handle(
+            self.header(),
+            &self.response.metadata.channel_metadata_renderer,
+        )
+    }
+
+    /// The canonical vanity url of the channel, e.g.
+    /// `http://www.youtube.com/@LinusTechTips`
+    pub fn vanity_url(&self) -> Option<&str> {
+        self.response
+            .metadata
+            .channel_metadata_renderer
+            .vanity_channel_url
+            .as_deref()
+    }
+
+    /// The amount of subscribers this channel has.
+    ///
+    /// [`None`] if the channel hides its subscriber count or it could not be
+    /// parsed.
+    pub fn subscribers(&self) -> Option<u64> {
+        self.header().subscribers()
+    }
+
+    /// The avatar of the channel in various sizes
+    pub fn avatar(&self) -> impl Iterator<Item = &crate::Thumbnail> {
+        self.header().avatar.thumbnails.iter()
+    }
+
+    /// The banner of the channel in various sizes
+    pub fn banner(&self) -> impl Iterator<Item = &crate::Thumbnail> {
+        self.header().banner.thumbnails.iter()
+    }
+
+    /// The [`Badges`](Badge) of this channel.
+    pub fn badges(&self) -> impl Iterator<Item = Badge> + '_ {
+        self.header().badges.iter().map(Badge::from)
+    }
+
+    /// Is this channel marked as family safe?
+    pub fn family_safe(&self) -> bool {
+        self.response
+            .metadata
+            .channel_metadata_renderer
+            .is_family_safe
+    }
+
+    /// Refetch the channel to get more information
+    ///
+    /// To refetch the channels of many items, prefer
+    /// [`Client::channels_bulk`](crate::Client::channels_bulk).
+    pub async fn upgrade(&self) -> crate::Result<Channel> {
+        self.client.channel(self.id()).await
+    }
+}
+
+impl std::fmt::Debug for Header {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Header")
+            .field("id", &self.id())
+            .field("name", &self.name())
+            .field("handle", &self.handle())
+            .field("subscribers", &self.subscribers())
+            .field("avatar", &self.avatar().collect::<Vec<_>>())
+            .field("banner", &self.banner().collect::<Vec<_>>())
+            .field("badges", &self.badges().collect::<Vec<_>>())
+            .field("family_safe", &self.family_safe())
+            .finish()
+    }
+}
+
+impl PartialEq for Header {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for Header {}
@@ -0,0 +1,95 @@
+//! Channel videos
+
+use crate::{parse, youtube::browse, Client, Thumbnail};
+
+/// A Video of the Videos tab of a [`Channel`](super::Channel), see
+/// [`Channel::videos`](super::Channel::videos).
+///
+/// # Note
+///
+/// If more information about this video is required, use the
+/// [`Video::upgrade`](Video::upgrade) method.
+#[derive(Clone)]
+pub struct Video {
+    client: Client,
+    video: browse::channel::videos::VideoRenderer,
+}
+
+impl Video {
+    pub(super) fn new(client: Client, video: browse::channel::videos::VideoRenderer) -> Self {
+        Self { client, video }
+    }
+
+    /// The [`Id`](crate::video::Id) of a video.
+    pub fn id(&self) -> crate::video::Id {
+        self.video.video_id
+    }
+
+    /// The title of a video.
+    pub fn title(&self) -> &str {
+        &self.video.title.runs[0].text
+    }
+
+    /// The [`Thumbnails`](Thumbnail) of a video.
+    pub fn thumbnails(&self) -> &Vec<Thumbnail> {
+        &self.video.thumbnail.thumbnails
+    }
+
+    /// The view count of a video as shown by YouTube, e.g. `1,234 views`
+    /// or `1,234 watching` for livestreams.
+    pub fn view_count_text(&self) -> Option<String> {
+        self.video.view_count_text.as_ref().map(|x| x.text())
+    }
+
+    /// The amount of views of a video.
+    ///
+    /// [`None`] for livestreams, see [`Video::view_count_text`].
+    pub fn views(&self) -> Option<u64> {
+        parse::views(&self.view_count_text()?)
+    }
+
+    /// When a video was published relative to now, e.g. `3 days ago`.
+    ///
+    /// [`None`] for livestreams and upcoming videos.
+    pub fn published_time_text(&self) -> Option<&str> {
+        self.video.published_time_text.as_deref()
+    }
+
+    /// The length of a video.
+    ///
+    /// [`None`] for livestreams and upcoming videos.
+    pub fn length(&self) -> Option<std::time::Duration> {
+        self.video.length_text.as_deref().and_then(parse::length)
+    }
+
+    /// Refetch this video for more information.
+    pub async fn upgrade(&self) -> crate::Result<crate::Video> {
+        self.client.video(self.id()).await
+    }
+
+    /// Get the [`Streams`](crate::Stream) for this video.
+    pub async fn streams(&self) -> crate::Result<impl Iterator<Item = crate::Stream>> {
+        self.client.streams(self.id()).await
+    }
+}
+
+impl std::fmt::Debug for Video {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChannelVideo")
+            .field("id", &self.id())
+            .field("title", &self.title())
+            .field("thumbnails", &self.thumbnails())
+            .field("view_count_text", &self.view_count_text())
+            .field("published_time_text", &self.published_time_text())
+            .field("length", &self.length())
+            .finish()
+    }
+}
+
+impl PartialEq for Video {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for Video {}
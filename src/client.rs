@@ -1,3 +1,5 @@
+use std::collections::{HashMap, HashSet};
+
 use crate::{
     channel, playlist, stream, video, youtube::innertube::Api, Channel, Playlist, Stream, Video,
 };
@@ -7,9 +9,18 @@ use crate::{
 /// Note: This structure already uses an [`Arc`](std::sync::Arc) internally, so
 ///       it does not need to be wrapped again.
 #[allow(missing_debug_implementations)]
-#[derive(Clone, Default)]
+#[derive(Clone)]
 pub struct Client {
     pub(crate) api: Api,
+    pub(crate) id_mismatch: video::IdMismatch,
+    pub(crate) personal_playlists: bool,
+    pub(crate) stream_fallback: bool,
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        ClientBuilder::default().build()
+    }
 }
 
 impl Client {
@@ -18,23 +29,295 @@ impl Client {
         Self::default()
     }
 
+    /// Create a [`ClientBuilder`] to configure a [`Client`]
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::default()
+    }
+
     /// Get a [`Video`] identified by a [`Id`](video::Id)
     pub async fn video(&self, id: video::Id) -> crate::Result<Video> {
-        Video::get(self.clone(), id).await
+        Video::get(self.clone(), id, None).await
+    }
+
+    /// Get a [`Video`] identified by a [`Id`](video::Id) in the context of a
+    /// [`Playlist`] identified by a [`Id`](playlist::Id)
+    ///
+    /// The playlist is available afterwards with [`Video::playlist_context`].
+    pub async fn video_in_playlist(
+        &self,
+        id: video::Id,
+        playlist: playlist::Id,
+    ) -> crate::Result<Video> {
+        Video::get(self.clone(), id, Some(playlist)).await
+    }
+
+    /// Get a [`Video`] from a watch URL
+    ///
+    /// If the URL carries a `list=` parameter, the [`Video`] is fetched in the
+    /// context of that playlist, see [`Client::video_in_playlist`].
+    pub async fn video_from_url(&self, url: &str) -> crate::Result<Video> {
+        let (id, playlist) =
+            video::parse_url(url).ok_or_else(|| crate::Error::InvalidUrl(url.to_string()))?;
+        Video::get(self.clone(), id, playlist).await
+    }
+
+    /// Check whether a [`Video`] identified by a [`Id`](video::Id) exists and
+    /// is playable.
+    ///
+    /// This only requests and parses the playability of the video, which is
+    /// much cheaper than [`Client::video`].
+    pub async fn is_available(&self, id: video::Id) -> crate::Result<video::Availability> {
+        let status = self.api.playability(id).await?;
+
+        if status.is_playable() {
+            Ok(video::Availability::Playable)
+        } else {
+            Ok(video::Availability::Unplayable(status.as_error()))
+        }
+    }
+
+    /// Check the [availability](Client::is_available) of many [`Videos`](Video)
+    /// identified by their [`Ids`](video::Id)
+    ///
+    /// Duplicate ids are checked only once and at most `concurrency` videos
+    /// are checked at the same time. A `concurrency` of `0` is treated as `1`.
+    pub async fn are_available(
+        &self,
+        ids: impl IntoIterator<Item = video::Id>,
+        concurrency: usize,
+    ) -> HashMap<video::Id, crate::Result<video::Availability>> {
+        use futures_util::StreamExt;
+
+        let ids: HashSet<video::Id> = ids.into_iter().collect();
+
+        futures_util::stream::iter(ids)
+            .map(|id| async move { (id, self.is_available(id).await) })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await
     }
 
     /// Get the [`Stream`]s of a [`Video`] identified by a [`Id`](video::Id)
+    ///
+    /// This is empty for livestreams, see [`Client::hls_streams`].
     pub async fn streams(&self, id: video::Id) -> crate::Result<impl Iterator<Item = Stream>> {
         stream::get(self.clone(), id).await
     }
 
+    /// Get the [`Dash`](stream::Dash) representations of a [`Video`]
+    /// identified by a [`Id`](video::Id).
+    ///
+    /// This is empty for videos without a DASH manifest.
+    pub async fn dash_streams(&self, id: video::Id) -> crate::Result<Vec<stream::Dash>> {
+        stream::get_dash(self.clone(), id).await
+    }
+
+    /// Get the [`Hls`](stream::Hls) variants of a livestream identified by a
+    /// [`Id`](video::Id).
+    ///
+    /// Livestreams have no regular [`Stream`]s. This is empty for videos
+    /// that are not live.
+    pub async fn hls_streams(&self, id: video::Id) -> crate::Result<Vec<stream::Hls>> {
+        stream::get_hls(self.clone(), id).await
+    }
+
     /// Get a [`Playlist`] identified by a [`Id`](playlist::Id)
     pub async fn playlist(&self, id: playlist::Id) -> crate::Result<Playlist> {
         Playlist::get(self.clone(), id).await
     }
 
+    /// Get a [`Radio`](playlist::Radio) identified by a
+    /// [`Id`](playlist::Id), started from the video `seed`
+    ///
+    /// Radio and mix playlists, whose [`Id`](playlist::Id) starts with `RD`,
+    /// can not be requested with [`Client::playlist`].
+    pub async fn radio(&self, id: playlist::Id, seed: video::Id) -> crate::Result<playlist::Radio> {
+        playlist::Radio::get(self.clone(), id, seed).await
+    }
+
     /// Get a [`Channel`] identified by a [`Id`](channel::Id)
     pub async fn channel(&self, id: channel::Id) -> crate::Result<Channel> {
         Channel::get(self.clone(), id).await
     }
+
+    /// Resolve a channel `@handle`, `c/name` or `user/name` to its
+    /// [`Id`](channel::Id)
+    ///
+    /// Full urls like `https://www.youtube.com/@handle` are accepted as well.
+    /// Returns [`Youtube::NotFound`](crate::error::Youtube::NotFound) if no
+    /// channel is found.
+    pub async fn resolve_channel(&self, value: &str) -> crate::Result<channel::Id> {
+        channel::resolve(self, value).await
+    }
+
+    /// Get a [`Channel`] identified by its `@handle`, see
+    /// [`Client::resolve_channel`]
+    pub async fn channel_by_handle(&self, handle: &str) -> crate::Result<Channel> {
+        let id = self.resolve_channel(handle).await?;
+        self.channel(id).await
+    }
+
+    /// Get a [`Channel`] identified by a [`Id`](channel::Id) together with the
+    /// [`Playlist`] of its uploads
+    ///
+    /// Both are requested concurrently. If either request fails, the error is
+    /// wrapped in [`Error::Concurrent`](crate::Error::Concurrent) naming the
+    /// request.
+    pub async fn channel_with_uploads(
+        &self,
+        id: channel::Id,
+    ) -> crate::Result<(Channel, Playlist)> {
+        let concurrent = |request| {
+            move |err| crate::Error::Concurrent {
+                request,
+                source: Box::new(err),
+            }
+        };
+
+        futures_util::try_join!(
+            async { self.channel(id).await.map_err(concurrent("channel")) },
+            async {
+                self.playlist(id.uploads())
+                    .await
+                    .map_err(concurrent("uploads"))
+            },
+        )
+    }
+
+    /// Get many [`Channels`](Channel) identified by their [`Ids`](channel::Id)
+    ///
+    /// Duplicate ids are fetched only once and at most `concurrency` channels
+    /// are requested at the same time. Prefer this over calling `upgrade()`
+    /// on the channels of many items, e.g. of related videos, which sends one
+    /// request per item. A `concurrency` of `0` is treated as `1`.
+    pub async fn channels_bulk(
+        &self,
+        ids: impl IntoIterator<Item = channel::Id>,
+        concurrency: usize,
+    ) -> HashMap<channel::Id, crate::Result<Channel>> {
+        use futures_util::StreamExt;
+
+        let ids: HashSet<channel::Id> = ids.into_iter().collect();
+
+        futures_util::stream::iter(ids)
+            .map(|id| async move { (id, self.channel(id).await) })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Get only the [`Header`](channel::Header) of a [`Channel`] identified by
+    /// a [`Id`](channel::Id)
+    ///
+    /// This is cheaper than [`Client::channel`] but lacks the information
+    /// from the About page, see [`channel::Header`].
+    pub async fn channel_header(&self, id: channel::Id) -> crate::Result<channel::Header> {
+        channel::Header::get(self.clone(), id).await
+    }
+}
+
+/// A builder to configure a [`Client`]
+#[allow(missing_debug_implementations)]
+#[derive(Clone)]
+pub struct ClientBuilder {
+    api: Api,
+    id_mismatch: video::IdMismatch,
+    personal_playlists: bool,
+    stream_fallback: bool,
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        Self {
+            api: Api::default(),
+            id_mismatch: video::IdMismatch::default(),
+            personal_playlists: false,
+            stream_fallback: true,
+        }
+    }
+}
+
+impl ClientBuilder {
+    /// Register a callback that receives every renderer that is skipped,
+    /// because this library does not know it.
+    ///
+    /// The first argument describes where the renderer was found, the second
+    /// is its raw JSON. This is useful to notice when YouTube ships a new
+    /// renderer. Responses are deserialized more slowly while a callback is
+    /// registered, so it is off by default.
+    pub fn on_unknown_renderer(
+        mut self,
+        callback: impl Fn(&str, serde_json::Value) + Send + Sync + 'static,
+    ) -> Self {
+        self.api.on_unknown_renderer = Some(std::sync::Arc::new(callback));
+        self
+    }
+
+    /// Set how to handle YouTube answering for a different video than the
+    /// requested one. Defaults to [`IdMismatch::Follow`](video::IdMismatch::Follow).
+    pub fn id_mismatch(mut self, policy: video::IdMismatch) -> Self {
+        self.id_mismatch = policy;
+        self
+    }
+
+    /// Request personal playlists like Watch Later anyway, instead of failing
+    /// with [`Youtube::RequiresAuthentication`](crate::error::Youtube::RequiresAuthentication)
+    /// without a request. Defaults to `false`.
+    ///
+    /// Only useful if the requests are authenticated by other means, e.g.
+    /// a proxy adding cookies.
+    pub fn personal_playlists(mut self, personal_playlists: bool) -> Self {
+        self.personal_playlists = personal_playlists;
+        self
+    }
+
+    /// Check whether the [`Stream`]s YouTube returned are forbidden, and if
+    /// so request them again in the context of other YouTube clients.
+    /// Defaults to `true`.
+    ///
+    /// The urls YouTube returns for its Android app start answering with
+    /// `403 Forbidden` now and then. This costs one `HEAD` request per
+    /// request of [`Stream`]s. Which context produced working urls is
+    /// logged.
+    pub fn stream_fallback(mut self, stream_fallback: bool) -> Self {
+        self.stream_fallback = stream_fallback;
+        self
+    }
+
+    /// Set the timeout of a request to YouTube's internal API. Defaults to 30
+    /// seconds.
+    ///
+    /// Requests that time out are retried 5 times before failing with
+    /// [`Error::Timeout`](crate::Error::Timeout).
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.api.timeout = Some(timeout);
+        self
+    }
+
+    /// Send the requests to YouTube's internal API to `url` instead, e.g. a
+    /// mirror or a recording proxy. The endpoint is appended to `url`, like
+    /// `<url>/player`.
+    #[cfg(feature = "unstable")]
+    pub fn api_url(mut self, url: reqwest::Url) -> Self {
+        self.api.base_url = Some(url);
+        self
+    }
+
+    /// Send the requests to YouTube's website, e.g. to check whether a
+    /// [`Video`] is a Short, to `url` instead of `https://www.youtube.com/`.
+    #[cfg(feature = "unstable")]
+    pub fn web_url(mut self, url: reqwest::Url) -> Self {
+        self.api.web_url = Some(url);
+        self
+    }
+
+    /// Build the configured [`Client`]
+    pub fn build(self) -> Client {
+        Client {
+            api: self.api,
+            id_mismatch: self.id_mismatch,
+            personal_playlists: self.personal_playlists,
+            stream_fallback: self.stream_fallback,
+        }
+    }
 }
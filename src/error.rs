@@ -4,22 +4,133 @@
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     /// A Error that can occur when requesting web content
+    ///
+    /// The underlying error is shared, as identical requests that are in
+    /// flight at the same time are coalesced and report the same error.
     #[error("An Error occurred while requesting web content: {0}")]
-    Request(#[from] reqwest::Error),
+    Request(#[source] std::sync::Arc<reqwest::Error>),
 
     /// A Error reported by YouTube
     #[error(transparent)]
     Youtube(#[from] Youtube),
+
+    /// A request to YouTube timed out on every attempt
+    #[error("Request to '{endpoint}' timed out after {attempts} attempts")]
+    Timeout {
+        /// The endpoint that was requested
+        endpoint: &'static str,
+        /// The amount of attempts that were made
+        attempts: u32,
+        /// The error of the last attempt
+        source: std::sync::Arc<reqwest::Error>,
+    },
+
+    /// A response from YouTube could not be deserialized.
+    ///
+    /// This mostly means, that YouTube changed the layout of a response. Set
+    /// `YTEXTRACT_DUMP_ERR` at compile time to dump the offending response.
+    #[error("Failed to deserialize response from '{endpoint}' at '{path}': {source}")]
+    Deserialization {
+        /// The endpoint that produced the response
+        endpoint: &'static str,
+        /// The JSON path at which deserialization failed
+        path: String,
+        /// The underlying error
+        source: serde_json::Error,
+        /// Roughly 200 characters of the response around the error location
+        snippet: String,
+    },
+
+    /// YouTube returned a different video than requested
+    #[error("Requested video '{requested}' but YouTube returned '{returned}'")]
+    IdMismatch {
+        /// The requested [`Id`](crate::video::Id)
+        requested: crate::video::Id,
+        /// The returned [`Id`](crate::video::Id)
+        returned: crate::video::Id,
+    },
+
+    /// One of multiple concurrently issued requests failed
+    #[error("The request for the {request} failed: {source}")]
+    Concurrent {
+        /// What was requested, e.g. `channel` or `uploads`
+        request: &'static str,
+        /// The error of the failed request
+        source: Box<Error>,
+    },
+
+    /// A Error that occurred while writing a download to disk
+    #[error("An IO Error occurred: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// A [`Stream`](crate::Stream) can not be used
+    #[error(transparent)]
+    Stream(#[from] crate::stream::Error),
+
+    /// A caption track returned by YouTube could not be parsed
+    #[error("Failed to parse caption track: {0}")]
+    Caption(String),
+
+    /// A DASH manifest returned by YouTube could not be parsed
+    #[error("Failed to parse DASH manifest: {0}")]
+    Dash(String),
+
+    /// A URL did not contain a valid Id
+    #[error("Found no valid id in URL: '{0}'")]
+    InvalidUrl(String),
+
+    /// A [`Video`](crate::playlist::Video) of a [`Playlist`](crate::Playlist)
+    /// is unavailable
+    #[error(transparent)]
+    Unavailable(#[from] crate::playlist::video::Error),
+
+    /// A [`Channel`](crate::video::related::Channel) of a related item has no
+    /// [`Id`](crate::channel::Id) and cannot be upgraded
+    #[error("The channel of a related item has no id")]
+    MissingChannelId,
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        Self::Request(std::sync::Arc::new(err))
+    }
 }
 
 /// A Error reported by YouTube.
 #[derive(Debug, thiserror::Error, PartialEq, Eq, Clone)]
-pub struct Youtube(pub(crate) String);
+#[non_exhaustive]
+pub enum Youtube {
+    /// YouTube refused the request with a message, e.g. `Video unavailable`
+    #[error("{0}")]
+    Message(String),
 
-impl std::fmt::Display for Youtube {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", &self.0)
-    }
+    /// A [`Video`](crate::Video) has no transcript
+    #[error("No transcript is available for this video")]
+    TranscriptUnavailable,
+
+    /// A premiere or scheduled livestream has not started yet
+    #[error("This video is not available yet{}", starts_at.map(|x| format!(", it starts at {}", x)).unwrap_or_default())]
+    NotYetAvailable {
+        /// When the premiere or livestream is scheduled to start
+        starts_at: Option<chrono::DateTime<chrono::Utc>>,
+    },
+
+    /// A personal playlist, like Watch Later or Liked videos, was requested.
+    ///
+    /// These can only be viewed when signed in, which this library does not
+    /// support. See [`ClientBuilder::personal_playlists`](crate::ClientBuilder::personal_playlists)
+    /// to request them anyway.
+    #[error("Personal playlists like Watch Later or Liked videos require authentication")]
+    RequiresAuthentication,
+
+    /// A url, e.g. of a channel handle, did not resolve to anything
+    #[error("Nothing was found at '{0}'")]
+    NotFound(String),
+
+    /// YouTube redirected to the contained url instead of the requested page,
+    /// e.g. to its cookie consent page
+    #[error("Redirected to '{0}' instead of the requested page")]
+    UnexpectedRedirect(reqwest::Url),
 }
 
 /// The Error produced when a invalid Id is found
@@ -40,4 +151,37 @@ pub enum Id<const N: usize> {
     /// A Id was not the expected length
     #[error("Expected a id of length {N} but found a id of length {0}")]
     InvalidLength(usize),
+
+    /// A Id of another kind was found, e.g. a channel id where a video id
+    /// was expected
+    #[error("Expected a {expected} id but found a {found} id")]
+    WrongKind {
+        /// The kind of Id that was expected
+        expected: IdKind,
+        /// The kind of Id that was found
+        found: IdKind,
+    },
+}
+
+/// The kinds of Ids known to this library
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IdKind {
+    /// A [`video::Id`](crate::video::Id)
+    Video,
+    /// A [`channel::Id`](crate::channel::Id)
+    Channel,
+    /// A [`playlist::Id`](crate::playlist::Id)
+    Playlist,
+}
+
+impl std::fmt::Display for IdKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Video => "video",
+            Self::Channel => "channel",
+            Self::Playlist => "playlist",
+        }
+        .fmt(f)
+    }
 }
@@ -1,7 +1,9 @@
 //! A YouTube Id
 
+use crate::error::IdKind;
+
 macro_rules! define_id {
-    ($len:literal, $doc:literal, [$($prefix:literal),*,]) => {
+    ($len:literal, $kind:ident, $name:literal, $doc:literal, [$($prefix:literal),*,]) => {
         #[derive(Clone, Copy, PartialEq, Eq, Hash)]
         #[doc = $doc]
         pub struct Id([u8; $len]);
@@ -61,6 +63,30 @@ macro_rules! define_id {
             }
         }
 
+        #[cfg(feature = "schemars")]
+        impl schemars::JsonSchema for Id {
+            fn schema_name() -> String {
+                String::from($name)
+            }
+
+            fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+                schemars::schema::SchemaObject {
+                    instance_type: Some(schemars::schema::InstanceType::String.into()),
+                    metadata: Some(Box::new(schemars::schema::Metadata {
+                        description: Some(String::from($doc)),
+                        ..Default::default()
+                    })),
+                    string: Some(Box::new(schemars::schema::StringValidation {
+                        max_length: Some($len),
+                        min_length: Some($len),
+                        pattern: Some(String::from("^[0-9A-Za-z_-]*$")),
+                    })),
+                    ..Default::default()
+                }
+                .into()
+            }
+        }
+
         impl std::str::FromStr for Id {
             type Err = crate::error::Id<$len>;
 
@@ -79,6 +105,8 @@ macro_rules! define_id {
                     // correctness will be checked later.
                     .unwrap_or(value);
 
+                crate::id::check_kind(value, crate::error::IdKind::$kind)?;
+
                 value
                     .chars()
                     .all(crate::id::validate_char)
@@ -95,6 +123,52 @@ macro_rules! define_id {
     };
 }
 
+/// The prefixes of the different kinds of playlists
+pub const PLAYLIST_PREFIXES: &[&str] = &["PL", "RD", "UL", "UU", "PU", "OL", "LL", "FL", "WL"];
+
+/// Guess the kind of a Id from its shape, [`None`] if it has no known shape
+pub fn kind(value: &str) -> Option<IdKind> {
+    const URL_PREFIXES: &[&str] = &[
+        "watch?v=",
+        "embed/",
+        "shorts/",
+        "channel/",
+        "playlist?list=",
+    ];
+
+    let value = crate::youtube::strip_url_prefix(value);
+    let value = URL_PREFIXES
+        .iter()
+        .find_map(|prefix| value.strip_prefix(prefix))
+        .unwrap_or(value);
+
+    if value.is_empty() || !value.chars().all(validate_char) {
+        None
+    } else if value.len() == 24 && value.starts_with("UC") {
+        Some(IdKind::Channel)
+    } else if PLAYLIST_PREFIXES.iter().any(|x| value.starts_with(x)) {
+        Some(IdKind::Playlist)
+    } else if value.len() == 11 {
+        Some(IdKind::Video)
+    } else {
+        None
+    }
+}
+
+/// Fail with [`WrongKind`](crate::error::Id::WrongKind) if `value` has the
+/// shape of another kind of Id than `expected`
+pub fn check_kind<const N: usize>(
+    value: &str,
+    expected: IdKind,
+) -> Result<(), crate::error::Id<N>> {
+    match kind(value) {
+        // Video ids can start with a playlist prefix by chance
+        Some(IdKind::Playlist) if expected == IdKind::Video && value.len() == 11 => Ok(()),
+        Some(found) if found != expected => Err(crate::error::Id::WrongKind { expected, found }),
+        _ => Ok(()),
+    }
+}
+
 pub const fn validate_char(c: char) -> bool {
     matches!(c, '0'..='9' | 'a'..='z' | 'A'..='Z' | '_' | '-')
 }
@@ -1,4 +1,6 @@
 #![doc = include_str!("../README.md")]
+// The README indents its list items for GitHub
+#![allow(clippy::doc_overindented_list_items)]
 #![deny(
     missing_docs,
     unsafe_code,
@@ -12,18 +14,22 @@ pub(crate) mod id;
 pub mod channel;
 mod client;
 pub mod error;
+pub mod parse;
 pub mod playlist;
+#[cfg(feature = "serde")]
+pub mod snapshot;
 pub mod stream;
 mod thumbnail;
+pub mod types;
 pub mod video;
 pub(crate) mod youtube;
 
 pub use channel::Channel;
-pub use client::Client;
+pub use client::{Client, ClientBuilder};
 pub use error::Error;
 pub use playlist::Playlist;
 pub use stream::Stream;
-pub use thumbnail::Thumbnail;
+pub use thumbnail::{dedup_thumbnails, Thumbnail};
 pub use video::Video;
 
 /// The Result type used by this library
@@ -0,0 +1,261 @@
+//! Parsers for the human readable text YouTube shows.
+//!
+//! These are the parsers this library uses internally. They only understand
+//! the english texts YouTube returns for the `en` language, which this
+//! library always requests.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ytextract::parse;
+//!
+//! assert_eq!(parse::count("1.2M subscribers"), Some(1_200_000));
+//! assert_eq!(parse::length("3:25"), Some(std::time::Duration::from_secs(205)));
+//! ```
+
+use std::time::Duration;
+
+use chrono::{DateTime, Months, NaiveDate, Utc};
+
+/// Parse a possibly abbreviated count.
+///
+/// Only the first word of `value` is considered, so units like
+/// `subscribers` or `views` are ignored. Accepted forms:
+///
+/// - `1234`
+/// - `1,234`
+/// - `1.2K` (thousands)
+/// - `3M` (millions)
+/// - `1.23B` (billions)
+///
+/// The modifiers may be lowercase and abbreviated counts may use either `.`
+/// or `,` as decimal separator, e.g. `1,2M`. Without a modifier, `,` only
+/// groups thousands.
+///
+/// [`None`] if the first word is none of the above.
+pub fn count(value: &str) -> Option<u64> {
+    let value = value.split_whitespace().next()?;
+    let last = value.chars().last()?;
+    let mul = match last.to_ascii_uppercase() {
+        'K' => 1_000.0,
+        'M' => 1_000_000.0,
+        'B' => 1_000_000_000.0,
+        _ if last.is_ascii_digit() => return grouped(value),
+        _ => return None,
+    };
+
+    // The modifier is ASCII, so this does not split a character
+    let value = &value[..value.len() - 1];
+    let separators = value.chars().filter(|c| matches!(c, '.' | ',')).count();
+    let valid = value
+        .chars()
+        .all(|c| c.is_ascii_digit() || matches!(c, '.' | ','));
+    if value.is_empty() || !valid || separators > 1 {
+        return None;
+    }
+
+    let val: f64 = value.replace(',', ".").parse().ok()?;
+    Some((val * mul).round() as u64)
+}
+
+/// Parse a number whose thousands may be grouped by `,`, e.g. `1,234,567`.
+fn grouped(value: &str) -> Option<u64> {
+    let mut groups = value.split(',');
+    let first = groups.next()?;
+    let valid = if value.contains(',') {
+        (1..=3).contains(&first.len()) && groups.all(|x| x.len() == 3)
+    } else {
+        !first.is_empty()
+    };
+
+    if valid && value.chars().all(|c| c.is_ascii_digit() || c == ',') {
+        value.replace(',', "").parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Parse the first full, unabbreviated number in `value` in any locale, e.g.
+/// `4,457 likes`, `4.457 „Mag ich“` or `4 457 mentions J’aime`.
+///
+/// The thousands may be grouped by `,`, `.`, `'` or a (no-break) space.
+///
+/// [`None`] if `value` contains no number, its groups are malformed or it is
+/// abbreviated, e.g. `1.2M likes` or `1,2 Mio.`, which [`count`]
+/// understands.
+pub fn digits(value: &str) -> Option<u64> {
+    const SEPARATORS: &[char] = &[',', '.', '\'', ' ', '\u{a0}', '\u{202f}'];
+    const ABBREVIATIONS: &[&str] = &["K", "M", "B", "k", "Tsd.", "Mio.", "Mrd."];
+
+    let start = value.find(|c: char| c.is_ascii_digit())?;
+    let mut rest = &value[start..];
+    let mut groups = Vec::new();
+    loop {
+        let end = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        groups.push(&rest[..end]);
+        rest = &rest[end..];
+
+        // Another group follows if a separator is followed by a digit
+        let mut next = rest.chars();
+        match (next.next(), next.next()) {
+            (Some(separator), Some(digit))
+                if SEPARATORS.contains(&separator) && digit.is_ascii_digit() =>
+            {
+                rest = &rest[separator.len_utf8()..];
+            }
+            _ => break,
+        }
+    }
+
+    let grouped = match groups.split_first() {
+        Some((first, [])) => !first.is_empty(),
+        Some((first, others)) => {
+            (1..=3).contains(&first.len()) && others.iter().all(|x| x.len() == 3)
+        }
+        None => false,
+    };
+    let abbreviated = rest.starts_with(char::is_alphanumeric)
+        || rest
+            .split_whitespace()
+            .next()
+            .is_some_and(|x| ABBREVIATIONS.contains(&x));
+
+    if grouped && !abbreviated {
+        groups.concat().parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Parse a view count in the format `<N> views`, e.g. `1,234 views`,
+/// `1 view`, `1.2K views` or `No views`.
+///
+/// Unlike the other parsers, this also understands the german (`1.234
+/// Aufrufe`, `Keine Aufrufe`) and french (`1 234 vues`, `Aucune vue`)
+/// texts. The grouping separators `,`, `.` and non-breaking spaces are
+/// ignored.
+///
+/// [`None`] if `value` is not a view count.
+pub fn views(value: &str) -> Option<u64> {
+    const UNITS: &[&str] = &["view", "views", "Aufruf", "Aufrufe", "vue", "vues"];
+    const ZERO: &[&str] = &["No", "Keine", "Aucune"];
+
+    let (amount, unit) = value.trim().rsplit_once(' ')?;
+    if !UNITS.contains(&unit) {
+        return None;
+    }
+    if ZERO.contains(&amount) {
+        return Some(0);
+    }
+    if amount.ends_with(|c: char| matches!(c.to_ascii_uppercase(), 'K' | 'M' | 'B')) {
+        return count(amount);
+    }
+
+    let amount: String = amount
+        .chars()
+        .filter(|c| !matches!(c, ',' | '.' | ' ' | '\u{a0}' | '\u{202f}'))
+        .collect();
+    if amount.is_empty() || !amount.chars().all(|c| c.is_ascii_digit()) {
+        None
+    } else {
+        amount.parse().ok()
+    }
+}
+
+/// Parse a length in the format `[[HH:]MM:]SS`, e.g. `1:02:03`, `4:05` or
+/// `59`.
+///
+/// [`None`] if any of the parts is not a number.
+pub fn length(value: &str) -> Option<Duration> {
+    value
+        .split(':')
+        .rev()
+        .enumerate()
+        .try_fold(0, |acc, (i, s)| {
+            let s: u64 = s.trim().parse().ok()?;
+            Some(acc + s * 60u64.checked_pow(i as u32)?)
+        })
+        .map(Duration::from_secs)
+}
+
+/// Parse a date in the format `<MONTH> <DAY>, <YEAR>`, e.g. `Jun 22, 2021`.
+///
+/// The date may be prefixed by `Premiered ` or `Premieres `, as shown for
+/// premieres. The month is the abbreviated english month name.
+pub fn date(value: &str) -> Option<NaiveDate> {
+    const PREFIXES: &[&str] = &["Premiered ", "Premieres "];
+
+    let value = PREFIXES
+        .iter()
+        .find_map(|x| value.strip_prefix(x))
+        .unwrap_or(value)
+        .trim();
+
+    NaiveDate::parse_from_str(value, "%b %e, %Y").ok()
+}
+
+/// Parse the countdown of a upcoming premiere or livestream in the format
+/// `<PREFIX> in <AMOUNT> <UNIT>`, e.g. `Premieres in 35 minutes`.
+///
+/// `<PREFIX>` is usually `Premieres` or `Starts`, `<UNIT>` is one of `second`,
+/// `minute`, `hour`, `day` or `week`, optionally in plural.
+pub fn countdown(value: &str) -> Option<Duration> {
+    let (_, value) = value.split_once(" in ")?;
+
+    let mut words = value.split_whitespace();
+    let amount: u64 = words.next()?.parse().ok()?;
+    let unit = words.next()?;
+    if words.next().is_some() {
+        return None;
+    }
+
+    let seconds = match unit.strip_suffix('s').unwrap_or(unit) {
+        "second" => 1,
+        "minute" => 60,
+        "hour" => 60 * 60,
+        "day" => 24 * 60 * 60,
+        "week" => 7 * 24 * 60 * 60,
+        _ => return None,
+    };
+
+    amount.checked_mul(seconds).map(Duration::from_secs)
+}
+
+/// Parse a date relative to `now` in the format `<AMOUNT> <UNIT> ago`, e.g.
+/// `3 weeks ago`.
+///
+/// The text may be prefixed by `Streamed ` or `Premiered `. `<UNIT>` is one
+/// of `second`, `minute`, `hour`, `day`, `week`, `month` or `year`, optionally
+/// in plural.
+pub fn relative_date(value: &str, now: DateTime<Utc>) -> Option<NaiveDate> {
+    const PREFIXES: &[&str] = &["Streamed ", "Premiered "];
+
+    let value = PREFIXES
+        .iter()
+        .find_map(|x| value.strip_prefix(x))
+        .unwrap_or(value);
+
+    let mut words = value.split_whitespace();
+    let amount: u32 = words.next()?.parse().ok()?;
+    let unit = words.next()?;
+    if words.next() != Some("ago") || words.next().is_some() {
+        return None;
+    }
+
+    let seconds = |secs: u32| now.checked_sub_signed(chrono::Duration::seconds(secs.into()));
+
+    let date = match unit.strip_suffix('s').unwrap_or(unit) {
+        "second" => seconds(amount)?,
+        "minute" => seconds(amount.checked_mul(60)?)?,
+        "hour" => seconds(amount.checked_mul(60 * 60)?)?,
+        "day" => seconds(amount.checked_mul(24 * 60 * 60)?)?,
+        "week" => seconds(amount.checked_mul(7 * 24 * 60 * 60)?)?,
+        "month" => now.checked_sub_months(Months::new(amount))?,
+        "year" => now.checked_sub_months(Months::new(amount.checked_mul(12)?))?,
+        _ => return None,
+    };
+
+    Some(date.date_naive())
+}
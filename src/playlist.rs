@@ -1,7 +1,9 @@
 //! Playlists
 
+pub mod radio;
 pub mod video;
 
+pub use self::radio::Radio;
 pub use self::video::Video;
 
 use crate::{
@@ -17,67 +19,240 @@ use crate::{
 };
 
 /// A Id describing a Playlist.
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
 pub struct Id(pub(crate) String);
 
 impl std::str::FromStr for Id {
     type Err = crate::error::Id<0>;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        const ID_PREFIXES: &[&str] = &["PL", "RD", "UL", "UU", "PU", "OL", "LL", "FL", "WL"];
-
-        let id = youtube::strip_url_prefix(s)
-            .strip_prefix("playlist?list=")
+        // The allowed lengths of the kinds of playlists with a fixed length,
+        // including their prefix
+        const LENGTHS: &[(&str, &[usize])] = &[
+            ("PL", &[18, 34]),
+            ("UU", &[24, 26]),
+            ("PU", &[24]),
+            ("FL", &[24, 26]),
+            ("OLAK5uy_", &[41]),
+            ("WL", &[2]),
+        ];
+
+        // The `list` parameter of any URL, e.g. `playlist?list=<ID>` or
+        // `watch?v=<VIDEO>&list=<ID>` on `www.` or `music.youtube.com`
+        let id = s
+            .split_once('?')
+            .and_then(|(_, query)| query.split('&').find_map(|x| x.strip_prefix("list=")))
             .unwrap_or(s);
 
-        if id.chars().all(crate::id::validate_char)
-            && ID_PREFIXES.iter().any(|prefix| id.starts_with(prefix))
+        crate::id::check_kind(id, crate::error::IdKind::Playlist)?;
+
+        if !(id.chars().all(crate::id::validate_char)
+            && crate::id::PLAYLIST_PREFIXES
+                .iter()
+                .any(|prefix| id.starts_with(prefix)))
         {
-            Ok(Self(id.to_string()))
-        } else {
-            Err(crate::error::Id::InvalidId(s.to_string()))
+            return Err(crate::error::Id::InvalidId(s.to_string()));
+        }
+
+        match LENGTHS.iter().find(|(prefix, _)| id.starts_with(prefix)) {
+            Some((_, lengths)) if !lengths.contains(&id.len()) => {
+                Err(crate::error::Id::InvalidLength(id.len()))
+            }
+            _ => Ok(Self(id.to_string())),
         }
     }
 }
 
+impl<'de> serde::Deserialize<'de> for Id {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let id = String::deserialize(deserializer)?;
+        id.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl serde::Serialize for Id {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Id {
+    fn schema_name() -> String {
+        String::from("PlaylistId")
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some(String::from("A Id describing a Playlist.")),
+                ..Default::default()
+            })),
+            string: Some(Box::new(schemars::schema::StringValidation {
+                pattern: Some(String::from("^[0-9A-Za-z_-]*$")),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
 impl std::fmt::Display for Id {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         self.0.fmt(f)
     }
 }
 
+impl Id {
+    /// The [`PlaylistKind`] of a playlist, derived from its [`Id`]
+    pub fn kind(&self) -> PlaylistKind {
+        const PERSONAL_PREFIXES: &[&str] = &["WL", "LL", "FL"];
+
+        if PERSONAL_PREFIXES.iter().any(|x| self.0.starts_with(x)) {
+            PlaylistKind::RequiresAuth
+        } else {
+            PlaylistKind::Regular
+        }
+    }
+
+    /// The continuation token YouTube uses to page through the videos of a
+    /// playlist, starting at the 1-based `index`, see
+    /// [`Playlist::videos_from`].
+    pub(crate) fn continuation(&self, index: u64) -> String {
+        youtube::innertube::playlist_continuation(self, index.saturating_sub(1))
+    }
+}
+
+/// Extract the [`Id`] from the `list=` parameter of a URL, e.g. the
+/// `urlCanonical` of a playlist, which may be a `playlist`, `watch` or
+/// `music.youtube.com` URL.
+///
+/// Values that are not URLs are parsed as [`Id`] directly.
+pub(crate) fn parse_url(value: &str) -> Option<Id> {
+    let url = match reqwest::Url::parse(value) {
+        Ok(url) => url,
+        Err(_) => return value.parse().ok(),
+    };
+
+    url.query_pairs()
+        .find(|(key, _)| key == "list")
+        .and_then(|(_, id)| id.parse().ok())
+}
+
+/// The kind of a [`Playlist`], see [`Id::kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PlaylistKind {
+    /// A playlist that can be viewed without signing in
+    Regular,
+
+    /// A personal playlist of the signed in user, like `WL` (Watch Later),
+    /// `LL` (Liked videos) or `FL` (Favorites), which can not be viewed
+    /// without signing in
+    RequiresAuth,
+}
+
+/// When a [`Playlist`] was last updated, see [`Playlist::last_updated`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LastUpdated {
+    /// An absolute date, e.g. `Jan 5, 2023`
+    Date(chrono::NaiveDate),
+
+    /// A date YouTube did not give as absolute date, e.g. `today`,
+    /// `yesterday` or `3 days ago`
+    Relative(String),
+}
+
+/// Who can view a [`Playlist`], see [`Playlist::privacy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Privacy {
+    /// Anyone can find and view the playlist
+    Public,
+
+    /// Anyone with a link can view the playlist
+    Unlisted,
+
+    /// Only the owner can view the playlist, which requires signing in
+    Private,
+}
+
 /// A Playlist.
 #[derive(Clone)]
 pub struct Playlist {
     client: Client,
+    id: Id,
     response: browse::playlist::Ok,
+    fetched_at: chrono::DateTime<chrono::Utc>,
 }
 
 impl Playlist {
     pub(crate) async fn get(client: crate::Client, id: Id) -> crate::Result<Self> {
-        let response: browse::playlist::Result = client.api.browse(Browse::Playlist(id)).await?;
-        let response = response.into_std()?;
+        if id.kind() == PlaylistKind::RequiresAuth && !client.personal_playlists {
+            return Err(crate::error::Youtube::RequiresAuthentication.into());
+        }
+
+        let response: browse::playlist::Result =
+            client.api.browse(Browse::Playlist(id.clone())).await?;
+        Self::new(client, response.into_std()?, Some(id))
+    }
+
+    /// Build a [`Playlist`] from its JSON `browse` response, fetched
+    /// elsewhere.
+    ///
+    /// The [`Client`] is only used by accessors that request more data, e.g.
+    /// [`Playlist::videos`]. The [`Id`] is taken from the canonical url of the
+    /// response, so this fails with
+    /// [`Error::InvalidUrl`](crate::Error::InvalidUrl) if it has none.
+    #[cfg(feature = "unstable")]
+    pub fn from_parts(response: &str, client: Client) -> crate::Result<Self> {
+        let response: browse::playlist::Result = client.api.deserialize("browse", response)?;
+        Self::new(client, response.into_std()?, None)
+    }
 
-        Ok(Self { client, response })
+    /// `requested` is used if the canonical url of `response` has no [`Id`].
+    fn new(
+        client: Client,
+        response: browse::playlist::Ok,
+        requested: Option<Id>,
+    ) -> crate::Result<Self> {
+        let url = &response.microformat.microformat_data_renderer.url_canonical;
+        let id = parse_url(url)
+            .or(requested)
+            .ok_or_else(|| crate::Error::InvalidUrl(url.clone()))?;
+
+        Ok(Self {
+            client,
+            id,
+            response,
+            fetched_at: chrono::Utc::now(),
+        })
     }
 
     fn microformat(&self) -> &browse::playlist::MicroformatDataRenderer {
         &self.response.microformat.microformat_data_renderer
     }
 
-    fn primary_sidebar(&self) -> &PlaylistSidebarPrimaryInfoRenderer {
-        &self
-            .response
-            .sidebar
-            .playlist_sidebar_renderer
-            .items
-            .0
-            .playlist_sidebar_primary_info_renderer
+    fn primary_sidebar(&self) -> Option<&PlaylistSidebarPrimaryInfoRenderer> {
+        self.response.sidebar.as_ref().map(|x| {
+            &x.playlist_sidebar_renderer
+                .items
+                .0
+                .playlist_sidebar_primary_info_renderer
+        })
     }
 
     fn secondary_sidebar(&self) -> Option<&PlaylistSidebarSecondaryInfoRenderer> {
         self.response
             .sidebar
+            .as_ref()?
             .playlist_sidebar_renderer
             .items
             .1
@@ -87,12 +262,17 @@ impl Playlist {
 
     /// The [`Id`] of a playlist
     pub fn id(&self) -> Id {
-        self.microformat()
-            .url_canonical
-            .clone()
-            .split_off(37)
-            .parse()
-            .expect("Id returned from YouTube was not parsable")
+        self.id.clone()
+    }
+
+    /// Refetch a playlist in place, e.g. to update its
+    /// [`views`](Self::views) and [`length`](Self::length).
+    ///
+    /// Returns the playlist as it was before the refresh, so callers can
+    /// compare the two to find out what changed.
+    pub async fn refresh(&mut self) -> crate::Result<Self> {
+        let fresh = Self::get(self.client.clone(), self.id()).await?;
+        Ok(std::mem::replace(self, fresh))
     }
 
     /// The title of a playlist.
@@ -102,22 +282,81 @@ impl Playlist {
 
     /// The description of a playlist.
     pub fn description(&self) -> &str {
-        &self.microformat().description
+        let description = &self.microformat().description;
+        if description.is_empty() {
+            self.response
+                .header
+                .as_ref()
+                .and_then(|x| x.description())
+                .unwrap_or(description)
+        } else {
+            description
+        }
     }
 
     /// The name of the author of this playlist
     pub fn channel(&self) -> Option<Channel<'_>> {
-        let sec = &self.secondary_sidebar()?.video_owner.video_owner_renderer;
+        let owner = &self.secondary_sidebar()?.video_owner.video_owner_renderer;
+        let (name, id) = owner.owner()?;
         Some(Channel {
             client: &self.client,
-            id: sec.id(),
-            name: sec.name(),
+            id,
+            name,
+            subscribers: owner.subscribers(),
+            thumbnails: &owner.thumbnail.thumbnails,
         })
     }
 
+    /// The contributors of a collaborative playlist, as their names and the
+    /// [`Ids`](crate::channel::Id) of their channels.
+    ///
+    /// Empty if the playlist only has a single owner, see
+    /// [`Playlist::channel`].
+    pub fn contributors(&self) -> Vec<(&str, Option<crate::channel::Id>)> {
+        self.secondary_sidebar()
+            .map(|x| x.video_owner.video_owner_renderer.contributors())
+            .unwrap_or_default()
+    }
+
+    /// How often a playlist was saved, if YouTube shows it.
+    pub fn saves(&self) -> Option<u64> {
+        self.primary_sidebar()?.saves()
+    }
+
+    /// The date this playlist was last updated.
+    ///
+    /// Relative dates like `Updated 3 days ago` are resolved against
+    /// [`Playlist::fetched_at`].
+    pub fn updated(&self) -> Option<chrono::NaiveDate> {
+        self.primary_sidebar()?.updated(self.fetched_at)
+    }
+
+    /// When this playlist was last updated, as given by YouTube.
+    ///
+    /// Unlike [`Playlist::updated`], relative dates are kept as they are.
+    pub fn last_updated(&self) -> Option<LastUpdated> {
+        self.primary_sidebar()?.last_updated()
+    }
+
+    /// When this playlist was fetched from YouTube.
+    pub fn fetched_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.fetched_at
+    }
+
+    /// The [`Privacy`] of this playlist.
+    pub fn privacy(&self) -> Privacy {
+        match self.primary_sidebar().and_then(|x| x.privacy()) {
+            Some(privacy) => privacy,
+            None if self.microformat().unlisted => Privacy::Unlisted,
+            None => Privacy::Public,
+        }
+    }
+
     /// Is this playlist unlisted?
+    ///
+    /// Shorthand for comparing [`Playlist::privacy`] to [`Privacy::Unlisted`].
     pub fn unlisted(&self) -> bool {
-        self.microformat().unlisted
+        self.privacy() == Privacy::Unlisted
     }
 
     /// The [`Thumbnails`](Thumbnail) of a playlist.
@@ -125,43 +364,252 @@ impl Playlist {
         &self.microformat().thumbnail.thumbnails
     }
 
+    /// The [`Thumbnail`] to show for a playlist.
+    ///
+    /// This is the largest of the [`Thumbnails`](Self::thumbnails) of a
+    /// playlist, unless they are only [placeholders](Thumbnail::is_placeholder).
+    /// In that case the largest thumbnail of the first video is used instead.
+    pub fn display_thumbnail(&self) -> Option<&Thumbnail> {
+        fn largest<'a>(thumbnails: impl Iterator<Item = &'a Thumbnail>) -> Option<&'a Thumbnail> {
+            thumbnails.max()
+        }
+
+        largest(self.thumbnails().iter().filter(|x| !x.is_placeholder())).or_else(|| {
+            largest(
+                self.response
+                    .contents
+                    .first_video()?
+                    .thumbnail
+                    .thumbnails
+                    .iter(),
+            )
+        })
+    }
+
     /// The amount of views of a playlist
-    pub fn views(&self) -> u64 {
-        self.primary_sidebar().stats.1.as_number()
+    ///
+    /// [`None`] if YouTube shows no view count, e.g. for
+    /// [podcasts](Self::is_podcast), or it could not be parsed.
+    pub fn views(&self) -> Option<u64> {
+        self.primary_sidebar()?.views()
     }
 
     /// The amount of videos in a playlist
     pub fn length(&self) -> u64 {
-        self.primary_sidebar().stats.0.as_number()
+        self.reported_length().unwrap_or_default()
+    }
+
+    /// The amount of videos YouTube reported, [`None`] if it is not shown or
+    /// can not be parsed.
+    fn reported_length(&self) -> Option<u64> {
+        self.primary_sidebar()
+            .and_then(|x| x.videos())
+            .or_else(|| self.episode_count())
+    }
+
+    /// Is this playlist a podcast?
+    ///
+    /// The episodes of a podcast are available with [`Playlist::videos`] like
+    /// any other videos.
+    pub fn is_podcast(&self) -> bool {
+        self.response.contents.is_rich_grid()
+    }
+
+    /// The amount of episodes of a [podcast](Self::is_podcast).
+    ///
+    /// [`None`] if this is not a podcast or the count can not be parsed.
+    pub fn episode_count(&self) -> Option<u64> {
+        self.response.header.as_ref()?.episode_count()
     }
 
     /// The [`Videos`](Video) of a playlist.
-    pub fn videos(&self) -> impl futures_core::Stream<Item = Result<Video, video::Error>> {
-        let contents = self.response.contents.clone();
-        let client = self.client.clone();
-        async_stream::stream! {
-            let mut videos: Box<dyn Iterator<Item = browse::playlist::PlaylistItem> + Send + Sync> =
-                Box::new(contents.into_videos());
-
-            while let Some(video) = videos.next() {
-                match video {
+    ///
+    /// Unavailable videos are yielded as
+    /// [`Error::Unavailable`](crate::Error::Unavailable) and do not end the
+    /// stream. If requesting the next page of videos fails, its error is
+    /// yielded and the stream ends, so iterating can be retried.
+    pub fn videos(&self) -> Videos {
+        Videos {
+            stream: Box::pin(videos(
+                self.client.clone(),
+                Box::new(self.response.contents.clone().into_videos()),
+                None,
+            )),
+            expected: self.reported_length(),
+        }
+    }
+
+    /// The [`Videos`](Video) of a playlist, starting at the 1-based `index`,
+    /// see [`Video::index`].
+    ///
+    /// Unlike [`Playlist::videos`], this does not page through all videos
+    /// before `index`. As YouTube returns whole pages of videos, the first
+    /// yielded video may be slightly before `index`.
+    ///
+    /// The returned [`Videos`] have no [`expected length`](Videos::expected_len).
+    pub fn videos_from(&self, index: u64) -> Videos {
+        Videos {
+            stream: Box::pin(videos(
+                self.client.clone(),
+                Box::new(std::iter::empty()),
+                Some(self.id.continuation(index)),
+            )),
+            expected: None,
+        }
+    }
+
+    /// Is the video `id` in this playlist?
+    ///
+    /// [Unavailable](crate::Error::Unavailable) videos count as contained.
+    /// See [`Playlist::find_video`] for the paging behaviour.
+    pub async fn contains(&self, id: crate::video::Id) -> crate::Result<bool> {
+        use futures_util::StreamExt;
+
+        let mut videos = self.videos();
+        while let Some(video) = videos.next().await {
+            match video {
+                Ok(video) if video.id() == id => return Ok(true),
+                Err(crate::Error::Unavailable(err)) if err.id == id => return Ok(true),
+                Ok(_) | Err(crate::Error::Unavailable(_)) => continue,
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Find the available video `id` in this playlist.
+    ///
+    /// The [`videos`](Self::videos) are paged through until `id` is found,
+    /// so no further pages are requested after a match.
+    pub async fn find_video(&self, id: crate::video::Id) -> crate::Result<Option<Video>> {
+        use futures_util::StreamExt;
+
+        let mut videos = self.videos();
+        while let Some(video) = videos.next().await {
+            match video {
+                Ok(video) if video.id() == id => return Ok(Some(video)),
+                Ok(_) | Err(crate::Error::Unavailable(_)) => continue,
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// The [`Videos`](Video) of a playlist, [upgraded](Video::upgrade) to
+    /// full [`Videos`](crate::Video).
+    ///
+    /// At most `concurrency` videos are upgraded at the same time while the
+    /// playlist is still being fetched. The videos are yielded in playlist
+    /// order. Unavailable videos and failed upgrades are yielded as errors
+    /// and do not end the stream. A `concurrency` of `0` is treated as `1`.
+    pub fn videos_upgraded(
+        &self,
+        concurrency: usize,
+    ) -> impl futures_core::Stream<Item = crate::Result<crate::Video>> {
+        use futures_util::StreamExt;
+
+        self.videos()
+            .map(|video| async move { video?.upgrade().await })
+            .buffered(concurrency.max(1))
+    }
+}
+
+/// The [`Videos`](Video) of a [`Playlist`], see [`Playlist::videos`].
+///
+/// The amount of videos YouTube reported is available from
+/// [`Videos::expected_len`], e.g. to size a progress bar.
+pub struct Videos {
+    stream: std::pin::Pin<Box<dyn futures_core::Stream<Item = crate::Result<Video>> + Send>>,
+    expected: Option<u64>,
+}
+
+impl Videos {
+    /// The amount of videos YouTube reported for the whole [`Playlist`], see
+    /// [`Playlist::length`].
+    ///
+    /// [`None`] if not known, e.g. for [`Playlist::videos_from`] or if YouTube
+    /// shows no count. As YouTube counts hidden videos and the playlist can
+    /// grow while iterating, this is only a estimate of the videos yielded.
+    pub fn expected_len(&self) -> Option<u64> {
+        self.expected
+    }
+}
+
+impl futures_core::Stream for Videos {
+    type Item = crate::Result<Video>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        self.stream.as_mut().poll_next(cx)
+    }
+
+    /// Always `(0, None)`, as hidden videos are never yielded and videos added
+    /// while iterating are. Use [`Videos::expected_len`] for a estimate.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+impl std::fmt::Debug for Videos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Videos")
+            .field("expected_len", &self.expected)
+            .finish()
+    }
+}
+
+type PlaylistItems = Box<dyn Iterator<Item = browse::playlist::PlaylistItem> + Send + Sync>;
+
+/// Yield the [`Videos`](Video) of `items`, followed by the videos of
+/// `continuation` and all further pages
+fn videos(
+    client: Client,
+    mut items: PlaylistItems,
+    mut continuation: Option<String>,
+) -> impl futures_core::Stream<Item = crate::Result<Video>> {
+    async_stream::stream! {
+        loop {
+            if let Some(continuation) = continuation.take() {
+                let response: crate::Result<browse::playlist::Continuation> = client
+                    .api
+                    .browse(Browse::Continuation(continuation))
+                    .await;
+                match response {
+                    Ok(response) => items = Box::new(response.into_videos()),
+                    Err(err) => {
+                        yield Err(err);
+                        break;
+                    }
+                }
+            }
+
+            for item in items.by_ref() {
+                match item {
                     browse::playlist::PlaylistItem::PlaylistVideoRenderer(video) => {
-                        yield Video::new(client.clone(), video);
+                        yield Video::new(client.clone(), video).map_err(crate::Error::from);
                     }
-                    browse::playlist::PlaylistItem::ContinuationItemRenderer(continuation) => {
-                        assert!(
-                            videos.next().is_none(),
-                            "Found a continuation in the middle of videos!"
-                        );
-                        let response: browse::playlist::Continuation = client
-                            .api
-                            .browse(Browse::Continuation(continuation.get()))
-                            .await
-                            .expect("Continuation request failed");
-                        videos = Box::new(response.into_videos());
+                    browse::playlist::PlaylistItem::RichItemRenderer(item) => {
+                        if let browse::playlist::RichItemContent::PlaylistVideoRenderer(video) = item.content {
+                            yield Video::new(client.clone(), video).map_err(crate::Error::from);
+                        }
+                    }
+                    browse::playlist::PlaylistItem::Other => continue,
+                    browse::playlist::PlaylistItem::ContinuationItemRenderer(next) => {
+                        // Usually the last item, videos after it are still yielded
+                        if continuation.replace(next.get()).is_some() {
+                            log::warn!("Found multiple continuations in a page of videos, using the last");
+                        }
                     }
                 }
             }
+
+            if continuation.is_none() {
+                break;
+            }
         }
     }
 }
@@ -171,8 +619,10 @@ impl std::fmt::Debug for Playlist {
         f.debug_struct("Playlist")
             .field("title", &self.title())
             .field("description", &self.description())
-            .field("unlisted", &self.unlisted())
+            .field("privacy", &self.privacy())
             .field("thumbnails", &self.thumbnails())
+            .field("is_podcast", &self.is_podcast())
+            .field("episode_count", &self.episode_count())
             .finish()
     }
 }
@@ -191,6 +641,8 @@ pub struct Channel<'a> {
     client: &'a Client,
     id: crate::channel::Id,
     name: &'a str,
+    subscribers: Option<u64>,
+    thumbnails: &'a [Thumbnail],
 }
 
 impl<'a> Channel<'a> {
@@ -204,7 +656,20 @@ impl<'a> Channel<'a> {
         self.name
     }
 
+    /// The amount of subscribers a [`Channel`] has, if YouTube shows it.
+    pub fn subscribers(&self) -> Option<u64> {
+        self.subscribers
+    }
+
+    /// The [`Thumbnails`](Thumbnail) of a [`Channel`]
+    pub fn thumbnails(&self) -> impl Iterator<Item = &Thumbnail> {
+        self.thumbnails.iter()
+    }
+
     /// Refetch the channel to get more information
+    ///
+    /// To refetch the channels of many items, prefer
+    /// [`Client::channels_bulk`](crate::Client::channels_bulk).
     pub async fn upgrade(&self) -> crate::Result<crate::Channel> {
         self.client.channel(self.id).await
     }
@@ -215,6 +680,8 @@ impl<'a> std::fmt::Debug for Channel<'a> {
         f.debug_struct("Channel")
             .field("id", &self.id)
             .field("name", &self.name)
+            .field("subscribers", &self.subscribers)
+            .field("thumbnails", &self.thumbnails)
             .finish()
     }
 }
@@ -0,0 +1,226 @@
+//! Radio and mix playlists
+//!
+//! Radios, whose [`Id`](super::Id) starts with `RD`, are generated by
+//! YouTube from a seed video and can not be browsed like a regular
+//! [`Playlist`](super::Playlist). Instead they are expanded page by page
+//! while watching their videos.
+
+use crate::{
+    parse,
+    youtube::{innertube::Next, next},
+    Client, Thumbnail,
+};
+
+/// A radio or mix playlist, see [`Client::radio`](crate::Client::radio).
+#[derive(Clone)]
+pub struct Radio {
+    client: Client,
+    id: super::Id,
+    seed: crate::video::Id,
+    playlist: next::WatchPlaylistRenderer,
+}
+
+impl Radio {
+    pub(crate) async fn get(
+        client: Client,
+        id: super::Id,
+        seed: crate::video::Id,
+    ) -> crate::Result<Self> {
+        let playlist = page(&client, &id, seed).await?.ok_or_else(|| {
+            crate::error::Youtube::Message(format!(
+                "The playlist '{}' can not be played from '{}'",
+                id, seed
+            ))
+        })?;
+
+        Ok(Self {
+            client,
+            id,
+            seed,
+            playlist,
+        })
+    }
+
+    /// The [`Id`](super::Id) of a radio.
+    pub fn id(&self) -> super::Id {
+        self.id.clone()
+    }
+
+    /// The [`Id`](crate::video::Id) of the video this radio was started
+    /// from.
+    pub fn seed(&self) -> crate::video::Id {
+        self.seed
+    }
+
+    /// The title of a radio, e.g. `Mix - Artist`.
+    pub fn title(&self) -> Option<&str> {
+        self.playlist.title.as_deref()
+    }
+
+    /// The [`Videos`](Video) of a radio.
+    ///
+    /// More videos are requested starting at the last yielded video, until
+    /// YouTube returns no new ones. If requesting more videos fails, its
+    /// error is yielded and the stream ends.
+    pub fn videos(&self) -> impl futures_core::Stream<Item = crate::Result<Video>> {
+        let client = self.client.clone();
+        let id = self.id.clone();
+        let mut videos: Vec<_> = self.playlist.videos().cloned().collect();
+
+        async_stream::stream! {
+            let mut last_index = 0;
+            loop {
+                let mut last = None;
+                for video in videos {
+                    if video.index().is_some_and(|index| index <= last_index) {
+                        continue;
+                    }
+
+                    last = Some((video.video_id, video.index()));
+                    yield Ok(Video {
+                        client: client.clone(),
+                        video,
+                    });
+                }
+
+                // Without a index, new videos can not be told apart
+                let (seed, index) = match last {
+                    Some((seed, Some(index))) => (seed, index),
+                    _ => break,
+                };
+                last_index = index;
+
+                match page(&client, &id, seed).await {
+                    Ok(playlist) => {
+                        videos = playlist
+                            .map(|x| x.videos().cloned().collect())
+                            .unwrap_or_default();
+                    }
+                    Err(err) => {
+                        yield Err(err);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Request the videos of the radio `id` around `seed`
+async fn page(
+    client: &Client,
+    id: &super::Id,
+    seed: crate::video::Id,
+) -> crate::Result<Option<next::WatchPlaylistRenderer>> {
+    let response: next::Root = client
+        .api
+        .next(Next::Video {
+            id: seed,
+            playlist: Some(id.clone()),
+        })
+        .await?;
+
+    Ok(response.watch_playlist())
+}
+
+impl std::fmt::Debug for Radio {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Radio")
+            .field("id", &self.id)
+            .field("seed", &self.seed)
+            .field("title", &self.title())
+            .finish()
+    }
+}
+
+impl PartialEq for Radio {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.seed == other.seed
+    }
+}
+
+impl Eq for Radio {}
+
+/// A Video of a [`Radio`].
+///
+/// # Note
+///
+/// If more information about this video is required, use the
+/// [`Video::upgrade`](Video::upgrade) method.
+#[derive(Clone)]
+pub struct Video {
+    client: Client,
+    video: next::PlaylistPanelVideoRenderer,
+}
+
+impl Video {
+    /// The [`Id`](crate::video::Id) of a video.
+    pub fn id(&self) -> crate::video::Id {
+        self.video.video_id
+    }
+
+    /// The title of a video.
+    pub fn title(&self) -> String {
+        self.video.title.text()
+    }
+
+    /// The 1-based position of a video in its [`Radio`].
+    pub fn index(&self) -> Option<u64> {
+        self.video.index()
+    }
+
+    /// The length of a video.
+    ///
+    /// [`None`] for livestreams.
+    pub fn length(&self) -> Option<std::time::Duration> {
+        self.video.length_text.as_deref().and_then(parse::length)
+    }
+
+    /// The [`Thumbnails`](Thumbnail) of a video.
+    pub fn thumbnails(&self) -> &Vec<Thumbnail> {
+        &self.video.thumbnail.thumbnails
+    }
+
+    /// The author of a video.
+    pub fn channel(&self) -> Option<super::Channel<'_>> {
+        let short = self.video.short_byline_text.as_ref()?.runs.first()?;
+        Some(super::Channel {
+            client: &self.client,
+            id: short.navigation_endpoint.browse_endpoint.browse_id,
+            name: &short.text,
+            subscribers: None,
+            thumbnails: &[],
+        })
+    }
+
+    /// Refetch this video for more information.
+    pub async fn upgrade(&self) -> crate::Result<crate::Video> {
+        self.client.video(self.id()).await
+    }
+
+    /// Get the [`Streams`](crate::Stream) for this video.
+    pub async fn streams(&self) -> crate::Result<impl Iterator<Item = crate::Stream>> {
+        self.client.streams(self.id()).await
+    }
+}
+
+impl std::fmt::Debug for Video {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RadioVideo")
+            .field("id", &self.id())
+            .field("index", &self.index())
+            .field("title", &self.title())
+            .field("length", &self.length())
+            .field("thumbnails", &self.thumbnails())
+            .field("author", &self.channel())
+            .finish()
+    }
+}
+
+impl PartialEq for Video {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for Video {}
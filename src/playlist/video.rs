@@ -1,15 +1,18 @@
 //! Playlist videos
 
-use crate::{youtube::browse, Client, Thumbnail};
+use crate::{parse, youtube::browse, Client, Thumbnail};
 
 /// The reason as to why a [`Video`] is unavailable
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
 pub enum UnavailabilityReason {
     /// The [`Video`] was deleted
     Deleted,
     /// The [`Video`] was made private
     Private,
+    /// The [`Video`] is unavailable for a reason not known to ytextract,
+    /// with the title YouTube shows in its place
+    Unknown(String),
 }
 
 impl UnavailabilityReason {
@@ -17,20 +20,23 @@ impl UnavailabilityReason {
         match title.as_ref() {
             "[Deleted video]" => Self::Deleted,
             "[Private video]" => Self::Private,
-            unknown => unimplemented!("Unknown error title: '{}'", unknown),
+            unknown => Self::Unknown(unknown.to_string()),
         }
     }
 }
 
 /// A [`Error`](std::error::Error) that occurs when a [`Video`] in a
 /// [`Playlist`](super::Playlist) is unavailable
-#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
 #[error("Video with id '{id}' is unavailable with reason: '{reason:?}'")]
 pub struct Error {
     /// The [`Id`](crate::video::Id) of the unavailable [`Video`]
     pub id: crate::video::Id,
     /// The [`Reason`](UnavailabilityReason) why this [`Video`] is unavailable
     pub reason: UnavailabilityReason,
+    /// The 1-based position of the unavailable [`Video`] in the
+    /// [`Playlist`](super::Playlist), see [`Video::index`]
+    pub index: Option<u64>,
 }
 
 /// A Video of a [`Playlist`](super::Playlist).
@@ -52,9 +58,14 @@ impl Video {
     ) -> Result<Self, Error> {
         match video {
             browse::playlist::PlaylistVideoRenderer::Ok(video) => Ok(Self { client, video }),
-            browse::playlist::PlaylistVideoRenderer::Err { title, video_id } => Err(Error {
+            browse::playlist::PlaylistVideoRenderer::Err {
+                title,
+                video_id,
+                index,
+            } => Err(Error {
                 id: video_id,
                 reason: UnavailabilityReason::from_title(&title.runs[0].text),
+                index: index.and_then(|x| parse::digits(&x)),
             }),
         }
     }
@@ -69,11 +80,25 @@ impl Video {
         &self.video.title.runs[0].text
     }
 
+    /// The 1-based position of a video in its [`Playlist`](super::Playlist).
+    ///
+    /// Unavailable videos keep their position, so gaps show hidden videos.
+    pub fn index(&self) -> Option<u64> {
+        self.video.index.as_deref().and_then(parse::digits)
+    }
+
     /// The length of a video.
-    pub fn length(&self) -> std::time::Duration {
+    ///
+    /// [`None`] for [upcoming](Self::is_upcoming) premieres and livestreams.
+    pub fn length(&self) -> Option<std::time::Duration> {
         self.video.length_seconds
     }
 
+    /// Is this video a premiere or livestream that has not started yet?
+    pub fn is_upcoming(&self) -> bool {
+        self.video.is_upcoming()
+    }
+
     /// The [`Thumbnails`](Thumbnail) of a video.
     pub fn thumbnails(&self) -> &Vec<Thumbnail> {
         &self.video.thumbnail.thumbnails
@@ -86,6 +111,8 @@ impl Video {
             client: &self.client,
             id: short.navigation_endpoint.browse_endpoint.browse_id,
             name: &short.text,
+            subscribers: None,
+            thumbnails: &[],
         }
     }
 
@@ -104,8 +131,10 @@ impl std::fmt::Debug for Video {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("PlaylistVideo")
             .field("id", &self.id())
+            .field("index", &self.index())
             .field("title", &self.title())
             .field("length", &self.length())
+            .field("is_upcoming", &self.is_upcoming())
             .field("thumbnails", &self.thumbnails())
             .field("author", &self.channel())
             .finish()
@@ -0,0 +1,288 @@
+//! Plain data snapshots of [`Video`], [`Playlist`], [`Channel`] and
+//! [`Stream`].
+//!
+//! Unlike the types they are taken from, snapshots do not hold a
+//! [`Client`](crate::Client) and can not fetch more information. They are
+//! meant to be stored, e.g. in a cache, and implement
+//! [`Serialize`](serde::Serialize) and [`Deserialize`](serde::Deserialize).
+//!
+//! Only available with the `serde` feature enabled.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = ytextract::Client::new();
+//!
+//! let video = client.video("nI2e-J6fsuk".parse()?).await?;
+//!
+//! let json = serde_json::to_string(&video.snapshot())?;
+//! let snapshot: ytextract::snapshot::VideoSnapshot = serde_json::from_str(&json)?;
+//!
+//! assert_eq!(snapshot.id, video.id());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::Duration;
+
+use crate::{channel::Badge, Channel, Playlist, Stream, Thumbnail, Video};
+
+/// A snapshot of a [`Video`], see [`Video::snapshot`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct VideoSnapshot {
+    /// The [`Id`](crate::video::Id) of the video
+    pub id: crate::video::Id,
+    /// The title of the video
+    pub title: String,
+    /// The description of the video
+    pub description: String,
+    /// The length of the video, [`None`] for livestreams
+    pub duration: Option<Duration>,
+    /// The keywords of the video
+    pub keywords: Vec<String>,
+    /// The channel that uploaded the video
+    pub channel: ChannelInfo,
+    /// The amount of views of the video
+    pub views: u64,
+    /// The amount of likes of the video, [`None`] if they are hidden
+    pub likes: Option<u64>,
+    /// When the video was published, if known
+    pub date: Option<chrono::NaiveDate>,
+    /// When the video was uploaded
+    pub upload_date: Option<chrono::NaiveDate>,
+    /// The category of the video, e.g. `Music`
+    pub category: Option<String>,
+    /// Is the video a livestream?
+    pub live: bool,
+    /// Is the video family safe?
+    pub family_safe: Option<bool>,
+    /// The thumbnails of the video
+    pub thumbnails: Vec<Thumbnail>,
+    /// When the video was fetched from YouTube
+    pub fetched_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// The channel of a [`VideoSnapshot`] or [`PlaylistSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ChannelInfo {
+    /// The [`Id`](crate::channel::Id) of the channel
+    pub id: crate::channel::Id,
+    /// The name of the channel
+    pub name: String,
+    /// The amount of subscribers of the channel, if known
+    pub subscribers: Option<u64>,
+}
+
+/// A snapshot of a [`Playlist`], see [`Playlist::snapshot`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PlaylistSnapshot {
+    /// The [`Id`](crate::playlist::Id) of the playlist
+    pub id: crate::playlist::Id,
+    /// The title of the playlist
+    pub title: String,
+    /// The description of the playlist
+    pub description: String,
+    /// The channel that created the playlist, [`None`] for auto-generated
+    /// playlists
+    pub channel: Option<ChannelInfo>,
+    /// Is the playlist unlisted?
+    pub unlisted: bool,
+    /// The amount of views of the playlist, if known
+    pub views: Option<u64>,
+    /// The amount of videos in the playlist
+    pub length: u64,
+    /// When the playlist was last updated
+    pub updated: Option<chrono::NaiveDate>,
+    /// The thumbnails of the playlist
+    pub thumbnails: Vec<Thumbnail>,
+    /// When the playlist was fetched from YouTube
+    pub fetched_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// A snapshot of a [`Channel`], see [`Channel::snapshot`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ChannelSnapshot {
+    /// The [`Id`](crate::channel::Id) of the channel
+    pub id: crate::channel::Id,
+    /// The name of the channel
+    pub name: String,
+    /// The description of the channel
+    pub description: String,
+    /// The country of the channel
+    pub country: Option<String>,
+    /// The amount of views of the channel, if known
+    pub views: Option<u64>,
+    /// The amount of subscribers of the channel, if known
+    pub subscribers: Option<u64>,
+    /// The badges of the channel
+    pub badges: Vec<Badge>,
+    /// The avatar of the channel
+    pub avatar: Vec<Thumbnail>,
+    /// The banner of the channel
+    pub banner: Vec<Thumbnail>,
+    /// When the channel joined YouTube
+    pub published: Option<chrono::NaiveDate>,
+    /// When the channel was fetched from YouTube
+    pub fetched_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// A snapshot of a [`Stream`], see [`Stream::snapshot`].
+///
+/// The [`url`](Self::url) expires after a few hours, see
+/// [`Stream::expires_at`](crate::stream::Common::expires_at).
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct StreamSnapshot {
+    /// The url of the stream
+    pub url: String,
+    /// The mime type of the stream
+    pub mime_type: String,
+    /// The bitrate of the stream
+    pub bitrate: u64,
+    /// The length of the stream
+    pub duration: Option<Duration>,
+    /// The kind specific information of the stream
+    pub kind: StreamKind,
+}
+
+/// The kind of a [`StreamSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum StreamKind {
+    /// A stream exclusively containing audio
+    Audio {
+        /// The sample rate of the audio
+        sample_rate: u64,
+        /// The amount of audio channels
+        channels: u64,
+    },
+    /// A stream exclusively containing video
+    Video {
+        /// The width of the video
+        width: u64,
+        /// The height of the video
+        height: u64,
+        /// The frames per second of the video
+        fps: u64,
+    },
+    /// A stream containing both video and audio
+    Muxed {
+        /// The width of the video
+        width: u64,
+        /// The height of the video
+        height: u64,
+        /// The frames per second of the video
+        fps: u64,
+        /// The sample rate of the audio
+        sample_rate: u64,
+        /// The amount of audio channels
+        channels: u64,
+    },
+}
+
+impl Video {
+    /// Take a [`VideoSnapshot`] of this video.
+    pub fn snapshot(&self) -> VideoSnapshot {
+        let channel = self.channel();
+
+        VideoSnapshot {
+            id: self.id(),
+            title: self.title().to_string(),
+            description: self.description().to_string(),
+            duration: self.duration(),
+            keywords: self.keywords().clone(),
+            channel: ChannelInfo {
+                id: channel.id(),
+                name: channel.name().to_string(),
+                subscribers: channel.subscribers(),
+            },
+            views: self.views(),
+            likes: self.likes(),
+            date: self.date(),
+            upload_date: self.upload_date(),
+            category: self.category().map(ToString::to_string),
+            live: self.live(),
+            family_safe: self.family_safe(),
+            thumbnails: self.thumbnails().clone(),
+            fetched_at: self.fetched_at(),
+        }
+    }
+}
+
+impl Playlist {
+    /// Take a [`PlaylistSnapshot`] of this playlist.
+    pub fn snapshot(&self) -> PlaylistSnapshot {
+        PlaylistSnapshot {
+            id: self.id(),
+            title: self.title().to_string(),
+            description: self.description().to_string(),
+            channel: self.channel().map(|channel| ChannelInfo {
+                id: channel.id(),
+                name: channel.name().to_string(),
+                subscribers: channel.subscribers(),
+            }),
+            unlisted: self.unlisted(),
+            views: self.views(),
+            length: self.length(),
+            updated: self.updated(),
+            thumbnails: self.thumbnails().clone(),
+            fetched_at: self.fetched_at(),
+        }
+    }
+}
+
+impl Channel {
+    /// Take a [`ChannelSnapshot`] of this channel.
+    pub fn snapshot(&self) -> ChannelSnapshot {
+        ChannelSnapshot {
+            id: self.id(),
+            name: self.name().to_string(),
+            description: self.description().to_string(),
+            country: self.country().map(ToString::to_string),
+            views: self.views(),
+            subscribers: self.subscribers(),
+            badges: self.badges().collect(),
+            avatar: self.avatar().cloned().collect(),
+            banner: self.banner().cloned().collect(),
+            published: self.published(),
+            fetched_at: self.fetched_at(),
+        }
+    }
+}
+
+impl Stream {
+    /// Take a [`StreamSnapshot`] of this stream.
+    pub fn snapshot(&self) -> StreamSnapshot {
+        let kind = match self {
+            Stream::Audio(audio) => StreamKind::Audio {
+                sample_rate: audio.sample_rate(),
+                channels: audio.channels(),
+            },
+            Stream::Video(video) => StreamKind::Video {
+                width: video.width(),
+                height: video.height(),
+                fps: video.fps(),
+            },
+            Stream::Muxed(muxed) => StreamKind::Muxed {
+                width: muxed.width(),
+                height: muxed.height(),
+                fps: muxed.fps(),
+                sample_rate: muxed.sample_rate(),
+                channels: muxed.channels(),
+            },
+        };
+
+        StreamSnapshot {
+            url: self.url().to_string(),
+            mime_type: self.mime_type().to_string(),
+            bitrate: self.bitrate(),
+            duration: self.duration(),
+            kind,
+        }
+    }
+}
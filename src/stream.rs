@@ -18,27 +18,287 @@
 
 mod audio;
 mod common;
+mod dash;
+mod download;
+mod hls;
+mod mime;
+mod muxed;
+mod select;
 mod video;
 
-pub use self::audio::Stream as Audio;
-pub use self::common::Stream as Common;
-pub use self::video::Stream as Video;
-use crate::{youtube::player_response::FormatType, Client};
+pub use self::audio::{AudioQuality, AudioTrack, Stream as Audio};
+pub use self::common::{ByteRange, Stream as Common};
+pub(crate) use self::dash::get as dash_representations;
+pub use self::dash::Representation as Dash;
+pub use self::download::{DownloadOptions, DownloadProgress};
+pub(crate) use self::hls::get as hls_variants;
+pub use self::hls::Variant as Hls;
+pub use self::mime::{Codec, Container};
+pub use self::muxed::Stream as Muxed;
+pub use self::select::Selection;
+pub use self::video::{ColorInfo, Projection, StereoLayout, Stream as Video, VideoQuality};
+use crate::{
+    youtube::{
+        innertube::StreamFallback,
+        player_response::{FormatType, StreamingData},
+    },
+    Client,
+};
 
 pub(crate) async fn get(
     client: Client,
     id: crate::video::Id,
 ) -> crate::Result<impl Iterator<Item = Stream>> {
+    let streaming_data = fetch(client.clone(), id).await?;
+    Ok(from_streaming_data(streaming_data, id, client))
+}
+
+pub(crate) async fn get_dash(client: Client, id: crate::video::Id) -> crate::Result<Vec<Dash>> {
+    let streaming_data = fetch(client.clone(), id).await?;
+    dash_representations(&client, &streaming_data).await
+}
+
+pub(crate) async fn get_hls(client: Client, id: crate::video::Id) -> crate::Result<Vec<Hls>> {
+    let streaming_data = fetch(client.clone(), id).await?;
+    hls_variants(&client, &streaming_data).await
+}
+
+/// Request the [`StreamingData`] of a video
+pub(crate) async fn fetch(client: Client, id: crate::video::Id) -> crate::Result<StreamingData> {
     let player_response = client.api.streams(id).await?;
 
-    // TODO: DashManifest/HlsManifest
-    Ok(player_response
-        .streaming_data
-        .adaptive_formats
+    if let Some(details) = &player_response.video_details {
+        client.id_mismatch.check(id, details.video_id)?;
+    }
+
+    Ok(working(&client, id, player_response.streaming_data).await)
+}
+
+/// Replace `streaming_data` with that of another context if its streams are
+/// forbidden, unless disabled with
+/// [`ClientBuilder::stream_fallback`](crate::ClientBuilder::stream_fallback).
+pub(crate) async fn working(
+    client: &Client,
+    id: crate::video::Id,
+    streaming_data: StreamingData,
+) -> StreamingData {
+    if client.stream_fallback && is_forbidden(client, id, &streaming_data).await {
+        fallback(client, id, streaming_data).await
+    } else {
+        streaming_data
+    }
+}
+
+/// Does YouTube answer a `HEAD` of the first stream with a 403?
+///
+/// Other errors are left to the actual requests of the streams.
+async fn is_forbidden(
+    client: &Client,
+    id: crate::video::Id,
+    streaming_data: &StreamingData,
+) -> bool {
+    let format = match streaming_data
+        .formats
+        .iter()
+        .chain(&streaming_data.adaptive_formats)
+        .next()
+    {
+        Some(format) => format.clone(),
+        None => return false,
+    };
+
+    let stream = Stream::new(format, id, client.clone());
+    match stream
+        .request(reqwest::Method::HEAD, stream.url())
+        .send()
+        .await
+    {
+        Ok(res) => res.status() == reqwest::StatusCode::FORBIDDEN,
+        Err(_) => false,
+    }
+}
+
+/// Request the streams with the other contexts, until one returns streams
+/// that are not forbidden.
+///
+/// The manifests of `original` are kept if the working context has none.
+async fn fallback(client: &Client, id: crate::video::Id, original: StreamingData) -> StreamingData {
+    for fallback in StreamFallback::ALL {
+        log::warn!(
+            "Streams of '{}' are forbidden, retrying with the {} context",
+            id,
+            fallback.name()
+        );
+
+        let response = client
+            .api
+            .streams_fallback(id, fallback)
+            .await
+            .and_then(|response| match &response.video_details {
+                Some(details) => client
+                    .id_mismatch
+                    .check(id, details.video_id)
+                    .map(|_| response),
+                None => Ok(response),
+            });
+        let streaming_data = match response {
+            Ok(response) => response.streaming_data,
+            Err(err) => {
+                log::warn!(
+                    "Requesting the streams with the {} context failed: {}",
+                    fallback.name(),
+                    err
+                );
+                continue;
+            }
+        };
+
+        let empty = streaming_data.formats.is_empty() && streaming_data.adaptive_formats.is_empty();
+        if !empty && !is_forbidden(client, id, &streaming_data).await {
+            log::info!(
+                "Streams of '{}' work with the {} context",
+                id,
+                fallback.name()
+            );
+            return StreamingData {
+                hls_manifest_url: streaming_data
+                    .hls_manifest_url
+                    .or(original.hls_manifest_url),
+                dash_manifest_url: streaming_data
+                    .dash_manifest_url
+                    .or(original.dash_manifest_url),
+                ..streaming_data
+            };
+        }
+    }
+
+    log::warn!("No context produced working streams for '{}'", id);
+    original
+}
+
+pub(crate) fn from_streaming_data(
+    streaming_data: StreamingData,
+    id: crate::video::Id,
+    client: Client,
+) -> impl Iterator<Item = Stream> {
+    streaming_data
+        .formats
         .into_iter()
-        .map(move |stream| Stream::new(stream, client.clone())))
+        .chain(streaming_data.adaptive_formats)
+        .map(move |stream| Stream::new(stream, id, client.clone()))
 }
 
+/// A [`Error`](std::error::Error) that occurs when using a [`Stream`]
+#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The [`Url`](Common::url) of a [`Stream`] expired at the contained
+    /// time. The streams have to be requested again.
+    #[error("The stream url expired at {0}, request the streams again")]
+    Expired(chrono::DateTime<chrono::Utc>),
+
+    /// A [`Stream`] could not be [`refreshed`](Stream::refresh), as its video
+    /// no longer has a stream with its itag
+    #[error("The video no longer has a stream with itag {itag}, available itags: {available:?}")]
+    MissingItag {
+        /// The itag of the [`Stream`]
+        itag: u64,
+        /// The itags of the streams the video has now
+        available: Vec<u64>,
+    },
+
+    /// The length of a [`Stream`] is neither known from its format nor sent
+    /// by the server
+    #[error("The length of the stream is unknown")]
+    UnknownContentLength,
+
+    /// The server sent more bytes than were requested, e.g. as it ignored the
+    /// requested range
+    #[error("Received more than the {expected} bytes of the requested range")]
+    RangeExceeded {
+        /// The length of the requested range
+        expected: u64,
+    },
+}
+
+/// Filters for [`Iterators`](Iterator) of [`Streams`](Stream), e.g. the one
+/// returned by [`Client::streams`](crate::Client::streams).
+///
+/// # Example
+///
+/// ```rust
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use ytextract::stream::{Codec, Container, StreamsExt};
+///
+/// let client = ytextract::Client::new();
+///
+/// let stream = client
+///     .streams("nI2e-J6fsuk".parse()?)
+///     .await?
+///     .select()
+///     .container(Container::Mp4)
+///     .max_height(1080)
+///     .prefer_codec(Codec::Avc1)
+///     .best();
+///
+/// assert!(stream.is_some());
+/// # Ok(())
+/// # }
+/// ```
+pub trait StreamsExt: Iterator<Item = Stream> + Sized {
+    /// The best [`Video`] stream.
+    ///
+    /// Streams are ranked by their [`VideoQuality`], then their frames per
+    /// second and then their bitrate.
+    fn best_video(self) -> Option<Video> {
+        self.filter(Stream::is_video)
+            .max_by_key(select::rank)
+            .and_then(Stream::into_video)
+    }
+
+    /// The best [`Audio`] stream.
+    ///
+    /// Streams are ranked by their [`AudioQuality`] and then their bitrate.
+    fn best_audio(self) -> Option<Audio> {
+        self.filter_map(Stream::into_audio)
+            .max_by_key(|x| (x.quality(), x.bitrate()))
+    }
+
+    /// The worst [`Audio`] stream, ranked like in
+    /// [`best_audio`](Self::best_audio).
+    fn worst_audio(self) -> Option<Audio> {
+        self.filter_map(Stream::into_audio)
+            .min_by_key(|x| (x.quality(), x.bitrate()))
+    }
+
+    /// Select the best stream matching some criteria, see [`Selection`].
+    fn select(self) -> Selection<Self> {
+        Selection::new(self)
+    }
+
+    /// Only keep the [`Audio`] streams of the [`AudioTrack`] in `language`,
+    /// e.g. `en`, together with all [`Video`] streams.
+    ///
+    /// A region is ignored unless `language` has one, so `en` matches
+    /// `en-US`. [`Audio`] streams without an [`AudioTrack`] are kept, as
+    /// the video only has a single audio track.
+    fn audio_language(self, language: &str) -> impl Iterator<Item = Stream> {
+        let language = language.to_string();
+        self.filter(move |stream| match stream {
+            Stream::Audio(audio) => audio.track().is_none_or(|track| {
+                let code = track.language();
+                code.eq_ignore_ascii_case(&language)
+                    || code
+                        .split_once('-')
+                        .is_some_and(|(code, _)| code.eq_ignore_ascii_case(&language))
+            }),
+            Stream::Video(_) | Stream::Muxed(_) => true,
+        })
+    }
+}
+
+impl<I: Iterator<Item = Stream>> StreamsExt for I {}
+
 /// A Stream of a YouTube video
 #[derive(Clone)]
 pub enum Stream {
@@ -46,14 +306,21 @@ pub enum Stream {
     Audio(Audio),
     /// A Stream exclusively containing [`Video`] data
     Video(Video),
+    /// A Stream containing both video and audio data, see [`Muxed`]
+    Muxed(Muxed),
 }
 
 impl Stream {
-    pub(crate) fn new(format: crate::youtube::player_response::Format, client: Client) -> Self {
+    pub(crate) fn new(
+        format: crate::youtube::player_response::Format,
+        video_id: crate::video::Id,
+        client: Client,
+    ) -> Self {
         match format.ty {
             FormatType::Audio(audio) => Self::Audio(Audio {
                 common: Common {
                     format: format.base,
+                    video_id,
                     client,
                 },
                 audio,
@@ -61,22 +328,84 @@ impl Stream {
             FormatType::Video(video) => Self::Video(Video {
                 common: Common {
                     format: format.base,
+                    video_id,
                     client,
                 },
                 video,
             }),
+            FormatType::Muxed { video, audio } => Self::Muxed(Muxed {
+                common: Common {
+                    format: format.base,
+                    video_id,
+                    client,
+                },
+                video,
+                audio,
+            }),
         }
     }
 
     /// Returns `true` if the stream is [`Self::Audio`].
+    ///
+    /// This is `false` for [`Self::Muxed`] streams, even though they contain
+    /// audio.
     pub fn is_audio(&self) -> bool {
         matches!(self, Self::Audio(..))
     }
 
     /// Returns `true` if the stream is [`Self::Video`].
+    ///
+    /// This is `false` for [`Self::Muxed`] streams, even though they contain
+    /// video.
     pub fn is_video(&self) -> bool {
         matches!(self, Self::Video(..))
     }
+
+    /// Returns `true` if the stream is [`Self::Muxed`].
+    pub fn is_muxed(&self) -> bool {
+        matches!(self, Self::Muxed(..))
+    }
+
+    /// Request the streams of the video of a [`Stream`] again and return the
+    /// one with the same itag and [`AudioTrack`], with a fresh [`Url`](Common::url).
+    ///
+    /// Useful once a [`Stream`] [`is_expired`](Common::is_expired). Fails
+    /// with [`Error::MissingItag`] if the video no longer has such a stream.
+    pub async fn refresh(&self) -> crate::Result<Stream> {
+        let track = |stream: &Stream| match stream {
+            Stream::Audio(audio) => audio.track().map(|x| x.id().to_string()),
+            _ => None,
+        };
+        let (itag, own_track) = (self.itag(), track(self));
+
+        let mut available = Vec::new();
+        for stream in get(self.client.clone(), self.video_id()).await? {
+            if stream.itag() == itag && track(&stream) == own_track {
+                return Ok(stream);
+            }
+            available.push(stream.itag());
+        }
+
+        Err(Error::MissingItag { itag, available }.into())
+    }
+
+    /// Returns the [`Audio`] stream, [`None`] if the stream is not
+    /// [`Self::Audio`].
+    pub fn into_audio(self) -> Option<Audio> {
+        match self {
+            Self::Audio(audio) => Some(audio),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`Video`] stream, [`None`] if the stream is not
+    /// [`Self::Video`].
+    pub fn into_video(self) -> Option<Video> {
+        match self {
+            Self::Video(video) => Some(video),
+            _ => None,
+        }
+    }
 }
 
 impl std::ops::Deref for Stream {
@@ -86,6 +415,7 @@ impl std::ops::Deref for Stream {
         match self {
             Stream::Audio(audio) => &audio.common,
             Stream::Video(video) => &video.common,
+            Stream::Muxed(muxed) => &muxed.common,
         }
     }
 }
@@ -103,6 +433,10 @@ impl std::fmt::Debug for Stream {
                 video.common.debug(&mut debug);
                 video.debug(&mut debug);
             }
+            Stream::Muxed(muxed) => {
+                muxed.common.debug(&mut debug);
+                muxed.debug(&mut debug);
+            }
         }
         debug.finish()?;
 
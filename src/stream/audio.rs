@@ -1,4 +1,4 @@
-use crate::youtube::player_response::AudioFormat;
+use crate::youtube::player_response::{self, AudioFormat};
 
 /// A [`Stream`](super::Stream) specifically containing audio data.
 #[derive(Clone)]
@@ -26,13 +26,111 @@ impl Stream {
         self.audio.audio_channels
     }
 
+    /// The loudness of a [`Stream`] relative to YouTube's target loudness, in
+    /// decibels.
+    ///
+    /// Negative values are quieter than the target, so applying `-loudness_db`
+    /// as gain normalizes a download like YouTube does during playback.
+    pub fn loudness_db(&self) -> Option<f64> {
+        self.audio.loudness_db
+    }
+
+    /// The [`AudioQuality`] of a [`Stream`]
+    pub fn quality(&self) -> AudioQuality {
+        AudioQuality::new(&self.audio.audio_quality)
+    }
+
+    /// The [`AudioTrack`] of a [`Stream`].
+    ///
+    /// [`None`] if the video only has a single audio track.
+    pub fn track(&self) -> Option<AudioTrack<'_>> {
+        self.audio.audio_track.as_ref().map(AudioTrack)
+    }
+
     pub(super) fn debug(&self, debug: &mut std::fmt::DebugStruct<'_, '_>) {
         debug
             .field("sample_rate", &self.sample_rate())
-            .field("channels", &self.channels());
+            .field("channels", &self.channels())
+            .field("loudness_db", &self.loudness_db())
+            .field("quality", &self.quality())
+            .field("track", &self.track());
     }
 }
 
+/// The quality of a audio [`Stream`], ordered from worst to best.
+///
+/// Unknown qualities are ordered before all others.
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum AudioQuality {
+    /// Any other quality, e.g. `AUDIO_QUALITY_ULTRALOW`
+    Other(String),
+    /// `AUDIO_QUALITY_LOW`
+    Low,
+    /// `AUDIO_QUALITY_MEDIUM`
+    Medium,
+    /// `AUDIO_QUALITY_HIGH`
+    High,
+}
+
+impl AudioQuality {
+    /// Parse a `AUDIO_QUALITY_*` string as returned by YouTube
+    pub fn new(quality: &str) -> Self {
+        match quality {
+            "AUDIO_QUALITY_LOW" => Self::Low,
+            "AUDIO_QUALITY_MEDIUM" => Self::Medium,
+            "AUDIO_QUALITY_HIGH" => Self::High,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+/// A audio track of a video with multiple, e.g. dubbed, audio tracks
+#[derive(Clone, Copy)]
+pub struct AudioTrack<'a>(&'a player_response::AudioTrack);
+
+impl<'a> AudioTrack<'a> {
+    /// The id of a [`AudioTrack`], e.g. `en.4`
+    pub fn id(&self) -> &'a str {
+        &self.0.id
+    }
+
+    /// The language code of a [`AudioTrack`], e.g. `en` or `pt-BR`
+    pub fn language(&self) -> &'a str {
+        self.0.id.split('.').next().unwrap_or_default()
+    }
+
+    /// The name of a [`AudioTrack`], e.g. `English (United States) original`
+    pub fn display_name(&self) -> &'a str {
+        &self.0.display_name
+    }
+
+    /// Is this the [`AudioTrack`] that is played by default?
+    pub fn is_default(&self) -> bool {
+        self.0.audio_is_default
+    }
+}
+
+impl<'a> std::fmt::Debug for AudioTrack<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AudioTrack")
+            .field("language", &self.language())
+            .field("display_name", &self.display_name())
+            .field("is_default", &self.is_default())
+            .finish()
+    }
+}
+
+impl<'a> PartialEq for AudioTrack<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.id == other.0.id
+    }
+}
+
+impl<'a> Eq for AudioTrack<'a> {}
+
 impl std::fmt::Debug for Stream {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut debug = f.debug_struct("AudioStream");
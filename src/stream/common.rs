@@ -1,13 +1,19 @@
 use crate::{youtube::player_response::CommonFormat, Client};
 
-use reqwest::Url;
+use reqwest::{
+    header::{HeaderMap, HeaderValue, CONTENT_LENGTH},
+    Method, RequestBuilder, Url,
+};
 
-use std::time::Duration;
+use chrono::{DateTime, Utc};
+
+use std::{borrow::Cow, time::Duration};
 
 /// A [`Stream`](super::Stream) containing video or audio data.
 #[derive(Clone)]
 pub struct Stream {
     pub(super) format: CommonFormat,
+    pub(super) video_id: crate::video::Id,
     pub(super) client: Client,
 }
 
@@ -18,50 +24,335 @@ impl Stream {
     }
 
     /// The length of a [`Stream`] in bytes
+    ///
+    /// Fails with [`Error::Expired`](super::Error::Expired) if the [`Url`]
+    /// expired, and with
+    /// [`Error::UnknownContentLength`](super::Error::UnknownContentLength) if
+    /// the length is not known and the server does not send it either.
     pub async fn content_length(&self) -> crate::Result<u64> {
+        self.check_expired()?;
+
         if let Some(content_length) = self.format.content_length {
             Ok(content_length)
         } else {
             let res = self
-                .client
-                .api
-                .http
-                .head(self.url())
+                .request(Method::HEAD, self.url())
                 .send()
                 .await?
                 .error_for_status()?;
 
-            Ok(res
-                .content_length()
-                .expect("HEAD request did not have a content-length"))
+            // `Response::content_length` is the length of the empty body of a
+            // HEAD response, not the header
+            res.headers()
+                .get(CONTENT_LENGTH)
+                .and_then(|x| x.to_str().ok()?.parse().ok())
+                .ok_or_else(|| super::Error::UnknownContentLength.into())
         }
     }
 
     /// Get the [`Stream`] as a [`AsyncStream`](futures_core::Stream) of [`Bytes`](bytes::Bytes)
+    ///
+    /// Fails with [`Error::Expired`](super::Error::Expired) if the [`Url`]
+    /// expired.
     pub async fn get(
         &self,
     ) -> crate::Result<impl futures_core::Stream<Item = Result<bytes::Bytes, reqwest::Error>>> {
+        self.check_expired()?;
+
         Ok(self
-            .client
-            .api
-            .http
-            .get(self.url())
+            .request(Method::GET, self.url())
             .send()
             .await?
             .error_for_status()?
             .bytes_stream())
     }
 
+    /// Get the bytes in `range` of a [`Stream`] as a
+    /// [`AsyncStream`](futures_core::Stream) of [`Bytes`](bytes::Bytes).
+    ///
+    /// The range is requested with the `range` query parameter, see
+    /// [`get_chunked`](Self::get_chunked).
+    pub async fn get_range(
+        &self,
+        range: std::ops::Range<u64>,
+    ) -> crate::Result<impl futures_core::Stream<Item = Result<bytes::Bytes, reqwest::Error>>> {
+        self.check_expired()?;
+
+        Ok(self
+            .request(Method::GET, self.ranged_url(range))
+            .send()
+            .await?
+            .error_for_status()?
+            .bytes_stream())
+    }
+
+    /// Get a [`Stream`] in chunks of `chunk_size` bytes.
+    ///
+    /// YouTube throttles large downloads over a single request, so requesting
+    /// chunks of about 10 MB is a lot faster than [`get`](Self::get). Every
+    /// item is the response to one request, which is a whole chunk unless the
+    /// connection ended early, in which case the missing bytes are requested
+    /// next. A failed chunk ends the [`AsyncStream`](futures_core::Stream)
+    /// with its error; the chunks received until then can be continued from
+    /// with [`get_range`](Self::get_range). A response longer than its chunk
+    /// fails with [`Error::RangeExceeded`](super::Error::RangeExceeded).
+    ///
+    /// A `chunk_size` of `0` is treated as `1`.
+    pub fn get_chunked(
+        &self,
+        chunk_size: u64,
+    ) -> impl futures_core::Stream<Item = crate::Result<bytes::Bytes>> + '_ {
+        let chunk_size = chunk_size.max(1);
+
+        async_stream::stream! {
+            let total = match self.content_length().await {
+                Ok(total) => total,
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            };
+
+            let mut offset = 0;
+            while offset < total {
+                let end = total.min(offset + chunk_size);
+                let chunk = match self.get_chunk(offset..end).await {
+                    Ok(chunk) if chunk.is_empty() => {
+                        yield Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+                        return;
+                    }
+                    Ok(chunk) if chunk.len() as u64 > end - offset => {
+                        yield Err(super::Error::RangeExceeded { expected: end - offset }.into());
+                        return;
+                    }
+                    Ok(chunk) => chunk,
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                };
+
+                offset += chunk.len() as u64;
+                if offset < end {
+                    // The connection ended early, continue with the missing bytes
+                    log::warn!("Chunk ended at {} of {}", offset, end);
+                }
+
+                yield Ok(chunk);
+            }
+        }
+    }
+
+    async fn get_chunk(&self, range: std::ops::Range<u64>) -> crate::Result<bytes::Bytes> {
+        Ok(self
+            .request(Method::GET, self.ranged_url(range))
+            .send()
+            .await?
+            .error_for_status()?
+            .bytes()
+            .await?)
+    }
+
+    /// The headers that have to accompany a request to the [`Url`] of a
+    /// [`Stream`].
+    ///
+    /// This is only needed when requesting the [`Stream`] with a different
+    /// HTTP client; all methods of a [`Stream`] already send these headers.
+    ///
+    /// - OTF streams only respond with data if a `Range` header is present.
+    /// - Streams for the web client expect an `Origin` and `Referer` of
+    ///   `https://www.youtube.com`.
+    pub fn request_headers(&self) -> HeaderMap {
+        const YOUTUBE: HeaderValue = HeaderValue::from_static("https://www.youtube.com");
+
+        let mut headers = HeaderMap::new();
+
+        if self.format.stream_type.as_deref() == Some("FORMAT_STREAM_TYPE_OTF") {
+            headers.insert(reqwest::header::RANGE, HeaderValue::from_static("bytes=0-"));
+        }
+
+        let web_client = self
+            .format
+            .url
+            .query_pairs()
+            .any(|(key, value)| key == "c" && value.starts_with("WEB"));
+
+        if web_client {
+            headers.insert(reqwest::header::ORIGIN, YOUTUBE);
+            headers.insert(reqwest::header::REFERER, YOUTUBE);
+        }
+
+        headers
+    }
+
+    /// Build a request to `url` carrying the [`request_headers`](Self::request_headers).
+    ///
+    /// Urls that already carry a `range` parameter do not get a `Range`
+    /// header, as the two would conflict.
+    pub(super) fn request(&self, method: Method, url: Url) -> RequestBuilder {
+        let mut headers = self.request_headers();
+        if url.query_pairs().any(|(key, _)| key == "range") {
+            headers.remove(reqwest::header::RANGE);
+        }
+
+        self.client.api.http.request(method, url).headers(headers)
+    }
+
+    /// The [`Url`] of a [`Stream`] limited to the given byte range.
+    ///
+    /// This uses the `range` query parameter, which Google's CDN handles more
+    /// reliably than the HTTP `Range` header.
+    pub(super) fn ranged_url(&self, range: std::ops::Range<u64>) -> Url {
+        let mut url = self.url();
+        url.query_pairs_mut().append_pair(
+            "range",
+            &format!("{}-{}", range.start, range.end.saturating_sub(1)),
+        );
+        url
+    }
+
+    /// The query parameters of the [`Url`] of a [`Stream`].
+    ///
+    /// Useful when debugging failing downloads, see also the getters for
+    /// single parameters like [`expires_at`](Self::expires_at).
+    pub fn url_params(&self) -> impl Iterator<Item = (Cow<'_, str>, Cow<'_, str>)> {
+        self.format.url.query_pairs()
+    }
+
+    /// The value of the query parameter `key` of the [`Url`] of a [`Stream`]
+    fn url_param(&self, key: &str) -> Option<Cow<'_, str>> {
+        self.url_params().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    /// When the [`Url`] of a [`Stream`] expires, from its `expire` parameter.
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.format.expires_at()
+    }
+
+    /// Has the [`Url`] of a [`Stream`] expired?
+    ///
+    /// Requests to an expired [`Url`] fail, so the streams have to be
+    /// requested again. [`false`] if the expiry is unknown.
+    pub fn is_expired(&self) -> bool {
+        self.expires_at().is_some_and(|expire| expire <= Utc::now())
+    }
+
+    /// Fail with [`Error::Expired`](super::Error::Expired) if the [`Url`] of
+    /// a [`Stream`] expired, instead of the 403 YouTube responds with.
+    pub(super) fn check_expired(&self) -> crate::Result<()> {
+        match self.expires_at() {
+            Some(expire) if self.is_expired() => Err(super::Error::Expired(expire).into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// The length of a [`Stream`] in bytes, from the `clen` parameter of its
+    /// [`Url`].
+    ///
+    /// Logs a warning if it differs from the length YouTube reported
+    /// alongside the [`Url`].
+    pub fn clen(&self) -> Option<u64> {
+        let clen = self.url_param("clen")?.parse().ok()?;
+
+        if let Some(content_length) = self.format.content_length {
+            if content_length != clen {
+                log::warn!(
+                    "itag {}: 'clen' of url is {} but content length is {}",
+                    self.format.itag,
+                    clen,
+                    content_length
+                );
+            }
+        }
+
+        Some(clen)
+    }
+
+    /// The mime type of a [`Stream`] without codecs, e.g. `video/mp4`, from
+    /// the `mime` parameter of its [`Url`].
+    ///
+    /// Logs a warning if it differs from the [`mime_type`](Self::mime_type).
+    pub fn mime(&self) -> Option<String> {
+        let mime = self.url_param("mime")?.into_owned();
+
+        let essence = self.mime_type().split(';').next().unwrap_or_default();
+        if essence.trim() != mime {
+            log::warn!(
+                "itag {}: 'mime' of url is '{}' but mime type is '{}'",
+                self.format.itag,
+                mime,
+                self.mime_type()
+            );
+        }
+
+        Some(mime)
+    }
+
+    /// Is the download speed of a [`Stream`] not throttled, from the
+    /// `ratebypass` parameter of its [`Url`]?
+    pub fn ratebypass(&self) -> bool {
+        self.url_param("ratebypass").as_deref() == Some("yes")
+    }
+
+    /// The source of a [`Stream`], e.g. `youtube`, from the `source`
+    /// parameter of its [`Url`].
+    pub fn source(&self) -> Option<String> {
+        self.url_param("source").map(Cow::into_owned)
+    }
+
     /// The [mime type](https://en.wikipedia.org/wiki/Media_type) of a [`Stream`]
     pub fn mime_type(&self) -> &str {
         &self.format.mime_type
     }
 
+    /// The [`Id`](crate::video::Id) of the video of a [`Stream`]
+    pub fn video_id(&self) -> crate::video::Id {
+        self.video_id
+    }
+
+    /// The itag of a [`Stream`], which identifies its format, e.g. `137` for
+    /// 1080p H.264 video
+    pub fn itag(&self) -> u64 {
+        self.format.itag
+    }
+
+    /// The [`Container`](super::Container) of a [`Stream`], from its
+    /// [`mime_type`](Self::mime_type)
+    pub fn container(&self) -> super::Container {
+        super::Container::from_mime_type(self.mime_type())
+    }
+
+    /// The [`Codec`](super::Codec)s of a [`Stream`], from its
+    /// [`mime_type`](Self::mime_type)
+    pub fn codecs(&self) -> Vec<super::Codec> {
+        super::Codec::from_mime_type(self.mime_type())
+    }
+
     /// The bitrate of a [`Stream`]
     pub fn bitrate(&self) -> u64 {
         self.format.bitrate
     }
 
+    /// The average bitrate of a [`Stream`].
+    ///
+    /// More accurate than the [`bitrate`](Self::bitrate), which is the peak
+    /// bitrate, for streams with a variable bitrate.
+    pub fn average_bitrate(&self) -> Option<u64> {
+        self.format.average_bitrate
+    }
+
+    /// The [`ByteRange`] of the initialization segment of a [`Stream`], i.e.
+    /// the headers of the container
+    pub fn init_range(&self) -> Option<ByteRange> {
+        self.format.init_range.map(ByteRange::new)
+    }
+
+    /// The [`ByteRange`] of the segment index of a [`Stream`], e.g. the
+    /// `sidx` box of mp4 or the `Cues` of webm
+    pub fn index_range(&self) -> Option<ByteRange> {
+        self.format.index_range.map(ByteRange::new)
+    }
+
     /// The [`Duration`] of a [`Stream`]
     pub fn duration(&self) -> Option<Duration> {
         self.format.duration
@@ -70,12 +361,38 @@ impl Stream {
     pub(super) fn debug(&self, debug: &mut std::fmt::DebugStruct<'_, '_>) {
         debug
             .field("url", &self.url())
+            .field("itag", &self.itag())
             .field("mime_type", &self.mime_type())
             .field("bitrate", &self.bitrate())
             .field("duration", &self.duration());
     }
 }
 
+/// A range of bytes of a [`Stream`], including both `start` and `end`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ByteRange {
+    /// The first byte
+    pub start: u64,
+    /// The last byte
+    pub end: u64,
+}
+
+impl ByteRange {
+    fn new(range: crate::youtube::player_response::ByteRange) -> Self {
+        Self {
+            start: range.start,
+            end: range.end,
+        }
+    }
+
+    /// The [`Range`](std::ops::Range) of a [`ByteRange`], e.g. for
+    /// [`Stream::get_range`]
+    pub fn range(&self) -> std::ops::Range<u64> {
+        self.start..self.end + 1
+    }
+}
+
 impl std::fmt::Debug for Stream {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut debug = f.debug_struct("CommonStream");
@@ -0,0 +1,345 @@
+use std::{fmt::Write, time::Duration};
+
+use reqwest::Url;
+use roxmltree::Node;
+
+/// A representation of the DASH manifest of a video.
+///
+/// Some videos, e.g. OTF streams, offer formats in their DASH manifest that
+/// are missing from the regular [`Stream`](super::Stream)s, see
+/// [`Client::dash_streams`](crate::Client::dash_streams). A representation is
+/// split into [`segments`](Self::segments), which have to be downloaded after
+/// the [`initialization`](Self::initialization) and concatenated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Representation {
+    id: String,
+    mime_type: String,
+    codecs: Option<String>,
+    bandwidth: u64,
+    width: Option<u64>,
+    height: Option<u64>,
+    frame_rate: Option<f64>,
+    sample_rate: Option<u64>,
+    initialization: Option<Url>,
+    segments: Vec<Url>,
+}
+
+impl Representation {
+    /// Parse the representations of a DASH manifest.
+    ///
+    /// Relative urls are resolved against `base`. Segment templates are
+    /// expanded into their segment urls, which is only possible for manifests
+    /// with a known duration or a `SegmentTimeline`.
+    pub fn parse(manifest: &str, base: &Url) -> crate::Result<Vec<Self>> {
+        let document = roxmltree::Document::parse(manifest)
+            .map_err(|err| crate::Error::Dash(err.to_string()))?;
+
+        let mpd = document.root_element();
+        let duration = mpd
+            .attribute("mediaPresentationDuration")
+            .and_then(iso_duration);
+        let base = base_url(mpd, base);
+
+        let mut representations = Vec::new();
+        for period in children(mpd, "Period") {
+            let base = base_url(period, &base);
+            let duration = period
+                .attribute("duration")
+                .and_then(iso_duration)
+                .or(duration);
+
+            for set in children(period, "AdaptationSet") {
+                let base = base_url(set, &base);
+
+                for node in children(set, "Representation") {
+                    let base = base_url(node, &base);
+                    representations.push(Self::from_node(node, set, &base, duration)?);
+                }
+            }
+        }
+
+        Ok(representations)
+    }
+
+    fn from_node(
+        node: Node<'_, '_>,
+        set: Node<'_, '_>,
+        base: &Url,
+        duration: Option<Duration>,
+    ) -> crate::Result<Self> {
+        let attribute = |name| node.attribute(name).or_else(|| set.attribute(name));
+        let number = |name| attribute(name).and_then(|x| x.parse().ok());
+
+        let id = node
+            .attribute("id")
+            .ok_or_else(|| crate::Error::Dash("a Representation has no id".to_string()))?;
+        let bandwidth = number("bandwidth").ok_or_else(|| {
+            crate::Error::Dash(format!("Representation '{}' has no bandwidth", id))
+        })?;
+
+        let mut representation = Self {
+            id: id.to_string(),
+            mime_type: attribute("mimeType").unwrap_or_default().to_string(),
+            codecs: attribute("codecs").map(ToString::to_string),
+            bandwidth,
+            width: number("width"),
+            height: number("height"),
+            frame_rate: attribute("frameRate").and_then(frame_rate),
+            sample_rate: number("audioSamplingRate"),
+            initialization: None,
+            segments: Vec::new(),
+        };
+
+        let segment_info = |name| child(node, name).or_else(|| child(set, name));
+        let join = |url: &str| {
+            base.join(url)
+                .map_err(|err| crate::Error::Dash(format!("Invalid url '{}': {}", url, err)))
+        };
+
+        if let Some(list) = segment_info("SegmentList") {
+            if let Some(url) = child(list, "Initialization").and_then(|x| x.attribute("sourceURL"))
+            {
+                representation.initialization = Some(join(url)?);
+            }
+            representation.segments = children(list, "SegmentURL")
+                .filter_map(|x| x.attribute("media"))
+                .map(join)
+                .collect::<crate::Result<_>>()?;
+        } else if let Some(template) = segment_info("SegmentTemplate") {
+            let expand =
+                |template: &str, number, time| join(&expand(template, id, bandwidth, number, time));
+
+            if let Some(url) = template.attribute("initialization") {
+                representation.initialization = Some(expand(url, 0, 0)?);
+            }
+            if let Some(media) = template.attribute("media") {
+                representation.segments = segment_times(template, duration)
+                    .into_iter()
+                    .map(|(number, time)| expand(media, number, time))
+                    .collect::<crate::Result<_>>()?;
+            }
+        } else {
+            // A single segment, e.g. with a `SegmentBase`
+            representation.segments.push(base.clone());
+        }
+
+        Ok(representation)
+    }
+
+    /// The id of a [`Representation`], the itag for YouTube manifests
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The itag of a [`Representation`]
+    pub fn itag(&self) -> Option<u64> {
+        self.id.parse().ok()
+    }
+
+    /// The mime type of a [`Representation`], e.g. `video/mp4`
+    pub fn mime_type(&self) -> &str {
+        &self.mime_type
+    }
+
+    /// The codecs of a [`Representation`], e.g. `avc1.4d401f`
+    pub fn codecs(&self) -> Option<&str> {
+        self.codecs.as_deref()
+    }
+
+    /// The bitrate of a [`Representation`] in bits per second
+    pub fn bandwidth(&self) -> u64 {
+        self.bandwidth
+    }
+
+    /// The width of a video [`Representation`]
+    pub fn width(&self) -> Option<u64> {
+        self.width
+    }
+
+    /// The height of a video [`Representation`]
+    pub fn height(&self) -> Option<u64> {
+        self.height
+    }
+
+    /// The frames per second of a video [`Representation`]
+    pub fn frame_rate(&self) -> Option<f64> {
+        self.frame_rate
+    }
+
+    /// The sample rate of a audio [`Representation`]
+    pub fn sample_rate(&self) -> Option<u64> {
+        self.sample_rate
+    }
+
+    /// The [`Url`] of the initialization segment of a [`Representation`]
+    pub fn initialization(&self) -> Option<&Url> {
+        self.initialization.as_ref()
+    }
+
+    /// The [`Url`]s of the media segments of a [`Representation`], in order
+    pub fn segments(&self) -> impl Iterator<Item = &Url> {
+        self.segments.iter()
+    }
+}
+
+fn children<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+    name: &'static str,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children().filter(move |x| x.has_tag_name(name))
+}
+
+fn child<'a, 'input: 'a>(node: Node<'a, 'input>, name: &'static str) -> Option<Node<'a, 'input>> {
+    children(node, name).next()
+}
+
+/// Resolve the `BaseURL` of `node` against `base`
+fn base_url(node: Node<'_, '_>, base: &Url) -> Url {
+    child(node, "BaseURL")
+        .and_then(|x| x.text())
+        .and_then(|x| base.join(x.trim()).ok())
+        .unwrap_or_else(|| base.clone())
+}
+
+/// The most segments a single representation is expanded into, so malformed
+/// manifests can not allocate without bounds
+const MAX_SEGMENTS: usize = 100_000;
+
+/// The number and time of each segment of a `SegmentTemplate`
+fn segment_times(template: Node<'_, '_>, duration: Option<Duration>) -> Vec<(u64, u64)> {
+    let number = |name, default| {
+        template
+            .attribute(name)
+            .and_then(|x| x.parse().ok())
+            .unwrap_or(default)
+    };
+    let start_number = number("startNumber", 1);
+    let timescale = number("timescale", 1);
+    let end = duration.map(|x| (x.as_secs_f64() * timescale as f64).ceil() as u64);
+
+    let mut times = Vec::new();
+
+    if let Some(timeline) = child(template, "SegmentTimeline") {
+        let mut time = 0;
+        for s in children(timeline, "S") {
+            let attribute = |name| s.attribute(name).and_then(|x| x.parse::<i64>().ok());
+            let d = match attribute("d") {
+                Some(d) if d > 0 => d as u64,
+                _ => continue,
+            };
+            time = attribute("t").map_or(time, |t| t as u64);
+
+            let repeat = match attribute("r") {
+                // Repeat until the end of the period
+                Some(r) if r < 0 => end.map_or(0, |end| {
+                    end.saturating_sub(time).div_ceil(d).saturating_sub(1)
+                }),
+                Some(r) => r as u64,
+                None => 0,
+            };
+
+            for _ in 0..=repeat {
+                if times.len() == MAX_SEGMENTS {
+                    return times;
+                }
+                times.push((start_number + times.len() as u64, time));
+                time = time.saturating_add(d);
+            }
+        }
+    } else if let (Some(d), Some(end)) = (template.attribute("duration"), end) {
+        if let Ok(d @ 1..) = d.parse::<u64>() {
+            times.extend(
+                (0..end.div_ceil(d))
+                    .take(MAX_SEGMENTS)
+                    .map(|i| (start_number + i, i * d)),
+            );
+        }
+    }
+
+    times
+}
+
+/// Expand the identifiers of a `SegmentTemplate`, e.g. `$Number%05d$`
+fn expand(template: &str, id: &str, bandwidth: u64, number: u64, time: u64) -> String {
+    let mut out = String::new();
+
+    for (i, part) in template.split('$').enumerate() {
+        if i % 2 == 0 {
+            out.push_str(part);
+            continue;
+        }
+
+        let (name, width) = match part.split_once("%0") {
+            Some((name, format)) => (name, format.trim_end_matches('d').parse().unwrap_or(0)),
+            None => (part, 0),
+        };
+
+        let _ = match name {
+            "" => write!(out, "$"),
+            "RepresentationID" => write!(out, "{}", id),
+            "Number" => write!(out, "{:0width$}", number, width = width),
+            "Bandwidth" => write!(out, "{:0width$}", bandwidth, width = width),
+            "Time" => write!(out, "{:0width$}", time, width = width),
+            _ => write!(out, "${}$", part),
+        };
+    }
+
+    out
+}
+
+/// Parse a frame rate like `30` or `30000/1001`
+fn frame_rate(value: &str) -> Option<f64> {
+    match value.split_once('/') {
+        Some((numerator, denominator)) => {
+            Some(numerator.parse::<f64>().ok()? / denominator.parse::<f64>().ok()?)
+        }
+        None => value.parse().ok(),
+    }
+}
+
+/// Parse a ISO 8601 duration like `PT1H2M3.5S`
+fn iso_duration(value: &str) -> Option<Duration> {
+    let value = value.strip_prefix('P')?;
+    let (date, time) = value.split_once('T').unwrap_or((value, ""));
+
+    let mut secs = 0.0;
+    for (part, units) in [
+        (date, &[('D', 86400.0)][..]),
+        (time, &[('H', 3600.0), ('M', 60.0), ('S', 1.0)][..]),
+    ] {
+        let mut rest = part;
+        for (unit, factor) in units {
+            if let Some((number, after)) = rest.split_once(*unit) {
+                secs += number.parse::<f64>().ok()? * factor;
+                rest = after;
+            }
+        }
+        if !rest.is_empty() {
+            return None;
+        }
+    }
+
+    Duration::try_from_secs_f64(secs).ok()
+}
+
+pub(crate) async fn get(
+    client: &crate::Client,
+    streaming_data: &crate::youtube::player_response::StreamingData,
+) -> crate::Result<Vec<Representation>> {
+    let url = match &streaming_data.dash_manifest_url {
+        Some(url) => url,
+        None => return Ok(Vec::new()),
+    };
+
+    let manifest = client
+        .api
+        .http
+        .get(url.clone())
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+
+    Representation::parse(&manifest, url)
+}
@@ -0,0 +1,316 @@
+use std::path::{Path, PathBuf};
+
+use futures_util::StreamExt;
+
+/// Options for [`Stream::download`](super::Common::download).
+///
+/// Downloads are always written to `<path>.part` first and only moved to
+/// `<path>` once they completed. A existing `<path>.part` is resumed instead
+/// of being downloaded again, if the `<path>.part.meta` next to it shows that
+/// it belongs to the same stream.
+#[derive(Debug, Clone, Default)]
+pub struct DownloadOptions {
+    keep_partial: bool,
+}
+
+impl DownloadOptions {
+    /// Create the default [`DownloadOptions`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep the `<path>.part` file if the download fails or is cancelled,
+    /// so it can be resumed later. By default it is removed.
+    pub fn keep_partial(mut self, keep_partial: bool) -> Self {
+        self.keep_partial = keep_partial;
+        self
+    }
+}
+
+/// The size of the chunks downloads are requested in
+const CHUNK_SIZE: u64 = 10 * 1024 * 1024;
+
+/// The progress of a download, see
+/// [`Stream::download_with_progress`](super::Common::download_with_progress).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DownloadProgress {
+    /// The amount of bytes that were written, including those of a resumed
+    /// partial download
+    pub bytes_done: u64,
+    /// The total amount of bytes of the stream
+    pub total: u64,
+}
+
+/// The path a download is written to until it completed.
+pub(crate) fn part_path(path: &Path) -> PathBuf {
+    let mut part = path.as_os_str().to_owned();
+    part.push(".part");
+    PathBuf::from(part)
+}
+
+/// The path the identity of the stream a `<path>.part` belongs to is stored
+/// at, see [`Common::part_identity`](super::Common::part_identity).
+fn meta_path(part: &Path) -> PathBuf {
+    let mut meta = part.as_os_str().to_owned();
+    meta.push(".meta");
+    PathBuf::from(meta)
+}
+
+/// The file I/O of a download.
+///
+/// With the `tokio` feature it runs on tokio's blocking thread pool instead of
+/// blocking the executor.
+#[cfg(feature = "tokio")]
+mod fs {
+    use std::{io, path::Path};
+
+    use tokio::io::AsyncWriteExt;
+
+    use tokio::fs::File;
+
+    pub(super) async fn len(path: &Path) -> io::Result<u64> {
+        Ok(tokio::fs::metadata(path).await?.len())
+    }
+
+    pub(super) async fn open(path: &Path, append: bool) -> io::Result<File> {
+        tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(append)
+            .write(true)
+            .truncate(!append)
+            .open(path)
+            .await
+    }
+
+    pub(super) async fn write(file: &mut File, bytes: &[u8]) -> io::Result<()> {
+        file.write_all(bytes).await
+    }
+
+    pub(super) async fn finish(mut file: File, from: &Path, to: &Path) -> io::Result<()> {
+        file.flush().await?;
+        file.sync_all().await?;
+        drop(file);
+        tokio::fs::rename(from, to).await
+    }
+
+    pub(super) async fn read(path: &Path) -> io::Result<String> {
+        tokio::fs::read_to_string(path).await
+    }
+
+    pub(super) async fn create(path: &Path, contents: &str) -> io::Result<()> {
+        tokio::fs::write(path, contents).await
+    }
+
+    pub(super) async fn remove(path: &Path) -> io::Result<()> {
+        tokio::fs::remove_file(path).await
+    }
+}
+
+/// The file I/O of a download, as blocking [`std::fs`] calls.
+///
+/// These are only `async` to match the `tokio` variant: every call blocks the
+/// thread it is polled on, and with it the executor, until the I/O finished.
+#[cfg(not(feature = "tokio"))]
+mod fs {
+    use std::{io, io::Write, path::Path};
+
+    use std::fs::File;
+
+    pub(super) async fn len(path: &Path) -> io::Result<u64> {
+        Ok(std::fs::metadata(path)?.len())
+    }
+
+    pub(super) async fn open(path: &Path, append: bool) -> io::Result<File> {
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(append)
+            .write(true)
+            .truncate(!append)
+            .open(path)
+    }
+
+    pub(super) async fn write(file: &mut File, bytes: &[u8]) -> io::Result<()> {
+        file.write_all(bytes)
+    }
+
+    pub(super) async fn finish(file: File, from: &Path, to: &Path) -> io::Result<()> {
+        file.sync_all()?;
+        drop(file);
+        std::fs::rename(from, to)
+    }
+
+    pub(super) async fn read(path: &Path) -> io::Result<String> {
+        std::fs::read_to_string(path)
+    }
+
+    pub(super) async fn create(path: &Path, contents: &str) -> io::Result<()> {
+        std::fs::write(path, contents)
+    }
+
+    pub(super) async fn remove(path: &Path) -> io::Result<()> {
+        std::fs::remove_file(path)
+    }
+}
+
+/// Removes the partial file and its meta file when dropped, unless it was
+/// disarmed.
+///
+/// This covers errors, panics and cancellation of the download future alike.
+struct PartGuard<'a> {
+    paths: [&'a Path; 2],
+    armed: bool,
+}
+
+impl Drop for PartGuard<'_> {
+    fn drop(&mut self) {
+        if self.armed {
+            for path in self.paths {
+                let _ = std::fs::remove_file(path);
+            }
+        }
+    }
+}
+
+impl super::Common {
+    /// Download a [`Stream`](super::Stream) to `path` and return the amount of
+    /// bytes the file has.
+    ///
+    /// See [`DownloadOptions`] for how partial downloads are handled.
+    ///
+    /// # Blocking
+    ///
+    /// Without the `tokio` feature, the file is written with blocking calls
+    /// that stall the executor while they run. Enable it to do the file I/O
+    /// on tokio's blocking thread pool instead.
+    pub async fn download(
+        &self,
+        path: impl AsRef<Path>,
+        options: &DownloadOptions,
+    ) -> crate::Result<u64> {
+        self.download_inner(path.as_ref(), options, |_| {}).await
+    }
+
+    /// Download a [`Stream`](super::Stream) to `path` with the default
+    /// [`DownloadOptions`] and return the amount of bytes the file has.
+    ///
+    /// The file I/O blocks without the `tokio` feature, see
+    /// [`download`](Self::download).
+    pub async fn download_to(&self, path: impl AsRef<Path>) -> crate::Result<u64> {
+        self.download(path, &DownloadOptions::new()).await
+    }
+
+    /// Download a [`Stream`](super::Stream) to `path` like
+    /// [`download_to`](Self::download_to), calling `progress` once the
+    /// download started and after every written chunk.
+    pub async fn download_with_progress(
+        &self,
+        path: impl AsRef<Path>,
+        progress: impl FnMut(DownloadProgress),
+    ) -> crate::Result<u64> {
+        self.download_inner(path.as_ref(), &DownloadOptions::new(), progress)
+            .await
+    }
+
+    /// Resume downloading a [`Stream`](super::Stream) to `path`, continuing
+    /// a `<path>.part` file left by a previous, interrupted download.
+    ///
+    /// The partial file is validated against the
+    /// [`content_length`](Self::content_length) and kept if this download is
+    /// interrupted again. Fails with [`Error::Expired`] if the
+    /// [`Url`](Self::url) expired, in which case the streams have to be
+    /// requested again. The file I/O blocks without the `tokio` feature, see
+    /// [`download`](Self::download).
+    ///
+    /// [`Error::Expired`]: super::Error::Expired
+    pub async fn resume_download_to(&self, path: impl AsRef<Path>) -> crate::Result<u64> {
+        let options = DownloadOptions::new().keep_partial(true);
+        self.download_inner(path.as_ref(), &options, |_| {}).await
+    }
+
+    /// What identifies the stream a partial download belongs to, stored in
+    /// its `<path>.part.meta`.
+    fn part_identity(&self, total: u64) -> String {
+        format!("{} {} {}", self.video_id(), self.itag(), total)
+    }
+
+    async fn download_inner(
+        &self,
+        path: &Path,
+        options: &DownloadOptions,
+        mut progress: impl FnMut(DownloadProgress),
+    ) -> crate::Result<u64> {
+        let part = part_path(path);
+        let meta = meta_path(&part);
+        let total = self.content_length().await?;
+        let identity = self.part_identity(total);
+
+        let mut offset = fs::len(&part).await.unwrap_or(0);
+
+        if offset != 0 && fs::read(&meta).await.ok().as_deref() != Some(identity.as_str()) {
+            log::warn!(
+                "Partial download '{}' belongs to another stream, restarting",
+                part.display()
+            );
+            offset = 0;
+        } else if offset > total {
+            log::warn!(
+                "Partial download '{}' is larger than the stream, restarting",
+                part.display()
+            );
+            offset = 0;
+        }
+
+        fs::create(&meta, &identity).await?;
+        let mut file = fs::open(&part, offset != 0).await?;
+
+        let mut guard = PartGuard {
+            paths: [&part, &meta],
+            armed: !options.keep_partial,
+        };
+
+        progress(DownloadProgress {
+            bytes_done: offset,
+            total,
+        });
+
+        // Request in chunks, as YouTube throttles large requests
+        while offset < total {
+            let (start, end) = (offset, total.min(offset + CHUNK_SIZE));
+            let mut bytes = Box::pin(self.get_range(offset..end).await?);
+
+            while let Some(chunk) = bytes.next().await {
+                let chunk = chunk?;
+                if offset + chunk.len() as u64 > end {
+                    return Err(super::Error::RangeExceeded {
+                        expected: end - start,
+                    }
+                    .into());
+                }
+                fs::write(&mut file, &chunk).await?;
+                offset += chunk.len() as u64;
+                progress(DownloadProgress {
+                    bytes_done: offset,
+                    total,
+                });
+            }
+
+            if offset == start {
+                return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+            } else if offset < end {
+                // The connection ended early, continue with the missing bytes
+                log::warn!(
+                    "Chunk of '{}' ended at {} of {}",
+                    part.display(),
+                    offset,
+                    end
+                );
+            }
+        }
+
+        fs::finish(file, &part, path).await?;
+        guard.armed = false;
+        let _ = fs::remove(&meta).await;
+
+        Ok(offset)
+    }
+}
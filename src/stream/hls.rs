@@ -0,0 +1,135 @@
+use reqwest::Url;
+
+/// A variant of the HLS master playlist of a livestream.
+///
+/// Livestreams are only available over HLS, see
+/// [`Client::hls_streams`](crate::Client::hls_streams). The [`url`](Self::url)
+/// points to the media playlist of the variant, which has to be played or
+/// downloaded with a HLS client.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variant {
+    url: Url,
+    bandwidth: u64,
+    resolution: Option<(u64, u64)>,
+    codecs: Option<String>,
+    frame_rate: Option<f64>,
+}
+
+impl Variant {
+    /// Parse the variants of a HLS master playlist.
+    ///
+    /// Relative media playlist urls are resolved against `base`. Variants
+    /// without a `BANDWIDTH` or url are skipped.
+    pub fn parse(master: &str, base: &Url) -> Vec<Self> {
+        let mut variants = Vec::new();
+        let mut attributes = None;
+
+        for line in master.lines().map(str::trim) {
+            if let Some(attrs) = line.strip_prefix("#EXT-X-STREAM-INF:") {
+                attributes = Some(attrs);
+            } else if line.is_empty() || line.starts_with('#') {
+                continue;
+            } else if let Some(attrs) = attributes.take() {
+                if let Some(variant) = Self::from_attributes(attrs, line, base) {
+                    variants.push(variant);
+                }
+            }
+        }
+
+        variants
+    }
+
+    fn from_attributes(attributes: &str, uri: &str, base: &Url) -> Option<Self> {
+        let mut variant = Self {
+            url: base.join(uri).ok()?,
+            bandwidth: 0,
+            resolution: None,
+            codecs: None,
+            frame_rate: None,
+        };
+        let mut bandwidth = None;
+
+        for (key, value) in split_attributes(attributes) {
+            match key {
+                "BANDWIDTH" => bandwidth = value.parse().ok(),
+                "RESOLUTION" => {
+                    variant.resolution = value
+                        .split_once('x')
+                        .and_then(|(w, h)| Some((w.parse().ok()?, h.parse().ok()?)))
+                }
+                "CODECS" => variant.codecs = Some(value.to_string()),
+                "FRAME-RATE" => variant.frame_rate = value.parse().ok(),
+                _ => {}
+            }
+        }
+
+        variant.bandwidth = bandwidth?;
+        Some(variant)
+    }
+
+    /// The [`Url`] of the media playlist of a [`Variant`]
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// The peak bitrate of a [`Variant`] in bits per second
+    pub fn bandwidth(&self) -> u64 {
+        self.bandwidth
+    }
+
+    /// The width and height of a [`Variant`], [`None`] for audio only
+    /// variants
+    pub fn resolution(&self) -> Option<(u64, u64)> {
+        self.resolution
+    }
+
+    /// The codecs of a [`Variant`], e.g. `avc1.4D401F,mp4a.40.2`
+    pub fn codecs(&self) -> Option<&str> {
+        self.codecs.as_deref()
+    }
+
+    /// The frames per second of a [`Variant`]
+    pub fn frame_rate(&self) -> Option<f64> {
+        self.frame_rate
+    }
+}
+
+/// Split a attribute list like `BANDWIDTH=1,CODECS="a,b"` into its pairs.
+fn split_attributes(attributes: &str) -> impl Iterator<Item = (&str, &str)> {
+    let mut rest = attributes;
+
+    std::iter::from_fn(move || {
+        let (key, after) = rest.split_once('=')?;
+        let (value, after) = match after.strip_prefix('"') {
+            Some(quoted) => {
+                let (value, after) = quoted.split_once('"').unwrap_or((quoted, ""));
+                (value, after.strip_prefix(',').unwrap_or(after))
+            }
+            None => after.split_once(',').unwrap_or((after, "")),
+        };
+        rest = after;
+        Some((key.trim(), value))
+    })
+}
+
+pub(crate) async fn get(
+    client: &crate::Client,
+    streaming_data: &crate::youtube::player_response::StreamingData,
+) -> crate::Result<Vec<Variant>> {
+    let url = match &streaming_data.hls_manifest_url {
+        Some(url) => url,
+        None => return Ok(Vec::new()),
+    };
+
+    let master = client
+        .api
+        .http
+        .get(url.clone())
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+
+    Ok(Variant::parse(&master, url))
+}
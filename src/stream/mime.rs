@@ -0,0 +1,81 @@
+/// The container format of a [`Stream`](super::Stream), from its mime type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Container {
+    /// `video/mp4` or `audio/mp4`
+    Mp4,
+    /// `video/webm` or `audio/webm`
+    WebM,
+    /// `video/3gpp`
+    ThreeGp,
+    /// Any other container, with the subtype of the mime type, e.g. `ogg`
+    Other(String),
+}
+
+impl Container {
+    /// Parse the [`Container`] from a mime type like
+    /// `video/mp4; codecs="avc1.640028"`.
+    pub fn from_mime_type(mime_type: &str) -> Self {
+        let essence = mime_type.split(';').next().unwrap_or_default().trim();
+        let subtype = essence.split_once('/').map_or(essence, |(_, x)| x);
+
+        match subtype.to_ascii_lowercase().as_str() {
+            "mp4" => Self::Mp4,
+            "webm" => Self::WebM,
+            "3gpp" => Self::ThreeGp,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+/// A codec of a [`Stream`](super::Stream), from its mime type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Codec {
+    /// H.264, e.g. `avc1.640028`
+    Avc1,
+    /// VP9, `vp9` or e.g. `vp09.02.51.10.01.09.16.09.00`
+    Vp9,
+    /// AV1, e.g. `av01.0.08M.08`
+    Av01,
+    /// Opus, `opus`
+    Opus,
+    /// AAC, e.g. `mp4a.40.2`
+    Mp4a,
+    /// Any other codec, with its full codec string
+    Other(String),
+}
+
+impl Codec {
+    /// Parse the [`Codec`]s from the `codecs` parameter of a mime type like
+    /// `video/mp4; codecs="avc1.42001E, mp4a.40.2"`.
+    pub fn from_mime_type(mime_type: &str) -> Vec<Self> {
+        let codecs = mime_type
+            .split(';')
+            .skip(1)
+            .filter_map(|x| x.trim().strip_prefix("codecs="))
+            .next()
+            .unwrap_or_default();
+
+        codecs
+            .trim_matches('"')
+            .split(',')
+            .map(str::trim)
+            .filter(|x| !x.is_empty())
+            .map(Self::from_codec)
+            .collect()
+    }
+
+    fn from_codec(codec: &str) -> Self {
+        let family = codec.split('.').next().unwrap_or_default();
+
+        match family.to_ascii_lowercase().as_str() {
+            "avc1" => Self::Avc1,
+            "vp9" | "vp09" => Self::Vp9,
+            "av01" => Self::Av01,
+            "opus" => Self::Opus,
+            "mp4a" => Self::Mp4a,
+            _ => Self::Other(codec.to_string()),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::youtube::player_response::{AudioFormat, VideoFormat};
+
+/// A [`Stream`](super::Stream) containing both video and audio data.
+///
+/// YouTube only offers a few of these, in low qualities.
+#[derive(Clone)]
+pub struct Stream {
+    pub(super) common: super::common::Stream,
+    pub(super) video: VideoFormat,
+    pub(super) audio: AudioFormat,
+}
+
+impl std::ops::Deref for Stream {
+    type Target = super::common::Stream;
+
+    fn deref(&self) -> &Self::Target {
+        &self.common
+    }
+}
+
+impl Stream {
+    /// The width of a [`Stream`]
+    pub fn width(&self) -> u64 {
+        self.video.width
+    }
+
+    /// The height of a [`Stream`]
+    pub fn height(&self) -> u64 {
+        self.video.height
+    }
+
+    /// The frames per second of a [`Stream`]
+    pub fn fps(&self) -> u64 {
+        self.video.fps
+    }
+
+    /// The quality label of a [`Stream`] as shown by YouTube, e.g. `360p`
+    pub fn quality_label(&self) -> &str {
+        &self.video.quality_label
+    }
+
+    /// The [`VideoQuality`](super::VideoQuality) of a [`Stream`]
+    pub fn quality(&self) -> super::VideoQuality {
+        super::VideoQuality::new(&self.video)
+    }
+
+    /// The sample rate of the audio of a [`Stream`]
+    pub fn sample_rate(&self) -> u64 {
+        self.audio.audio_sample_rate
+    }
+
+    /// The amount of audio channels of a [`Stream`]
+    pub fn channels(&self) -> u64 {
+        self.audio.audio_channels
+    }
+
+    pub(super) fn debug(&self, debug: &mut std::fmt::DebugStruct<'_, '_>) {
+        debug
+            .field("width", &self.width())
+            .field("height", &self.height())
+            .field("fps", &self.fps())
+            .field("sample_rate", &self.sample_rate())
+            .field("channels", &self.channels());
+    }
+}
+
+impl std::fmt::Debug for Stream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug = f.debug_struct("MuxedStream");
+        self.common.debug(&mut debug);
+        self.debug(&mut debug);
+        debug.finish()
+    }
+}
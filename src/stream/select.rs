@@ -0,0 +1,97 @@
+use super::{Codec, Container, Stream, VideoQuality};
+
+/// A builder selecting the best [`Stream`] matching some criteria, see
+/// [`StreamsExt::select`](super::StreamsExt::select).
+///
+/// Streams are ranked by:
+///
+/// 1. Having a [`prefer_codec`](Self::prefer_codec) codec
+/// 2. Matching [`prefer_hdr`](Self::prefer_hdr)
+/// 3. Their [`VideoQuality`], audio streams having none
+/// 4. Their frames per second
+/// 5. Their bitrate
+#[derive(Debug, Clone)]
+pub struct Selection<I> {
+    streams: I,
+    container: Option<Container>,
+    max_height: Option<u64>,
+    codec: Option<Codec>,
+    hdr: Option<bool>,
+}
+
+impl<I: Iterator<Item = Stream>> Selection<I> {
+    pub(super) fn new(streams: I) -> Self {
+        Self {
+            streams,
+            container: None,
+            max_height: None,
+            codec: None,
+            hdr: None,
+        }
+    }
+
+    /// Only select streams in `container`
+    pub fn container(mut self, container: Container) -> Self {
+        self.container = Some(container);
+        self
+    }
+
+    /// Only select streams with at most `height` lines of video. Audio
+    /// streams are not affected.
+    pub fn max_height(mut self, height: u64) -> Self {
+        self.max_height = Some(height);
+        self
+    }
+
+    /// Prefer streams using `codec` over better streams using other codecs
+    pub fn prefer_codec(mut self, codec: Codec) -> Self {
+        self.codec = Some(codec);
+        self
+    }
+
+    /// Prefer HDR video streams if `hdr` is `true`, or avoid them in favor
+    /// of SDR ones if it is `false`
+    pub fn prefer_hdr(mut self, hdr: bool) -> Self {
+        self.hdr = Some(hdr);
+        self
+    }
+
+    /// The best [`Stream`] matching all criteria
+    pub fn best(self) -> Option<Stream> {
+        let Self {
+            streams,
+            container,
+            max_height,
+            codec,
+            hdr,
+        } = self;
+
+        streams
+            .filter(|x| container.as_ref().is_none_or(|c| &x.container() == c))
+            .filter(|x| max_height.is_none_or(|max| height(x).is_none_or(|h| h <= max)))
+            .max_by_key(|x| {
+                let preferred = codec.as_ref().is_some_and(|c| x.codecs().contains(c));
+                let is_hdr = matches!(x, Stream::Video(video) if video.is_hdr());
+                (preferred, hdr.is_none_or(|hdr| hdr == is_hdr), rank(x))
+            })
+    }
+}
+
+fn height(stream: &Stream) -> Option<u64> {
+    match stream {
+        Stream::Audio(_) => None,
+        Stream::Video(video) => Some(video.height()),
+        Stream::Muxed(muxed) => Some(muxed.height()),
+    }
+}
+
+/// The quality, frames per second and bitrate of a [`Stream`]
+pub(super) fn rank(stream: &Stream) -> (Option<VideoQuality>, u64, u64) {
+    let (quality, fps) = match stream {
+        Stream::Audio(_) => (None, 0),
+        Stream::Video(video) => (Some(video.quality()), video.fps()),
+        Stream::Muxed(muxed) => (Some(muxed.quality()), muxed.fps()),
+    };
+
+    (quality, fps, stream.bitrate())
+}
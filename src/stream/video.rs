@@ -1,4 +1,4 @@
-use crate::youtube::player_response::VideoFormat;
+use crate::youtube::player_response::{self, VideoFormat};
 
 /// A [`Stream`](super::Stream) specifically containing video data.
 #[derive(Clone)]
@@ -31,11 +31,254 @@ impl Stream {
         self.video.fps
     }
 
+    /// The quality label of a [`Stream`] as shown by YouTube, e.g. `1080p60`
+    /// or `2160p HDR`
+    pub fn quality_label(&self) -> &str {
+        &self.video.quality_label
+    }
+
+    /// The [`VideoQuality`] of a [`Stream`], without its frame rate, see
+    /// [`fps`](Self::fps)
+    pub fn quality(&self) -> VideoQuality {
+        VideoQuality::new(&self.video)
+    }
+
+    /// The [`Projection`] of a [`Stream`]
+    pub fn projection(&self) -> Projection {
+        self.video
+            .projection_type
+            .as_deref()
+            .map_or(Projection::Rectangular, Projection::new)
+    }
+
+    /// The [`StereoLayout`] of a [`Stream`], [`None`] if it is not
+    /// stereoscopic
+    pub fn stereo_layout(&self) -> Option<StereoLayout> {
+        match self.video.stereo_layout.as_deref() {
+            Some(layout) => StereoLayout::new(layout),
+            // Older 3D formats only carry the layout in their projection
+            None => self
+                .video
+                .projection_type
+                .as_deref()?
+                .ends_with("_THREED_TOP_BOTTOM")
+                .then_some(StereoLayout::TopBottom),
+        }
+    }
+
+    /// The [`ColorInfo`] of a [`Stream`]
+    pub fn color_info(&self) -> Option<ColorInfo<'_>> {
+        self.video.color_info.as_ref().map(ColorInfo)
+    }
+
+    /// Is a [`Stream`] HDR, see [`ColorInfo::is_hdr`]?
+    pub fn is_hdr(&self) -> bool {
+        self.color_info().is_some_and(|x| x.is_hdr())
+    }
+
     pub(super) fn debug(&self, debug: &mut std::fmt::DebugStruct<'_, '_>) {
         debug
             .field("width", &self.width())
             .field("height", &self.height())
-            .field("fps", &self.fps());
+            .field("fps", &self.fps())
+            .field("quality_label", &self.quality_label())
+            .field("projection", &self.projection())
+            .field("stereo_layout", &self.stereo_layout())
+            .field("color_info", &self.color_info());
+    }
+}
+
+/// The color information of a video [`Stream`]
+#[derive(Clone, Copy)]
+pub struct ColorInfo<'a>(&'a player_response::ColorInfo);
+
+impl<'a> ColorInfo<'a> {
+    /// The color primaries of a [`ColorInfo`], e.g. `COLOR_PRIMARIES_BT2020`
+    pub fn primaries(&self) -> Option<&'a str> {
+        self.0.primaries.as_deref()
+    }
+
+    /// The transfer characteristics of a [`ColorInfo`], e.g.
+    /// `COLOR_TRANSFER_CHARACTERISTICS_SMPTEST2084`
+    pub fn transfer_characteristics(&self) -> Option<&'a str> {
+        self.0.transfer_characteristics.as_deref()
+    }
+
+    /// The matrix coefficients of a [`ColorInfo`], e.g.
+    /// `COLOR_MATRIX_COEFFICIENTS_BT2020_NCL`
+    pub fn matrix_coefficients(&self) -> Option<&'a str> {
+        self.0.matrix_coefficients.as_deref()
+    }
+
+    /// Are the transfer characteristics of a [`ColorInfo`] HDR, either PQ
+    /// (`SMPTEST2084`) or HLG (`ARIB_STD_B67`)?
+    pub fn is_hdr(&self) -> bool {
+        matches!(
+            self.transfer_characteristics(),
+            Some("COLOR_TRANSFER_CHARACTERISTICS_SMPTEST2084")
+                | Some("COLOR_TRANSFER_CHARACTERISTICS_ARIB_STD_B67")
+        )
+    }
+}
+
+impl<'a> std::fmt::Debug for ColorInfo<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ColorInfo")
+            .field("primaries", &self.primaries())
+            .field("transfer_characteristics", &self.transfer_characteristics())
+            .field("matrix_coefficients", &self.matrix_coefficients())
+            .finish()
+    }
+}
+
+/// How the frames of a video [`Stream`] are projected.
+///
+/// Anything but [`Projection::Rectangular`] needs a VR or 360° renderer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Projection {
+    /// A regular, flat video
+    Rectangular,
+    /// A 360° video in the equirectangular projection
+    Equirectangular,
+    /// A VR video projected onto a mesh, e.g. VR180
+    Mesh,
+    /// Any other projection, with the value YouTube returned
+    Other(String),
+}
+
+impl Projection {
+    fn new(projection: &str) -> Self {
+        match projection {
+            "RECTANGULAR" => Self::Rectangular,
+            "MESH" => Self::Mesh,
+            x if x.starts_with("EQUIRECTANGULAR") => Self::Equirectangular,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+/// How the views of both eyes are arranged in a stereoscopic video
+/// [`Stream`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum StereoLayout {
+    /// The left eye on top, the right eye on the bottom
+    TopBottom,
+    /// The left eye on the left, the right eye on the right
+    LeftRight,
+    /// Any other layout, with the value YouTube returned
+    Other(String),
+}
+
+impl StereoLayout {
+    fn new(layout: &str) -> Option<Self> {
+        match layout {
+            "STEREO_LAYOUT_MONO" | "STEREO_LAYOUT_UNKNOWN" => None,
+            "STEREO_LAYOUT_TOP_BOTTOM" => Some(Self::TopBottom),
+            "STEREO_LAYOUT_LEFT_RIGHT" => Some(Self::LeftRight),
+            other => Some(Self::Other(other.to_string())),
+        }
+    }
+}
+
+/// The resolution class of a video [`Stream`], ordered from worst to best.
+///
+/// # Example
+///
+/// ```rust
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use ytextract::{stream::VideoQuality, Stream};
+///
+/// let client = ytextract::Client::new();
+///
+/// let best = client
+///     .streams("nI2e-J6fsuk".parse()?)
+///     .await?
+///     .filter_map(Stream::into_video)
+///     .filter(|x| x.quality() <= VideoQuality::P1080)
+///     .max_by_key(|x| x.quality());
+///
+/// assert!(best.is_some());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum VideoQuality {
+    /// 144p
+    P144,
+    /// 240p
+    P240,
+    /// 360p
+    P360,
+    /// 480p
+    P480,
+    /// 720p
+    P720,
+    /// 1080p
+    P1080,
+    /// 1440p
+    P1440,
+    /// 2160p, 4K
+    P2160,
+    /// 4320p, 8K
+    P4320,
+}
+
+impl VideoQuality {
+    const ALL: [Self; 9] = [
+        Self::P144,
+        Self::P240,
+        Self::P360,
+        Self::P480,
+        Self::P720,
+        Self::P1080,
+        Self::P1440,
+        Self::P2160,
+        Self::P4320,
+    ];
+
+    pub(super) fn new(format: &VideoFormat) -> Self {
+        Self::from_label(&format.quality_label)
+            // The label describes the shorter side, e.g. of vertical videos
+            .unwrap_or_else(|| Self::from_lines(format.width.min(format.height)))
+    }
+
+    /// Parse a quality label like `1080p60` or `2160p HDR`.
+    ///
+    /// [`None`] if `label` does not start with `<lines>p`.
+    pub fn from_label(label: &str) -> Option<Self> {
+        let lines = label.split_once('p')?.0.parse().ok()?;
+        Some(Self::from_lines(lines))
+    }
+
+    /// The best [`VideoQuality`] with at most `lines` lines, e.g. `P1080`
+    /// for `1088`.
+    pub fn from_lines(lines: u64) -> Self {
+        Self::ALL
+            .iter()
+            .rev()
+            .copied()
+            .find(|x| x.lines() <= lines)
+            .unwrap_or(Self::P144)
+    }
+
+    /// The amount of lines of a [`VideoQuality`], e.g. `1080` for `P1080`
+    pub fn lines(self) -> u64 {
+        match self {
+            Self::P144 => 144,
+            Self::P240 => 240,
+            Self::P360 => 360,
+            Self::P480 => 480,
+            Self::P720 => 720,
+            Self::P1080 => 1080,
+            Self::P1440 => 1440,
+            Self::P2160 => 2160,
+            Self::P4320 => 4320,
+        }
     }
 }
 
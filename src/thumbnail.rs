@@ -1,11 +1,19 @@
+use std::cmp::Ordering;
+
 use reqwest::Url;
 
 /// A Thumbnail.
+///
+/// [`Thumbnails`](Thumbnail) are ordered by their area, then their width. Use
+/// [`Thumbnail::same_image`] to compare them while ignoring the size suffix of
+/// their urls.
 #[serde_with::serde_as]
-#[derive(Debug, serde::Deserialize, Clone, PartialEq, Eq)]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Thumbnail {
     /// The [`Url`] where the [`Thumbnail`] can be found.
     #[serde_as(as = "serde_with::DisplayFromStr")]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
     pub url: Url,
 
     /// The width of the [`Thumbnail`]
@@ -14,3 +22,86 @@ pub struct Thumbnail {
     /// The height of the [`Thumbnail`]
     pub height: u64,
 }
+
+impl Thumbnail {
+    /// Is this [`Thumbnail`] one of the grey placeholder images YouTube uses
+    /// when there is no real thumbnail, e.g. for auto-generated playlists?
+    pub fn is_placeholder(&self) -> bool {
+        let path = self.url.path();
+
+        path.contains("no_thumbnail")
+            // A video thumbnail without a video id, e.g. `/vi//default.jpg`
+            || path.contains("//")
+            || (self.url.host_str() == Some("img.youtube.com") && path.ends_with("/default.jpg"))
+    }
+
+    /// Is this the same image as `other`, possibly in a different size?
+    ///
+    /// Unlike `==`, this ignores the size suffix of `ggpht.com` and
+    /// `googleusercontent.com` urls, e.g. `=s88-c-k-c0x00ffffff-no-rj`.
+    pub fn same_image(&self, other: &Self) -> bool {
+        self.canonical_url() == other.canonical_url()
+    }
+
+    /// The [`Url`] without a size suffix, see [`Thumbnail::same_image`]
+    fn canonical_url(&self) -> &str {
+        let url = self.url.as_str();
+        let resizable = self.url.host_str().is_some_and(|host| {
+            host.ends_with("ggpht.com") || host.ends_with("googleusercontent.com")
+        });
+
+        match url.rfind('=') {
+            Some(index) if resizable && self.url.query().is_none() => &url[..index],
+            _ => url,
+        }
+    }
+
+    /// Does this [`Thumbnail`] exist?
+    ///
+    /// Sends a `HEAD` request to the [`Url`]. Useful for the thumbnails of
+    /// [`video::Id::thumbnails`](crate::video::Id::thumbnails), as not every
+    /// video has all of them.
+    pub async fn exists(&self, client: &crate::Client) -> crate::Result<bool> {
+        let res = client.api.http.head(self.url.clone()).send().await?;
+
+        if res.status() == reqwest::StatusCode::NOT_FOUND {
+            Ok(false)
+        } else {
+            res.error_for_status()?;
+            Ok(true)
+        }
+    }
+}
+
+impl PartialOrd for Thumbnail {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Thumbnail {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.width * self.height)
+            .cmp(&(other.width * other.height))
+            .then(self.width.cmp(&other.width))
+            // Keep the ordering consistent with equality
+            .then(self.height.cmp(&other.height))
+            .then_with(|| self.url.cmp(&other.url))
+    }
+}
+
+/// Remove [`Thumbnails`](Thumbnail) of the [same image](Thumbnail::same_image),
+/// e.g. the same avatar reported with different sizes, and sort them from
+/// smallest to largest.
+///
+/// Of duplicates, the largest one is kept.
+pub fn dedup_thumbnails(mut thumbnails: Vec<Thumbnail>) -> Vec<Thumbnail> {
+    // Largest first, so the largest of duplicates is kept
+    thumbnails.sort_by(|a, b| b.cmp(a));
+
+    let mut seen = std::collections::HashSet::new();
+    thumbnails.retain(|x| seen.insert(x.canonical_url().to_string()));
+
+    thumbnails.reverse();
+    thumbnails
+}
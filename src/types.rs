@@ -0,0 +1,21 @@
+//! All small, owned data types of this crate in one place.
+//!
+//! Unlike [`Video`](crate::Video) or [`Channel`](crate::Channel), which wrap a
+//! response and a [`Client`](crate::Client), these are plain data that
+//! implement [`Serialize`](serde::Serialize), [`Deserialize`](serde::Deserialize),
+//! [`Clone`] and [`PartialEq`].
+//!
+//! With the `schemars` feature enabled, they also implement
+//! [`JsonSchema`](https://docs.rs/schemars/0.8/schemars/trait.JsonSchema.html)
+//! so JSON schemas can be generated for them. The doc comments of the types
+//! and their fields end up as the descriptions in the schemas.
+
+pub use crate::{
+    channel::{Badge, Id as ChannelId},
+    playlist::Id as PlaylistId,
+    thumbnail::Thumbnail,
+    video::{
+        caption::CaptionCue, Chapter, Counts, EmbedInfo, Id as VideoId, IdMismatch, LiveDetails,
+        LiveStatus, MusicMetadata, RichMetadata, RichMetadataKind, TranscriptSegment,
+    },
+};
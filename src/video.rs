@@ -7,6 +7,13 @@
 //! [`Streams`](crate::Stream). These contain URLs to download videos,
 //! along with metadata about dimensions and fps.
 //!
+//! # Ratings
+//!
+//! YouTube no longer publishes dislike counts, so only likes are available
+//! with [`Video::likes`]. Whether a video accepts ratings at all is reported
+//! by [`Video::ratings_allowed`]; if it does not, [`Video::likes`] is
+//! [`None`].
+//!
 //! # Example
 //!
 //! ```rust
@@ -20,10 +27,29 @@
 //! # }
 //! ```
 
+pub mod caption;
+mod card;
+mod comment;
+mod end_screen;
+mod playback;
 pub mod related;
+pub mod storyboard;
+
+pub use self::caption::Caption;
+pub use self::card::Card;
+pub use self::comment::Comment;
+pub use self::end_screen::EndScreenElement;
+pub use self::playback::PlaybackTracking;
+pub use self::storyboard::Storyboard;
 
 use crate::{
-    youtube::{innertube::Next, next, player_response::PlayerResponse},
+    youtube::{
+        innertube::Next,
+        next::{self, WatchNextData},
+        parse_iso_date,
+        player_response::{PlayerMicroformatRenderer, PlayerResponse, StreamingData},
+        transcript,
+    },
     Client, Stream, Thumbnail,
 };
 
@@ -36,18 +62,132 @@ use std::time::Duration;
 pub struct Video {
     player_response: PlayerResponse,
     initial_data: next::Root,
+    // Only set if the WEB `next` response was unusable
+    android: Option<next::android::Root>,
+    requested_id: Id,
+    playlist: Option<crate::playlist::Id>,
+    // When `player_response` was fetched, to tell if its streams expired
+    fetched: std::time::Instant,
+    fetched_at: chrono::DateTime<chrono::Utc>,
+    // Cached result of `Video::is_short`
+    is_short: std::sync::OnceLock<bool>,
     client: Client,
 }
 
 impl Video {
-    pub(crate) async fn get(client: Client, id: Id) -> crate::Result<Self> {
+    pub(crate) async fn get(
+        client: Client,
+        id: Id,
+        playlist: Option<crate::playlist::Id>,
+    ) -> crate::Result<Self> {
+        let player_response: PlayerResponse = client.api.player(id).await?.into_std()?;
+        let returned = player_response.video_details.video_id;
+        client.id_mismatch.check(id, returned)?;
+
+        let initial_data: crate::Result<next::Root> = client
+            .api
+            .next(Next::Video {
+                id: returned,
+                playlist: playlist.clone(),
+            })
+            .await;
+
+        // The WEB layout changes often, fall back to the ANDROID layout if it
+        // can not be used
+        let (initial_data, android) = match initial_data {
+            Ok(initial_data) if initial_data.is_complete() => (initial_data, None),
+            Ok(_) | Err(crate::Error::Deserialization { .. }) => {
+                match client.api.next_android(returned).await {
+                    Ok(android) => (initial_data.unwrap_or_default(), Some(android)),
+                    Err(err) => {
+                        log::warn!("ANDROID next fallback failed: {}", err);
+                        (initial_data?, None)
+                    }
+                }
+            }
+            Err(err) => return Err(err),
+        };
+
         Ok(Self {
-            player_response: client.api.player(id).await?.into_std()?,
-            initial_data: client.api.next(Next::Video(id)).await?,
+            player_response,
+            initial_data,
+            android,
+            requested_id: id,
+            playlist,
+            fetched: std::time::Instant::now(),
+            fetched_at: chrono::Utc::now(),
+            is_short: std::sync::OnceLock::new(),
             client,
         })
     }
 
+    /// Build a [`Video`] from responses that were fetched elsewhere.
+    ///
+    /// The [`Client`] is only used by accessors that request more data, e.g.
+    /// [`Video::streams`]. Fails with [`Error::IdMismatch`](crate::Error::IdMismatch)
+    /// if the responses are for different videos.
+    ///
+    /// The `next` response may be one of the WEB or the ANDROID client.
+    #[cfg(feature = "unstable")]
+    pub fn from_parts(parts: Parts, client: Client) -> crate::Result<Self> {
+        use crate::youtube::player_response;
+
+        let player_response = client
+            .api
+            .deserialize::<player_response::Result<PlayerResponse>>(
+                "player",
+                &parts.player_response,
+            )?
+            .into_std()?;
+
+        let initial_data: crate::Result<next::Root> =
+            client.api.deserialize("next", &parts.next_response);
+        let (initial_data, android) = match initial_data {
+            Ok(initial_data) if initial_data.is_complete() => (initial_data, None),
+            initial_data => match client
+                .api
+                .deserialize::<next::android::Root>("next", &parts.next_response)
+            {
+                Ok(android) => (initial_data.unwrap_or_default(), Some(android)),
+                Err(_) => (initial_data?, None),
+            },
+        };
+
+        let id = player_response.video_details.video_id;
+        let endpoint = initial_data
+            .current_video_endpoint
+            .as_ref()
+            .or_else(|| android.as_ref()?.current_video_endpoint.as_ref());
+        if let Some(endpoint) = endpoint {
+            let returned = endpoint.watch_endpoint.video_id;
+            if returned != id {
+                return Err(crate::Error::IdMismatch {
+                    requested: id,
+                    returned,
+                });
+            }
+        }
+
+        Ok(Self {
+            player_response,
+            initial_data,
+            android,
+            requested_id: id,
+            playlist: None,
+            fetched: std::time::Instant::now(),
+            fetched_at: chrono::Utc::now(),
+            is_short: std::sync::OnceLock::new(),
+            client,
+        })
+    }
+
+    fn watch_next(&self) -> &dyn WatchNextData {
+        match &self.android {
+            Some(android) => android,
+            None => &self.initial_data,
+        }
+    }
+
     /// The title of a [`Video`].
     pub fn title(&self) -> &str {
         &self.player_response.video_details.title
@@ -58,9 +198,40 @@ impl Video {
         self.player_response.video_details.video_id
     }
 
+    /// Refetch a [`Video`] in place, e.g. to update its [`views`](Self::views)
+    /// and [`likes`](Self::likes).
+    ///
+    /// The [`requested id`](Self::requested_id) and the
+    /// [`playlist context`](Self::playlist_context) are reused. Returns the
+    /// [`Video`] as it was before the refresh, so callers can compare the two
+    /// to find out what changed.
+    pub async fn refresh(&mut self) -> crate::Result<Self> {
+        let fresh = Self::get(
+            self.client.clone(),
+            self.requested_id,
+            self.playlist.clone(),
+        )
+        .await?;
+        Ok(std::mem::replace(self, fresh))
+    }
+
+    /// The [`Id`] that was requested for a [`Video`].
+    ///
+    /// This only differs from [`Video::id`] if YouTube answered with a
+    /// different video, see [`IdMismatch`].
+    pub fn requested_id(&self) -> Id {
+        self.requested_id
+    }
+
     /// The [`Duration`] of a [`Video`].
-    pub fn duration(&self) -> Duration {
-        self.player_response.video_details.length_seconds
+    ///
+    /// [`None`] if the [`Video`] is a livestream or if YouTube does not know
+    /// the duration yet, e.g. for upcoming premieres.
+    pub fn duration(&self) -> Option<Duration> {
+        self.player_response
+            .video_details
+            .length_seconds
+            .filter(|x| !x.is_zero())
     }
 
     /// The keyword/tags of a [`Video`].
@@ -70,21 +241,12 @@ impl Video {
 
     /// The [`Channel`] of a [`Video`].
     pub fn channel(&self) -> Channel<'_> {
-        let owner = &self
-            .initial_data
-            .contents
-            .two_column_watch_next_results
-            .results
-            .results
-            .secondary()
-            .owner
-            .video_owner_renderer;
         Channel {
             client: &self.client,
             id: self.player_response.video_details.channel_id,
             name: &self.player_response.video_details.author,
-            subscribers: owner.subscribers(),
-            thumbnails: owner.thumbnails(),
+            subscribers: self.watch_next().subscribers(),
+            thumbnails: self.watch_next().owner_thumbnails(),
         }
     }
 
@@ -98,26 +260,212 @@ impl Video {
         self.player_response.video_details.view_count
     }
 
+    /// Can a [`Video`] be rated?
+    pub fn ratings_allowed(&self) -> bool {
+        self.player_response
+            .video_details
+            .allow_ratings
+            .unwrap_or_else(|| self.likes().is_some())
+    }
+
     /// The amount of likes a [`Video`] received.
+    ///
+    /// [`None`] if [ratings are not allowed](Self::ratings_allowed).
     pub fn likes(&self) -> Option<u64> {
-        self.initial_data
-            .contents
-            .two_column_watch_next_results
-            .results
-            .results
-            .primary()
-            .likes()
+        if self.player_response.video_details.allow_ratings == Some(false) {
+            return None;
+        }
+
+        self.watch_next().likes()
+    }
+
+    /// All counters of a [`Video`] at once, stamped with the current time.
+    ///
+    /// The time is when this method is called, not when the [`Video`] was
+    /// fetched.
+    pub fn counts(&self) -> Counts {
+        Counts {
+            views: self.views(),
+            likes: self.likes(),
+            comments: self.comment_count(),
+            channel_subscribers: self.channel().subscribers(),
+            observed_at: chrono::Utc::now(),
+        }
     }
 
     /// The hashtags a [`Video`] is tagged with.
     pub fn hashtags(&self) -> impl Iterator<Item = &str> {
-        self.initial_data
-            .contents
-            .two_column_watch_next_results
-            .results
-            .results
-            .primary()
-            .hashtags()
+        self.watch_next().hashtags().into_iter()
+    }
+
+    /// The rich metadata rows of a [`Video`], e.g. the game played in a
+    /// gaming video.
+    pub fn rich_metadata(&self) -> Vec<RichMetadata> {
+        self.watch_next()
+            .rich_metadata()
+            .into_iter()
+            .map(RichMetadata::new)
+            .collect()
+    }
+
+    /// The music featured in a [`Video`], e.g. the song and artist of a
+    /// music video.
+    ///
+    /// [`None`] if the [`Video`] has no music information.
+    pub fn music(&self) -> Option<MusicMetadata> {
+        let mut music = MusicMetadata::default();
+
+        for row in self.initial_data.music_rows() {
+            let field = match row.title.to_lowercase().as_str() {
+                "song" => &mut music.song,
+                "artist" => {
+                    if music.artist.is_none() {
+                        music.artist_id = row.link.and_then(|x| x.parse().ok());
+                    }
+                    &mut music.artist
+                }
+                "album" => &mut music.album,
+                "licenses" | "licensed to youtube by" => &mut music.licenses,
+                "writers" | "writer" => &mut music.writers,
+                _ => continue,
+            };
+            field.get_or_insert(row.value);
+        }
+
+        (music != MusicMetadata::default()).then_some(music)
+    }
+
+    /// The location a [`Video`] was recorded at, as set by the uploader.
+    ///
+    /// [`None`] for most videos.
+    pub fn location(&self) -> Option<&str> {
+        self.initial_data.location()
+    }
+
+    /// The [`Chapters`](Chapter) of a [`Video`], sorted by their start.
+    ///
+    /// This is empty if the [`Video`] has no chapters.
+    pub fn chapters(&self) -> Vec<Chapter> {
+        let mut chapters: Vec<_> = self
+            .initial_data
+            .chapters()
+            .map(|chapter| Chapter {
+                title: chapter.title.simple_text.clone(),
+                start: Duration::from_millis(chapter.time_range_start_millis),
+                thumbnails: chapter.thumbnail.thumbnails.clone(),
+            })
+            .collect();
+        chapters.sort_by_key(|x| x.start);
+        chapters
+    }
+
+    /// The transcript of a [`Video`], as shown in the transcript panel on the
+    /// watch page.
+    ///
+    /// Unlike [`Captions`](Caption), this is already segmented into
+    /// sentences. Returns [`Youtube::TranscriptUnavailable`](crate::error::Youtube::TranscriptUnavailable)
+    /// if the [`Video`] has no transcript.
+    pub async fn transcript(&self) -> crate::Result<Vec<TranscriptSegment>> {
+        let unavailable = || crate::Error::Youtube(crate::error::Youtube::TranscriptUnavailable);
+
+        let params = self
+            .initial_data
+            .transcript_params()
+            .ok_or_else(unavailable)?;
+        let segments = self
+            .client
+            .api
+            .transcript(params.to_string())
+            .await?
+            .into_segments()
+            .ok_or_else(unavailable)?;
+
+        let chapters = self.chapters();
+
+        Ok(segments
+            .into_iter()
+            .filter_map(|x| match x {
+                transcript::Segment::TranscriptSegmentRenderer(segment) => Some(segment),
+                _ => None,
+            })
+            .map(|segment| {
+                let start = Duration::from_millis(segment.start_ms);
+                TranscriptSegment {
+                    start,
+                    text: segment.snippet.text(),
+                    chapter: chapters
+                        .iter()
+                        .rev()
+                        .find(|x| x.start <= start)
+                        .map(|x| x.title.clone()),
+                }
+            })
+            .collect())
+    }
+
+    /// The [`Storyboards`](Storyboard) of a [`Video`], from the lowest to the
+    /// highest level of detail.
+    ///
+    /// This is empty for livestreams.
+    pub fn storyboards(&self) -> Vec<Storyboard> {
+        self.player_response
+            .storyboards
+            .as_ref()
+            .and_then(|x| x.player_storyboard_spec_renderer.as_ref())
+            .map(|x| Storyboard::from_spec(&x.spec, self.duration().unwrap_or_default()))
+            .unwrap_or_default()
+    }
+
+    /// The [`EndScreenElements`](EndScreenElement) shown at the end of a
+    /// [`Video`].
+    pub fn end_screen(&self) -> Vec<EndScreenElement> {
+        self.player_response
+            .endscreen
+            .iter()
+            .flat_map(|x| &x.endscreen_renderer.elements)
+            .map(|x| EndScreenElement::new(&x.endscreen_element_renderer))
+            .collect()
+    }
+
+    /// The info [`Cards`](Card) that pop up while watching a [`Video`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ytextract::Client::new();
+    ///
+    /// let video = client.video("7B2PIVSWtJA".parse()?).await?;
+    ///
+    /// for card in video.cards() {
+    ///     println!("{:?}: {}", card.cue(), card.teaser());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cards(&self) -> Vec<Card> {
+        self.player_response
+            .cards
+            .iter()
+            .flat_map(|x| &x.card_collection_renderer.cards)
+            .map(|x| Card::new(&x.card_renderer))
+            .collect()
+    }
+
+    /// The [`PlaybackTracking`] of a [`Video`].
+    ///
+    /// This is strictly opt-in: nothing is reported to YouTube unless
+    /// [`PlaybackTracking::ping`] is called.
+    pub fn playback_tracking(&self) -> Option<PlaybackTracking> {
+        let tracking = self.player_response.playback_tracking.as_ref()?;
+
+        Some(PlaybackTracking {
+            playback_url: tracking.videostats_playback_url.base_url.clone(),
+            watchtime_url: tracking
+                .videostats_watchtime_url
+                .as_ref()
+                .map(|x| x.base_url.clone()),
+        })
     }
 
     /// If a [`Video`] is live (e.g. a Livestream) or if it was live in the
@@ -126,75 +474,407 @@ impl Video {
         self.player_response.video_details.is_live_content
     }
 
+    /// The amount of people waiting for an [upcoming](Self::is_upcoming)
+    /// premiere or livestream to start.
+    pub fn waiting_count(&self) -> Option<u64> {
+        self.watch_next().waiting()
+    }
+
+    /// The [`LiveStatus`] of a [`Video`].
+    pub fn live_status(&self) -> LiveStatus {
+        if self.is_upcoming() {
+            let countdown = self.watch_next().countdown();
+
+            LiveStatus::Upcoming {
+                starts_at: self.scheduled_start().or_else(|| {
+                    let countdown = crate::parse::countdown(countdown?)?;
+                    Some(chrono::Utc::now() + chrono::Duration::from_std(countdown).ok()?)
+                }),
+                countdown: countdown.map(String::from),
+            }
+        } else if self.live_details().is_some_and(|x| x.is_live_now) {
+            LiveStatus::Live
+        } else if self.live() {
+            LiveStatus::Ended
+        } else {
+            LiveStatus::NotLive
+        }
+    }
+
+    /// The [`LiveDetails`] of a [`Video`] that is or was [`live`](Self::live).
+    ///
+    /// Unlike [`Video::views`], [`LiveDetails::concurrent_viewers`] is the
+    /// amount of people watching a live broadcast right now.
+    pub fn live_details(&self) -> Option<LiveDetails> {
+        let details = self.microformat()?.live_broadcast_details.as_ref()?;
+
+        Some(LiveDetails {
+            is_live_now: details.is_live_now,
+            started_at: details.start_timestamp?,
+            ended_at: details.end_timestamp,
+            concurrent_viewers: self.watch_next().concurrent_viewers(),
+        })
+    }
+
     /// The [`Thumbnails`](Thumbnail) of a [`Video`]
     pub fn thumbnails(&self) -> &Vec<Thumbnail> {
         &self.player_response.video_details.thumbnail.thumbnails
     }
 
     /// The date a [`Video`] was published.
-    pub fn date(&self) -> chrono::NaiveDate {
-        self.initial_data
-            .contents
-            .two_column_watch_next_results
-            .results
-            .results
-            .primary()
-            .date()
+    ///
+    /// This is taken from the machine readable publish date if available and
+    /// only falls back to the date text shown on the watch page, which can
+    /// only be parsed in english.
+    ///
+    /// [`None`] if neither is available, e.g. for region-blocked videos
+    /// without a watch page, or the date text can not be parsed.
+    pub fn date(&self) -> Option<chrono::NaiveDate> {
+        self.microformat()
+            .and_then(|x| x.publish_date.as_deref())
+            .and_then(parse_iso_date)
+            .or_else(|| self.watch_next().date())
+    }
+
+    /// The date a [`Video`] was published, same as [`Video::date`].
+    pub fn published(&self) -> Option<chrono::NaiveDate> {
+        self.date()
+    }
+
+    /// When this [`Video`] was fetched from YouTube.
+    pub fn fetched_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.fetched_at
+    }
+
+    /// The date a [`Video`] was uploaded.
+    ///
+    /// This can differ from [`Video::date`], e.g. for premieres or videos that
+    /// were uploaded as private and published later.
+    pub fn upload_date(&self) -> Option<chrono::NaiveDate> {
+        self.microformat()?
+            .upload_date
+            .as_deref()
+            .and_then(parse_iso_date)
     }
 
     /// The [`Items`](Related) related to a [`Video`].
-    pub fn related(&self) -> Option<impl futures_core::Stream<Item = Related>> {
-        let initial_items = self
-            .initial_data
-            .contents
-            .two_column_watch_next_results
-            .secondary_results
-            .as_ref()?
-            .secondary_results
-            .items()?;
+    ///
+    /// If requesting the next page of items fails, its error is yielded and
+    /// the stream ends.
+    pub fn related(&self) -> Option<impl futures_core::Stream<Item = crate::Result<Related>>> {
+        let initial_items = self.initial_data.secondary_results()?.items()?;
         let client = self.client.clone();
 
         Some(async_stream::stream! {
             let mut items: Box<dyn Iterator<Item = next::SectionItem> + Send + Sync> =
                 Box::new(initial_items);
+            let mut continuation = None;
 
-            while let Some(item) = items.next() {
-                match item {
-                    next::SectionItem::ContinuationItemRenderer(continuation) => {
-                        assert!(
-                            items.next().is_none(),
-                            "Found a continuation in the middle of items!"
-                        );
-                        let response: next::Continuation = client
-                            .api
-                            .next(Next::Continuation(continuation.get()))
-                            .await
-                            .expect("Continuation request failed");
-
-                        items = Box::new(response.into_videos());
+            loop {
+                if let Some(continuation) = continuation.take() {
+                    let response: crate::Result<next::Continuation> = client
+                        .api
+                        .next(Next::Continuation(continuation))
+                        .await;
+                    match response {
+                        Ok(response) => items = Box::new(response.into_videos()),
+                        Err(err) => {
+                            yield Err(err);
+                            break;
+                        }
                     }
-                    next::SectionItem::CompactVideoRenderer(video) => {
-                        yield Related::Video(related::Video(video, client.clone()));
-                    }
-                    next::SectionItem::CompactPlaylistRenderer(playlist) => {
-                        yield Related::Playlist(related::Playlist(playlist, client.clone()));
+                }
+
+                for item in items.by_ref() {
+                    match item {
+                        next::SectionItem::ContinuationItemRenderer(next) => {
+                            // Usually the last item, items after it are still yielded
+                            if continuation.replace(next.get()).is_some() {
+                                log::warn!("Found multiple continuations in a page of related items, using the last");
+                            }
+                        }
+                        next::SectionItem::CompactVideoRenderer(video) => {
+                            yield Ok(Related::Video(related::Video(video, client.clone())));
+                        }
+                        next::SectionItem::CompactPlaylistRenderer(playlist) => {
+                            yield Ok(Related::Playlist(related::Playlist(playlist, client.clone())));
+                        }
+                        next::SectionItem::CompactRadioRenderer(radio) => {
+                            yield Ok(Related::Radio(related::Radio(radio, client.clone())));
+                        }
+                        next::SectionItem::CompactMovieRenderer(movie) => {
+                            yield Ok(Related::Movie(related::Movie(movie, client.clone())));
+                        },
+                        // I don't know what this is - just skip it
+                        next::SectionItem::Other => continue,
                     }
-                    next::SectionItem::CompactRadioRenderer(radio) => {
-                        yield Related::Radio(related::Radio(radio, client.clone()));
+                }
+
+                if continuation.is_none() {
+                    break;
+                }
+            }
+        })
+    }
+
+    /// The amount of comments a [`Video`] has.
+    ///
+    /// [`None`] if comments are turned off or the count can not be parsed.
+    pub fn comment_count(&self) -> Option<u64> {
+        self.initial_data.comment_count()
+    }
+
+    /// The top-level [`Comments`](Comment) of a [`Video`].
+    ///
+    /// The stream is empty if comments are turned off.
+    pub fn comments(&self) -> impl futures_core::Stream<Item = crate::Result<Comment>> {
+        let mut continuation = self
+            .initial_data
+            .results()
+            .and_then(|x| x.comments())
+            .map(|x| x.get());
+        let client = self.client.clone();
+
+        async_stream::stream! {
+            while let Some(token) = continuation.take() {
+                let response: next::CommentsContinuation =
+                    match client.api.next(Next::Continuation(token)).await {
+                        Ok(response) => response,
+                        Err(err) => {
+                            yield Err(err);
+                            break;
+                        }
+                    };
+
+                for item in response.into_items() {
+                    match item {
+                        next::CommentItem::CommentThreadRenderer(thread) => {
+                            if let Some(comment) = thread.comment {
+                                yield Ok(Comment(comment.comment_renderer));
+                            }
+                        }
+                        next::CommentItem::ContinuationItemRenderer(next) => {
+                            continuation = Some(next.get());
+                        }
+                        next::CommentItem::CommentsHeaderRenderer {} | next::CommentItem::Other => {}
                     }
-                    next::SectionItem::CompactMovieRenderer(movie) => {
-                        yield Related::Movie(related::Movie(movie, client.clone()));
-                    },
-                    // I don't know what this is - just skip it
-                    next::SectionItem::Other => continue,
                 }
             }
+        }
+    }
+
+    /// Does a [`Video`] include paid promotion, like product placement or
+    /// sponsorships, as disclosed by its uploader?
+    pub fn has_paid_promotion(&self) -> bool {
+        self.player_response
+            .paid_content_overlay
+            .as_ref()
+            .is_some_and(|x| x.paid_content_overlay_renderer.is_some())
+    }
+
+    /// Is a [`Video`] a Short?
+    ///
+    /// This checks whether the `/shorts/` URL of a [`Video`] is served
+    /// without a redirect to the regular watch page. The result is cached,
+    /// so only the first call sends a request.
+    ///
+    /// Fails with [`Youtube::UnexpectedRedirect`] if YouTube redirects
+    /// anywhere else, e.g. to its cookie consent page, as it is unknown then.
+    ///
+    /// [`Youtube::UnexpectedRedirect`]: crate::error::Youtube::UnexpectedRedirect
+    pub async fn is_short(&self) -> crate::Result<bool> {
+        if let Some(is_short) = self.is_short.get() {
+            return Ok(*is_short);
+        }
+
+        let response = self
+            .client
+            .api
+            .http
+            .head(self.client.api.web_url(&format!("shorts/{}", self.id())))
+            .send()
+            .await?
+            .error_for_status()?;
+
+        let url = response.url();
+        let is_short = if url.path().starts_with("/shorts/") {
+            true
+        } else if url.path() == "/watch" {
+            false
+        } else {
+            return Err(crate::error::Youtube::UnexpectedRedirect(url.clone()).into());
+        };
+
+        Ok(*self.is_short.get_or_init(|| is_short))
+    }
+
+    /// The [`Captions`](Caption) of a [`Video`].
+    pub fn captions(&self) -> impl Iterator<Item = Caption> + '_ {
+        self.player_response
+            .captions
+            .iter()
+            .flat_map(|x| &x.player_captions_tracklist_renderer.caption_tracks)
+            .map(move |track| Caption(track.clone(), self.client.clone()))
+    }
+
+    /// Is a [`Video`] a premiere or livestream that has not started yet?
+    pub fn is_upcoming(&self) -> bool {
+        self.player_response.video_details.is_upcoming
+    }
+
+    /// When an [upcoming](Self::is_upcoming) [`Video`] is scheduled to start.
+    pub fn scheduled_start(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.player_response
+            .playability_status
+            .live_streamability
+            .as_ref()?
+            .scheduled_start()
+    }
+
+    /// Is a [`Video`] suitable for all ages?
+    pub fn family_safe(&self) -> Option<bool> {
+        self.microformat()?.is_family_safe
+    }
+
+    /// Does YouTube require viewers of a [`Video`] to confirm their age?
+    ///
+    /// This is only known from the playability status, videos that are not
+    /// [`family_safe`](Self::family_safe) can still be watched without
+    /// confirming. The [`Streams`](Stream) of such a [`Video`] are requested
+    /// with an embedded player, see [`Video::streams`].
+    pub fn age_restricted(&self) -> bool {
+        self.player_response.playability_status.is_age_gated()
+    }
+
+    /// Can a [`Video`] be embedded on other websites?
+    pub fn embeddable(&self) -> bool {
+        self.player_response.playability_status.playable_in_embed
+    }
+
+    fn microformat(&self) -> Option<&PlayerMicroformatRenderer> {
+        self.player_response
+            .microformat
+            .as_ref()
+            .map(|x| &x.player_microformat_renderer)
+    }
+
+    /// The category of a [`Video`], e.g. `Music` or `Gaming`.
+    pub fn category(&self) -> Option<&str> {
+        self.microformat()?.category.as_deref()
+    }
+
+    /// The [`EmbedInfo`] of a [`Video`].
+    ///
+    /// This may be [`Some`] even if the [`Video`] is not
+    /// [`embeddable`](Self::embeddable).
+    pub fn embed_info(&self) -> Option<&EmbedInfo> {
+        self.microformat()?.embed.as_ref()
+    }
+
+    /// Generate a `<iframe>` tag embedding a [`Video`] with the given
+    /// dimensions.
+    pub fn embed_html(&self, width: u64, height: u64) -> Option<String> {
+        let embed = self.embed_info()?;
+
+        Some(format!(
+            r#"<iframe width="{}" height="{}" src="{}" frameborder="0" allowfullscreen></iframe>"#,
+            width,
+            height,
+            escape_html(embed.url.as_str())
+        ))
+    }
+
+    /// The [`Id`](crate::playlist::Id) of the playlist this [`Video`] was
+    /// fetched in the context of.
+    ///
+    /// This is only [`Some`] when the [`Video`] was requested with
+    /// [`Client::video_in_playlist`] or with [`Client::video_from_url`] and a
+    /// URL carrying a `list=` parameter.
+    pub fn playlist_context(&self) -> Option<&crate::playlist::Id> {
+        self.playlist.as_ref()
+    }
+
+    /// The streaming data returned together with a [`Video`], if it is still
+    /// valid for `margin`.
+    ///
+    /// The urls carry their own expiry, which also covers responses that were
+    /// fetched long before being passed to [`Video::from_parts`].
+    fn cached_streaming_data(&self, margin: Duration) -> Option<&StreamingData> {
+        self.player_response.streaming_data.as_ref().filter(|x| {
+            x.expires_in_seconds
+                .is_none_or(|expires| self.fetched.elapsed() + margin < expires)
+                && !x.is_expired()
         })
     }
 
     /// The [`Streams`](Stream) of a [`Video`]
+    ///
+    /// The streams that were returned together with a [`Video`] are reused
+    /// while they are valid. Otherwise, e.g. for age-restricted videos, they
+    /// are requested like with [`Client::streams`]. Either way, forbidden
+    /// streams are replaced as described in
+    /// [`ClientBuilder::stream_fallback`](crate::ClientBuilder::stream_fallback).
     pub async fn streams(&self) -> crate::Result<impl Iterator<Item = Stream>> {
-        crate::stream::get(self.client.clone(), self.id()).await
+        // Leave some time to actually download the streams
+        const MARGIN: Duration = Duration::from_secs(5 * 60);
+
+        let cached = self
+            .cached_streaming_data(MARGIN)
+            .filter(|x| !x.adaptive_formats.is_empty());
+
+        match cached {
+            Some(streaming_data) => {
+                let streaming_data =
+                    crate::stream::working(&self.client, self.id(), streaming_data.clone()).await;
+                Ok(crate::stream::from_streaming_data(
+                    streaming_data,
+                    self.id(),
+                    self.client.clone(),
+                ))
+            }
+            None => {
+                let streaming_data = crate::stream::fetch(self.client.clone(), self.id()).await?;
+                Ok(crate::stream::from_streaming_data(
+                    streaming_data,
+                    self.id(),
+                    self.client.clone(),
+                ))
+            }
+        }
+    }
+
+    /// The [`Dash`](crate::stream::Dash) representations of a [`Video`], see
+    /// [`Client::dash_streams`].
+    ///
+    /// The manifest that was returned together with a [`Video`] is reused
+    /// while it is valid.
+    pub async fn dash_streams(&self) -> crate::Result<Vec<crate::stream::Dash>> {
+        let cached = self
+            .cached_streaming_data(Duration::ZERO)
+            .filter(|x| x.dash_manifest_url.is_some());
+
+        match cached {
+            Some(streaming_data) => {
+                crate::stream::dash_representations(&self.client, streaming_data).await
+            }
+            None => crate::stream::get_dash(self.client.clone(), self.id()).await,
+        }
+    }
+
+    /// The [`Hls`](crate::stream::Hls) variants of a livestream, see
+    /// [`Client::hls_streams`].
+    ///
+    /// The manifest that was returned together with a [`Video`] is reused
+    /// while it is valid.
+    pub async fn hls_streams(&self) -> crate::Result<Vec<crate::stream::Hls>> {
+        let cached = self
+            .cached_streaming_data(Duration::ZERO)
+            .filter(|x| x.hls_manifest_url.is_some());
+
+        match cached {
+            Some(streaming_data) => crate::stream::hls_variants(&self.client, streaming_data).await,
+            None => crate::stream::get_hls(self.client.clone(), self.id()).await,
+        }
     }
 }
 
@@ -209,13 +889,35 @@ impl std::fmt::Debug for Video {
             .field("description", &self.description())
             .field("views", &self.views())
             .field("likes", &self.likes())
+            .field("ratings_allowed", &self.ratings_allowed())
+            .field("comment_count", &self.comment_count())
             .field("live", &self.live())
+            .field("is_upcoming", &self.is_upcoming())
             .field("thumbnails", &self.thumbnails())
+            .field("category", &self.category())
             .field("date", &self.date())
+            .field("upload_date", &self.upload_date())
+            .field("playlist_context", &self.playlist_context())
             .finish()
     }
 }
 
+/// Escape a string for use inside a HTML attribute.
+fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 impl PartialEq for Video {
     fn eq(&self, other: &Self) -> bool {
         self.id() == other.id()
@@ -224,13 +926,16 @@ impl PartialEq for Video {
 
 impl Eq for Video {}
 
+/// The suffix YouTube appends to the names of auto-generated topic channels
+const TOPIC_SUFFIX: &str = " - Topic";
+
 /// The uploader of a video
 pub struct Channel<'a> {
     client: &'a Client,
     id: crate::channel::Id,
     name: &'a str,
     subscribers: Option<u64>,
-    thumbnails: &'a Vec<Thumbnail>,
+    thumbnails: &'a [Thumbnail],
 }
 
 impl<'a> Channel<'a> {
@@ -244,6 +949,23 @@ impl<'a> Channel<'a> {
         self.name
     }
 
+    /// The [name](Self::name) of a [`Channel`] without the ` - Topic` suffix
+    /// of [auto-generated](Self::is_auto_generated) channels, e.g. `Artist`
+    /// instead of `Artist - Topic`.
+    pub fn display_name(&self) -> &str {
+        if self.is_auto_generated() {
+            &self.name[..self.name.len() - TOPIC_SUFFIX.len()]
+        } else {
+            self.name
+        }
+    }
+
+    /// Is this [`Channel`] a topic channel auto-generated by YouTube, e.g.
+    /// for the music of an artist?
+    pub fn is_auto_generated(&self) -> bool {
+        self.name.len() > TOPIC_SUFFIX.len() && self.name.ends_with(TOPIC_SUFFIX)
+    }
+
     /// The amount of subscribers a [`Channel`] has.
     pub fn subscribers(&self) -> Option<u64> {
         self.subscribers
@@ -255,6 +977,9 @@ impl<'a> Channel<'a> {
     }
 
     /// Refetch the channel to get more information
+    ///
+    /// To refetch the channels of many items, prefer
+    /// [`Client::channels_bulk`](crate::Client::channels_bulk).
     pub async fn upgrade(&self) -> crate::Result<crate::Channel> {
         self.client.channel(self.id).await
     }
@@ -265,6 +990,7 @@ impl<'a> std::fmt::Debug for Channel<'a> {
         f.debug_struct("Channel")
             .field("id", &self.id)
             .field("name", &self.name)
+            .field("is_auto_generated", &self.is_auto_generated())
             .field("subscribers", &self.subscribers)
             .field("thumbnails", &self.thumbnails)
             .finish()
@@ -281,13 +1007,348 @@ impl<'a> Eq for Channel<'a> {}
 
 define_id! {
     11,
+    Video,
+    "VideoId",
     "An Id describing a [`Video`]",
     [
         "watch?v=",
         "embed/",
+        "shorts/",
     ]
 }
 
+impl Id {
+    /// The [`Url`](reqwest::Url) of the thumbnail of a video in the given
+    /// [`ThumbnailQuality`], without requesting the video.
+    ///
+    /// [`ThumbnailQuality::MaxRes`] and [`ThumbnailQuality::Standard`] do not
+    /// exist for some, mostly older, videos. Use [`Thumbnail::exists`] to check.
+    pub fn thumbnail(&self, quality: ThumbnailQuality) -> reqwest::Url {
+        format!("https://i.ytimg.com/vi/{}/{}.jpg", self, quality.name())
+            .parse()
+            .expect("thumbnail url was invalid")
+    }
+
+    /// The [`Thumbnails`](Thumbnail) of a video in every [`ThumbnailQuality`]
+    /// from lowest to highest, without requesting the video.
+    ///
+    /// See [`Id::thumbnail`] for thumbnails that may not exist.
+    pub fn thumbnails(&self) -> Vec<Thumbnail> {
+        ThumbnailQuality::ALL
+            .iter()
+            .map(|quality| {
+                let (width, height) = quality.size();
+                Thumbnail {
+                    url: self.thumbnail(*quality),
+                    width,
+                    height,
+                }
+            })
+            .collect()
+    }
+}
+
+/// The quality of a thumbnail, see [`Id::thumbnail`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ThumbnailQuality {
+    /// 120x90
+    Default,
+    /// 320x180
+    Medium,
+    /// 480x360
+    High,
+    /// 640x480, does not exist for some videos
+    Standard,
+    /// 1280x720, does not exist for some videos
+    MaxRes,
+}
+
+impl ThumbnailQuality {
+    const ALL: [Self; 5] = [
+        Self::Default,
+        Self::Medium,
+        Self::High,
+        Self::Standard,
+        Self::MaxRes,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::Medium => "mqdefault",
+            Self::High => "hqdefault",
+            Self::Standard => "sddefault",
+            Self::MaxRes => "maxresdefault",
+        }
+    }
+
+    /// The width and height of a thumbnail in this quality
+    pub fn size(self) -> (u64, u64) {
+        match self {
+            Self::Default => (120, 90),
+            Self::Medium => (320, 180),
+            Self::High => (480, 360),
+            Self::Standard => (640, 480),
+            Self::MaxRes => (1280, 720),
+        }
+    }
+}
+
+/// The raw responses a [`Video`] is built from, see [`Video::from_parts`].
+#[cfg(feature = "unstable")]
+#[derive(Debug, Clone)]
+pub struct Parts {
+    /// The JSON response of the `player` endpoint
+    pub player_response: String,
+    /// The JSON response of the `next` endpoint
+    pub next_response: String,
+}
+
+/// How to handle YouTube answering for a different [`Video`] than the
+/// requested one.
+///
+/// This happens rarely, e.g. for reuploaded videos that redirect to their
+/// canonical [`Id`]. Set it with
+/// [`ClientBuilder::id_mismatch`](crate::ClientBuilder::id_mismatch).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum IdMismatch {
+    /// Follow the returned [`Id`]. The requested one is kept in
+    /// [`Video::requested_id`].
+    #[default]
+    Follow,
+    /// Fail with [`Error::IdMismatch`](crate::Error::IdMismatch).
+    Error,
+}
+
+impl IdMismatch {
+    pub(crate) fn check(self, requested: Id, returned: Id) -> crate::Result<()> {
+        if requested == returned {
+            return Ok(());
+        }
+
+        match self {
+            Self::Follow => {
+                log::warn!(
+                    "Requested video '{}' but YouTube returned '{}'",
+                    requested,
+                    returned
+                );
+                Ok(())
+            }
+            Self::Error => Err(crate::Error::IdMismatch {
+                requested,
+                returned,
+            }),
+        }
+    }
+}
+
+/// A chapter of a [`Video`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Chapter {
+    /// The title of this chapter
+    pub title: String,
+    /// The offset into the [`Video`] at which this chapter starts
+    pub start: Duration,
+    /// The [`Thumbnails`](Thumbnail) of this chapter
+    pub thumbnails: Vec<Thumbnail>,
+}
+
+/// A segment of the transcript of a [`Video`], see [`Video::transcript`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TranscriptSegment {
+    /// The offset into the [`Video`] at which this segment starts
+    pub start: Duration,
+    /// The text of this segment
+    pub text: String,
+    /// The title of the [`Chapter`] this segment falls under
+    pub chapter: Option<String>,
+}
+
+/// The music featured in a [`Video`], see [`Video::music`].
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MusicMetadata {
+    /// The title of the song
+    pub song: Option<String>,
+    /// The name of the artist
+    pub artist: Option<String>,
+    /// The [`Id`](crate::channel::Id) of the channel of the artist, if linked
+    pub artist_id: Option<crate::channel::Id>,
+    /// The album the song is on
+    pub album: Option<String>,
+    /// Who licensed the song to YouTube
+    pub licenses: Option<String>,
+    /// The writers of the song
+    pub writers: Option<String>,
+}
+
+/// The kind of [`RichMetadata`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub enum RichMetadataKind {
+    /// The game that is played in a [`Video`]
+    Game,
+    /// The music or artist featured in a [`Video`]
+    Music,
+    /// Any other kind of metadata
+    Other,
+}
+
+/// A rich metadata row shown below the title of a [`Video`].
+///
+/// This is for example the game that is played in a gaming video, together
+/// with its release year and a link to the topic page of the game.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RichMetadata {
+    /// The kind of this metadata
+    pub kind: RichMetadataKind,
+    /// The title, e.g. the name of the game
+    pub title: String,
+    /// The subtitle, e.g. the release year of the game
+    pub subtitle: Option<String>,
+    /// The browse id of the linked topic page
+    pub browse_id: Option<String>,
+    /// The [`Thumbnails`](Thumbnail) of this metadata, e.g. the box art
+    pub thumbnails: Vec<Thumbnail>,
+}
+
+impl RichMetadata {
+    fn new(renderer: &next::RichMetadataRenderer) -> Self {
+        let kind = match renderer.style.as_str() {
+            "RICH_METADATA_RENDERER_STYLE_BOX_ART" => RichMetadataKind::Game,
+            style if style.contains("MUSIC") => RichMetadataKind::Music,
+            _ => RichMetadataKind::Other,
+        };
+
+        Self {
+            kind,
+            title: renderer.title.text(),
+            subtitle: renderer.subtitle.as_ref().map(|x| x.text()),
+            browse_id: renderer
+                .endpoint
+                .as_ref()
+                .and_then(|x| x.browse_endpoint.as_ref())
+                .map(|x| x.browse_id.clone()),
+            thumbnails: renderer.thumbnail.thumbnails.clone(),
+        }
+    }
+}
+
+/// Whether a [`Video`] is a premiere or livestream, see [`Video::live_status`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub enum LiveStatus {
+    /// A regular upload
+    NotLive,
+    /// A premiere or livestream that has not started yet
+    Upcoming {
+        /// When it is scheduled to start. If YouTube does not return the
+        /// exact time, this is estimated from the `countdown`.
+        starts_at: Option<chrono::DateTime<chrono::Utc>>,
+        /// The countdown shown on the watch page, e.g. `Premieres in 35 minutes`
+        countdown: Option<String>,
+    },
+    /// A livestream that is live right now
+    Live,
+    /// A livestream that has ended
+    Ended,
+}
+
+/// The details of a live broadcast, see [`Video::live_details`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LiveDetails {
+    /// Is the broadcast live right now?
+    pub is_live_now: bool,
+    /// When the broadcast started, or is scheduled to start
+    pub started_at: chrono::DateTime<chrono::Utc>,
+    /// When the broadcast ended, [`None`] while it is still live
+    pub ended_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The amount of people watching the broadcast right now
+    pub concurrent_viewers: Option<u64>,
+}
+
+/// A snapshot of the counters of a [`Video`], see [`Video::counts`].
+///
+/// This is the recommended shape for persisting counters over time. New
+/// fields will only be added as optional fields.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Counts {
+    /// The amount of views, see [`Video::views`].
+    pub views: u64,
+    /// The amount of likes, see [`Video::likes`].
+    pub likes: Option<u64>,
+    /// The amount of comments, see [`Video::comment_count`].
+    pub comments: Option<u64>,
+    /// The amount of subscribers of the uploader, see [`Channel::subscribers`].
+    pub channel_subscribers: Option<u64>,
+    /// When these counts were observed.
+    pub observed_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Information on how to embed a [`Video`] on other websites.
+#[serde_with::serde_as]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct EmbedInfo {
+    /// The [`Url`](reqwest::Url) of the embed iframe.
+    #[serde(rename = "iframeUrl")]
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub url: reqwest::Url,
+
+    /// The canonical width of the embed.
+    pub width: u64,
+
+    /// The canonical height of the embed.
+    pub height: u64,
+}
+
+/// Extract the [`Id`] and the optional [`playlist::Id`](crate::playlist::Id)
+/// from a watch URL.
+///
+/// Naked ids and URLs without a `list=` parameter yield no playlist.
+pub(crate) fn parse_url(value: &str) -> Option<(Id, Option<crate::playlist::Id>)> {
+    let url = match reqwest::Url::parse(value) {
+        Ok(url) => url,
+        Err(_) => return value.parse().ok().map(|id| (id, None)),
+    };
+
+    let query = |key: &str| {
+        url.query_pairs()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.into_owned())
+    };
+
+    let id = query("v")
+        .or_else(|| url.path_segments()?.next_back().map(String::from))?
+        .parse()
+        .ok()?;
+    let playlist = query("list").and_then(|x| x.parse().ok());
+
+    Some((id, playlist))
+}
+
+/// Whether a [`Video`] is playable, see
+/// [`Client::is_available`](crate::Client::is_available).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Availability {
+    /// The video exists and can be played
+    Playable,
+
+    /// The video is private, deleted or can not be played for another reason
+    Unplayable(crate::error::Youtube),
+}
+
 /// A Item that is related to a [`Video`].
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Related {
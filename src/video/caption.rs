@@ -0,0 +1,225 @@
+//! Captions of a video.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use ytextract::video::caption::CaptionCues;
+//!
+//! let client = ytextract::Client::new();
+//!
+//! let video = client.video("nI2e-J6fsuk".parse()?).await?;
+//!
+//! if let Some(caption) = video.captions().next() {
+//!     let cues = caption.fetch().await?;
+//!     println!("{}", cues.to_srt());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Write, time::Duration};
+
+use reqwest::Url;
+
+use crate::{youtube::player_response::CaptionTrack, Client};
+
+/// A caption track of a [`Video`](super::Video)
+#[derive(Clone)]
+pub struct Caption(pub(super) CaptionTrack, pub(super) Client);
+
+impl Caption {
+    /// The name of this caption track, e.g. `English (auto-generated)`.
+    pub fn name(&self) -> String {
+        self.0.name.text()
+    }
+
+    /// The language code of this caption track, e.g. `en`.
+    pub fn language_code(&self) -> &str {
+        &self.0.language_code
+    }
+
+    /// Was this caption track generated automatically?
+    pub fn is_auto_generated(&self) -> bool {
+        self.0.kind.as_deref() == Some("asr")
+    }
+
+    /// Can this caption track be translated with [`Caption::fetch_translated`]?
+    pub fn is_translatable(&self) -> bool {
+        self.0.is_translatable
+    }
+
+    /// The [`Url`] of this caption track.
+    pub fn url(&self) -> Url {
+        self.0.base_url.clone()
+    }
+
+    /// Fetch the [`CaptionCues`](CaptionCue) of this caption track.
+    pub async fn fetch(&self) -> crate::Result<Vec<CaptionCue>> {
+        self.fetch_url(self.url()).await
+    }
+
+    /// Fetch the [`CaptionCues`](CaptionCue) of this caption track,
+    /// translated by YouTube into the language with the given code, e.g. `de`.
+    pub async fn fetch_translated(&self, language_code: &str) -> crate::Result<Vec<CaptionCue>> {
+        let mut url = self.url();
+        url.query_pairs_mut().append_pair("tlang", language_code);
+        self.fetch_url(url).await
+    }
+
+    async fn fetch_url(&self, mut url: Url) -> crate::Result<Vec<CaptionCue>> {
+        // Always request the `srv3` format, regardless of what the base url asks for
+        let pairs: Vec<_> = url
+            .query_pairs()
+            .filter(|(key, _)| key != "fmt")
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect();
+        url.query_pairs_mut()
+            .clear()
+            .extend_pairs(pairs)
+            .append_pair("fmt", "srv3");
+
+        let body = self
+            .1
+            .api
+            .http
+            .get(url)
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+
+        parse_srv3(&body)
+    }
+}
+
+impl std::fmt::Debug for Caption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Caption")
+            .field("name", &self.name())
+            .field("language_code", &self.language_code())
+            .field("is_auto_generated", &self.is_auto_generated())
+            .field("is_translatable", &self.is_translatable())
+            .finish()
+    }
+}
+
+/// A single cue of a [`Caption`]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CaptionCue {
+    /// When this cue is shown
+    pub start: Duration,
+    /// How long this cue is shown
+    pub duration: Duration,
+    /// The text of this cue
+    pub text: String,
+}
+
+/// Conversion of [`CaptionCues`](CaptionCue) into subtitle files
+pub trait CaptionCues {
+    /// Format as a [SubRip](https://en.wikipedia.org/wiki/SubRip) file
+    fn to_srt(&self) -> String;
+
+    /// Format as a [WebVTT](https://www.w3.org/TR/webvtt1/) file
+    fn to_vtt(&self) -> String;
+}
+
+impl CaptionCues for [CaptionCue] {
+    fn to_srt(&self) -> String {
+        let mut out = String::new();
+        for (i, cue) in self.iter().enumerate() {
+            let _ = write!(
+                out,
+                "{}\n{} --> {}\n{}\n\n",
+                i + 1,
+                timestamp(cue.start, ','),
+                timestamp(cue.start + cue.duration, ','),
+                cue_text(&cue.text),
+            );
+        }
+        out
+    }
+
+    fn to_vtt(&self) -> String {
+        let mut out = String::from("WEBVTT\n\n");
+        for cue in self {
+            let text = cue_text(&cue.text)
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;");
+            let _ = write!(
+                out,
+                "{} --> {}\n{}\n\n",
+                timestamp(cue.start, '.'),
+                timestamp(cue.start + cue.duration, '.'),
+                text,
+            );
+        }
+        out
+    }
+}
+
+/// Format a timestamp as `HH:MM:SS<separator>mmm`
+fn timestamp(time: Duration, separator: char) -> String {
+    let secs = time.as_secs();
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60,
+        separator,
+        time.subsec_millis()
+    )
+}
+
+/// Remove blank lines, as they would end a cue early
+fn cue_text(text: &str) -> String {
+    text.lines()
+        .map(str::trim_end)
+        .filter(|x| !x.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Parse the `srv3` caption format:
+/// `<timedtext><body><p t="<START_MS>" d="<DURATION_MS>">TEXT</p></body></timedtext>`
+fn parse_srv3(body: &str) -> crate::Result<Vec<CaptionCue>> {
+    let document =
+        roxmltree::Document::parse(body).map_err(|err| crate::Error::Caption(err.to_string()))?;
+
+    document
+        .descendants()
+        .filter(|x| x.has_tag_name("p"))
+        // Appended paragraphs of automatic captions only contain a line break
+        .filter(|x| x.attribute("a") != Some("1"))
+        .filter_map(|x| parse_cue(x).transpose())
+        .collect()
+}
+
+/// Parse a single `<p>` of the `srv3` format, [`None`] if it has no text
+fn parse_cue(node: roxmltree::Node<'_, '_>) -> crate::Result<Option<CaptionCue>> {
+    let millis = |attribute| {
+        node.attribute(attribute)
+            .unwrap_or("0")
+            .parse()
+            .map(Duration::from_millis)
+            .map_err(|_| {
+                crate::Error::Caption(format!("'{}' of a cue was not a number", attribute))
+            })
+    };
+
+    let text: String = node.descendants().filter_map(|x| x.text()).collect();
+    let text = text.trim();
+
+    if text.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(CaptionCue {
+        start: millis("t")?,
+        duration: millis("d")?,
+        text: text.to_string(),
+    }))
+}
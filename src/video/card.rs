@@ -0,0 +1,167 @@
+//! Info cards of a video.
+
+use std::time::Duration;
+
+use reqwest::Url;
+
+use crate::youtube::player_response::CardRenderer;
+
+/// A info card of a [`Video`](super::Video)
+///
+/// Every card has a `teaser`, the text shown when the card pops up, and a
+/// `cue`, the time at which it pops up, measured from the start of the
+/// [`Video`](super::Video).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Card {
+    /// A card linking to a [`Video`](super::Video)
+    Video {
+        /// The [`Id`](super::Id) of the video
+        id: super::Id,
+        /// The title of the video
+        title: String,
+        /// The text shown when this card pops up
+        teaser: String,
+        /// When this card pops up
+        cue: Duration,
+    },
+    /// A card linking to a [`Playlist`](crate::Playlist)
+    Playlist {
+        /// The [`Id`](crate::playlist::Id) of the playlist
+        id: crate::playlist::Id,
+        /// The title of the playlist
+        title: String,
+        /// The text shown when this card pops up
+        teaser: String,
+        /// When this card pops up
+        cue: Duration,
+    },
+    /// A card linking to a website
+    Link {
+        /// The [`Url`] of the link
+        url: Url,
+        /// The title of the link
+        title: String,
+        /// The text shown when this card pops up
+        teaser: String,
+        /// When this card pops up
+        cue: Duration,
+    },
+    /// A poll
+    Poll {
+        /// The text shown when this card pops up
+        teaser: String,
+        /// When this card pops up
+        cue: Duration,
+    },
+    /// A card that is not known to this library
+    Other {
+        /// The text shown when this card pops up
+        teaser: String,
+        /// When this card pops up
+        cue: Duration,
+    },
+}
+
+impl Card {
+    pub(super) fn new(card: &CardRenderer) -> Self {
+        let teaser = card
+            .teaser
+            .as_ref()
+            .and_then(|x| x.simple_card_teaser_renderer.as_ref())
+            .map(|x| x.message.text())
+            .unwrap_or_default();
+        let cue = card
+            .cue_ranges
+            .first()
+            .map(|x| x.start_card_active_ms)
+            .unwrap_or_default();
+        let text = |text: &Option<crate::youtube::Text>| {
+            text.as_ref().map(|x| x.text()).unwrap_or_default()
+        };
+        let content = &card.content;
+
+        let video = content
+            .video_info_card_content_renderer
+            .as_ref()
+            .and_then(|x| {
+                let id = x
+                    .action
+                    .watch_endpoint
+                    .as_ref()?
+                    .video_id
+                    .as_deref()?
+                    .parse()
+                    .ok()?;
+                Some(Self::Video {
+                    id,
+                    title: text(&x.video_title),
+                    teaser: teaser.clone(),
+                    cue,
+                })
+            });
+        let playlist = || {
+            let x = content.playlist_info_card_content_renderer.as_ref()?;
+            let id = x
+                .action
+                .watch_endpoint
+                .as_ref()?
+                .playlist_id
+                .as_deref()?
+                .parse()
+                .ok()?;
+            Some(Self::Playlist {
+                id,
+                title: text(&x.playlist_title),
+                teaser: teaser.clone(),
+                cue,
+            })
+        };
+        let link = || {
+            let x = content.simple_card_content_renderer.as_ref()?;
+            Some(Self::Link {
+                url: x.command.url_endpoint.as_ref()?.url.clone(),
+                title: text(&x.title),
+                teaser: teaser.clone(),
+                cue,
+            })
+        };
+        let poll = || {
+            content.poll_renderer.as_ref().map(|_| Self::Poll {
+                teaser: teaser.clone(),
+                cue,
+            })
+        };
+
+        video
+            .or_else(playlist)
+            .or_else(link)
+            .or_else(poll)
+            .unwrap_or_else(|| Self::Other {
+                teaser: teaser.clone(),
+                cue,
+            })
+    }
+
+    /// The text shown when this card pops up
+    pub fn teaser(&self) -> &str {
+        match self {
+            Self::Video { teaser, .. }
+            | Self::Playlist { teaser, .. }
+            | Self::Link { teaser, .. }
+            | Self::Poll { teaser, .. }
+            | Self::Other { teaser, .. } => teaser,
+        }
+    }
+
+    /// When this card pops up
+    pub fn cue(&self) -> Duration {
+        match self {
+            Self::Video { cue, .. }
+            | Self::Playlist { cue, .. }
+            | Self::Link { cue, .. }
+            | Self::Poll { cue, .. }
+            | Self::Other { cue, .. } => *cue,
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! Comments of a video.
+
+use crate::{parse, youtube::next::CommentRenderer};
+
+/// A top-level comment on a [`Video`](super::Video)
+#[derive(Clone)]
+pub struct Comment(pub(super) CommentRenderer);
+
+impl Comment {
+    /// The id of this comment.
+    pub fn id(&self) -> &str {
+        &self.0.comment_id
+    }
+
+    /// The name of the author of this comment.
+    pub fn author_name(&self) -> &str {
+        &self.0.author_text
+    }
+
+    /// The [`Id`](crate::channel::Id) of the author of this comment.
+    ///
+    /// [`None`] if the author's channel no longer exists.
+    pub fn author_id(&self) -> Option<crate::channel::Id> {
+        self.0
+            .author_endpoint
+            .as_ref()
+            .map(|x| x.browse_endpoint.browse_id)
+    }
+
+    /// The text of this comment.
+    pub fn text(&self) -> String {
+        self.0.content_text.text()
+    }
+
+    /// The amount of likes this comment received.
+    ///
+    /// [`None`] if the like count can not be parsed.
+    pub fn likes(&self) -> Option<u64> {
+        match &self.0.vote_count {
+            Some(vote_count) => parse::count(vote_count),
+            None => Some(0),
+        }
+    }
+
+    /// When this comment was published, relative to now, e.g. `2 years ago`.
+    pub fn published(&self) -> String {
+        self.0.published_time_text.text()
+    }
+
+    /// The amount of replies to this comment.
+    pub fn replies(&self) -> u64 {
+        self.0.reply_count
+    }
+}
+
+impl std::fmt::Debug for Comment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Comment")
+            .field("id", &self.id())
+            .field("author_name", &self.author_name())
+            .field("author_id", &self.author_id())
+            .field("text", &self.text())
+            .field("likes", &self.likes())
+            .field("published", &self.published())
+            .field("replies", &self.replies())
+            .finish()
+    }
+}
+
+impl PartialEq for Comment {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for Comment {}
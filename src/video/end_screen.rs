@@ -0,0 +1,147 @@
+//! End screens of a video.
+
+use std::time::Duration;
+
+use reqwest::Url;
+
+use crate::{youtube::player_response::EndscreenElementRenderer, Thumbnail};
+
+/// A element of the end screen of a [`Video`](super::Video)
+///
+/// Every element is shown from `start` to `end`, measured from the start of
+/// the [`Video`](super::Video).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EndScreenElement {
+    /// A [`Video`](super::Video)
+    Video {
+        /// The [`Id`](super::Id) of the video
+        id: super::Id,
+        /// The title of the video
+        title: String,
+        /// The [`Thumbnails`](Thumbnail) of the video
+        thumbnails: Vec<Thumbnail>,
+        /// When this element is first shown
+        start: Duration,
+        /// When this element is hidden
+        end: Duration,
+    },
+    /// A [`Playlist`](crate::Playlist)
+    Playlist {
+        /// The [`Id`](crate::playlist::Id) of the playlist
+        id: crate::playlist::Id,
+        /// The title of the playlist
+        title: String,
+        /// When this element is first shown
+        start: Duration,
+        /// When this element is hidden
+        end: Duration,
+    },
+    /// A [`Channel`](crate::Channel), either promoted or to subscribe to
+    Channel {
+        /// The [`Id`](crate::channel::Id) of the channel
+        id: crate::channel::Id,
+        /// The name of the channel
+        name: String,
+        /// When this element is first shown
+        start: Duration,
+        /// When this element is hidden
+        end: Duration,
+    },
+    /// A link to a website
+    Link {
+        /// The [`Url`] of the link
+        url: Url,
+        /// The title of the link
+        title: String,
+        /// When this element is first shown
+        start: Duration,
+        /// When this element is hidden
+        end: Duration,
+    },
+    /// A element that is not known to this library
+    Other {
+        /// When this element is first shown
+        start: Duration,
+        /// When this element is hidden
+        end: Duration,
+    },
+}
+
+impl EndScreenElement {
+    pub(super) fn new(element: &EndscreenElementRenderer) -> Self {
+        let start = element.start_ms;
+        let end = element.end_ms;
+        let title = || element.title.as_ref().map(|x| x.text()).unwrap_or_default();
+        let endpoint = &element.endpoint;
+
+        let known = match element.style.as_str() {
+            "VIDEO" => endpoint
+                .watch_endpoint
+                .as_ref()
+                .and_then(|x| x.video_id.as_deref()?.parse().ok())
+                .map(|id| Self::Video {
+                    id,
+                    title: title(),
+                    thumbnails: element
+                        .image
+                        .as_ref()
+                        .map(|x| x.thumbnails.clone())
+                        .unwrap_or_default(),
+                    start,
+                    end,
+                }),
+            "PLAYLIST" => endpoint
+                .watch_endpoint
+                .as_ref()
+                .and_then(|x| x.playlist_id.as_deref()?.parse().ok())
+                .map(|id| Self::Playlist {
+                    id,
+                    title: title(),
+                    start,
+                    end,
+                }),
+            "CHANNEL" | "SUBSCRIBE" => endpoint
+                .browse_endpoint
+                .as_ref()
+                .and_then(|x| x.browse_id.parse().ok())
+                .map(|id| Self::Channel {
+                    id,
+                    name: title(),
+                    start,
+                    end,
+                }),
+            "WEBSITE" => endpoint.url_endpoint.as_ref().map(|x| Self::Link {
+                url: x.url.clone(),
+                title: title(),
+                start,
+                end,
+            }),
+            _ => None,
+        };
+
+        known.unwrap_or(Self::Other { start, end })
+    }
+
+    /// When this element is first shown
+    pub fn start(&self) -> Duration {
+        match self {
+            Self::Video { start, .. }
+            | Self::Playlist { start, .. }
+            | Self::Channel { start, .. }
+            | Self::Link { start, .. }
+            | Self::Other { start, .. } => *start,
+        }
+    }
+
+    /// When this element is hidden
+    pub fn end(&self) -> Duration {
+        match self {
+            Self::Video { end, .. }
+            | Self::Playlist { end, .. }
+            | Self::Channel { end, .. }
+            | Self::Link { end, .. }
+            | Self::Other { end, .. } => *end,
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Playback tracking of a video.
+
+use std::{
+    collections::hash_map::RandomState,
+    hash::BuildHasher,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use reqwest::Url;
+
+use crate::Client;
+
+/// The URLs a player reports playback of a [`Video`](super::Video) to.
+///
+/// Players built on this library can use [`PlaybackTracking::ping`] to have
+/// their playback count as a view, like it would on YouTube. This is an
+/// anonymous request, without any cookies or authentication.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlaybackTracking {
+    /// The URL that registers the start of a playback
+    pub playback_url: Url,
+    /// The URL that registers the watch time of a playback
+    pub watchtime_url: Option<Url>,
+}
+
+impl PlaybackTracking {
+    /// The URL [`PlaybackTracking::ping`] requests.
+    ///
+    /// This is the [`playback_url`](Self::playback_url) with a newly
+    /// generated client playback nonce (`cpn`) and the protocol version
+    /// (`ver`) appended.
+    pub fn ping_url(&self) -> Url {
+        let mut url = self.playback_url.clone();
+        url.query_pairs_mut()
+            .append_pair("ver", "2")
+            .append_pair("cpn", &nonce());
+        url
+    }
+
+    /// Report the start of a playback to YouTube.
+    pub async fn ping(&self, client: &Client) -> crate::Result<()> {
+        client
+            .api
+            .http
+            .get(self.ping_url())
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+}
+
+/// Generate a client playback nonce: 16 random characters of the URL-safe
+/// base64 alphabet
+fn nonce() -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let state = RandomState::new();
+    let mut random = [0u64; 2].map(|_| state.hash_one(COUNTER.fetch_add(1, Ordering::Relaxed)));
+
+    (0..16)
+        .map(|i| {
+            let word = &mut random[i / 8];
+            let c = ALPHABET[(*word & 63) as usize];
+            *word >>= 6;
+            c as char
+        })
+        .collect()
+}
@@ -1,11 +1,14 @@
 //! Recommended/Related items of a video.
 
-use crate::youtube::{
-    self,
-    next::{
-        CompactMovieRenderer, CompactPlaylistRenderer, CompactRadioRenderer, CompactVideoRenderer,
+use crate::{
+    parse,
+    youtube::{
+        self,
+        next::{
+            CompactMovieRenderer, CompactPlaylistRenderer, CompactRadioRenderer,
+            CompactVideoRenderer,
+        },
     },
-    parse_length,
 };
 
 use std::fmt::Debug;
@@ -31,26 +34,42 @@ impl Video {
     }
 
     /// The amount of views this video has.
+    ///
+    /// [`None`] if YouTube shows no view count, e.g. `No views` or
+    /// `<N> watching` for [live](Self::is_live) videos.
     pub fn views(&self) -> Option<u64> {
-        let s: &str = match self.0.view_count_text.as_ref()? {
-            // "<VIEWS> views"
-            crate::youtube::Text::SimpleText(simple) => {
-                simple
-                    .simple_text
-                    .split_once(' ')
-                    .expect("No space in view_count_text")
-                    .0
-            }
-            // ["<VIEWS>", ..]
-            crate::youtube::Text::Runs(runs) => &runs.runs[0].text,
-        };
-
-        Some(s.replace(',', "").parse().expect("Views were not parsable"))
+        // "<VIEWS> views" or ["<VIEWS>", " views"]
+        let text = self.0.view_count_text.as_ref()?.text();
+        if text.contains("watching") {
+            return None;
+        }
+
+        parse::count(&text)
+    }
+
+    /// When this video was published, relative to now, e.g. `3 years ago`.
+    ///
+    /// [`None`] for livestreams.
+    pub fn published_text(&self) -> Option<&str> {
+        self.0.published_time_text.as_deref()
+    }
+
+    /// Is this video live right now?
+    pub fn is_live(&self) -> bool {
+        self.0
+            .badges
+            .iter()
+            .any(|x| x.metadata_badge_renderer.style == "BADGE_STYLE_TYPE_LIVE_NOW")
+            || self
+                .0
+                .view_count_text
+                .as_ref()
+                .is_some_and(|x| x.text().contains("watching"))
     }
 
     /// The length of this video. [`None`] if this video is a livestream.
     pub fn length(&self) -> Option<std::time::Duration> {
-        self.0.length_text.as_deref().map(parse_length)
+        self.0.length_text.as_deref().and_then(parse::length)
     }
 
     /// The [`Channel`] that uploaded this video.
@@ -64,6 +83,7 @@ impl Video {
             ),
             name: &self.0.short_byline_text.runs[0].text,
             badges: &self.0.owner_badges,
+            thumbnails: &self.0.channel_thumbnail.thumbnails,
             client: &self.1,
         }
     }
@@ -87,6 +107,8 @@ impl std::fmt::Debug for Video {
             .field("thumbnails", &self.thumbnails().collect::<Vec<_>>())
             .field("views", &self.views())
             .field("length", &self.length())
+            .field("published_text", &self.published_text())
+            .field("is_live", &self.is_live())
             .field("channel", &self.channel())
             .finish()
     }
@@ -124,8 +146,9 @@ impl Playlist {
     pub fn channel(&self) -> Channel<'_> {
         Channel {
             id: self.0.channel_id(),
-            name: &self.0.channel_name(),
+            name: self.0.channel_name(),
             badges: &self.0.owner_badges,
+            thumbnails: &[],
             client: &self.1,
         }
     }
@@ -219,9 +242,9 @@ impl Movie {
         self.0.thumbnail.thumbnails.iter()
     }
 
-    /// The length of this movie.
-    pub fn length(&self) -> std::time::Duration {
-        parse_length(&self.0.length_text)
+    /// The length of this movie. [`None`] if the length could not be parsed.
+    pub fn length(&self) -> Option<std::time::Duration> {
+        parse::length(&self.0.length_text)
     }
 
     /// Refetch this video for more information.
@@ -255,6 +278,7 @@ pub struct Channel<'a> {
     id: Option<crate::channel::Id>,
     name: &'a str,
     badges: &'a Vec<youtube::Badge>,
+    thumbnails: &'a [crate::Thumbnail],
     client: &'a crate::Client,
 }
 
@@ -274,9 +298,20 @@ impl<'a> Channel<'a> {
         self.badges.iter().map(crate::channel::Badge::from)
     }
 
+    /// The avatar of this channel.
+    ///
+    /// Empty for the channels of playlists, as YouTube does not show them.
+    pub fn thumbnails(&self) -> impl Iterator<Item = &crate::Thumbnail> {
+        self.thumbnails.iter()
+    }
+
     /// Refetch this channel for more information.
-    pub async fn upgrade(&self) -> Option<crate::Result<crate::Channel>> {
-        Some(self.client.channel(self.id?).await)
+    ///
+    /// Fails with [`Error::MissingChannelId`](crate::Error::MissingChannelId)
+    /// if the [`Id`](Self::id) of this channel is not known.
+    pub async fn upgrade(&self) -> crate::Result<crate::Channel> {
+        let id = self.id.ok_or(crate::Error::MissingChannelId)?;
+        self.client.channel(id).await
     }
 }
 
@@ -286,6 +321,7 @@ impl<'a> std::fmt::Debug for Channel<'a> {
             .field("id", &self.id())
             .field("name", &self.name())
             .field("badges", &self.badges().collect::<Vec<_>>())
+            .field("thumbnails", &self.thumbnails)
             .finish()
     }
 }
@@ -0,0 +1,135 @@
+//! Storyboards of a video.
+//!
+//! Storyboards are the sprite sheets shown as previews while seeking through
+//! a video. YouTube provides them in multiple levels of detail.
+
+use std::{convert::TryFrom, time::Duration};
+
+use reqwest::Url;
+
+/// A level of detail of the storyboards of a [`Video`](super::Video)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Storyboard {
+    level: usize,
+    url: String,
+    width: u64,
+    height: u64,
+    frame_count: u64,
+    columns: u64,
+    rows: u64,
+    interval: Duration,
+    name: String,
+    sigh: String,
+}
+
+impl Storyboard {
+    /// Parse a storyboard spec, as found in the player response, into its
+    /// levels.
+    ///
+    /// `duration` is the duration of the video, which is needed for levels
+    /// that do not specify their interval. Malformed levels are skipped.
+    pub fn from_spec(spec: &str, duration: Duration) -> Vec<Self> {
+        let mut parts = spec.split('|');
+        let url = match parts.next() {
+            Some(url) => url,
+            None => return Vec::new(),
+        };
+
+        parts
+            .enumerate()
+            .filter_map(|(level, part)| Self::parse_level(url, level, part, duration))
+            .collect()
+    }
+
+    /// Parse a single level in the format
+    /// `<WIDTH>#<HEIGHT>#<FRAMES>#<COLUMNS>#<ROWS>#<INTERVAL_MS>#<NAME>#<SIGH>`
+    fn parse_level(url: &str, level: usize, part: &str, duration: Duration) -> Option<Self> {
+        let mut fields = part.split('#');
+        let mut number = || -> Option<u64> { fields.next()?.parse().ok() };
+
+        let width = number()?;
+        let height = number()?;
+        let frame_count = number()?;
+        let columns = number()?;
+        let rows = number()?;
+        let interval = number()?;
+        let name = fields.next()?.to_string();
+        let sigh = fields.next()?.to_string();
+
+        if columns == 0 || rows == 0 {
+            return None;
+        }
+
+        // The first level does not specify an interval, as it spreads its
+        // frames evenly over the whole video
+        let interval = match interval {
+            0 => duration / u32::try_from(frame_count).ok().filter(|&n| n > 0)?,
+            interval => Duration::from_millis(interval),
+        };
+
+        Some(Self {
+            level,
+            url: url.to_string(),
+            width,
+            height,
+            frame_count,
+            columns,
+            rows,
+            interval,
+            name,
+            sigh,
+        })
+    }
+
+    /// The width of a single frame
+    pub fn width(&self) -> u64 {
+        self.width
+    }
+
+    /// The height of a single frame
+    pub fn height(&self) -> u64 {
+        self.height
+    }
+
+    /// The amount of frames per row of a sprite sheet
+    pub fn columns(&self) -> u64 {
+        self.columns
+    }
+
+    /// The amount of rows of frames of a sprite sheet
+    pub fn rows(&self) -> u64 {
+        self.rows
+    }
+
+    /// The total amount of frames over all sprite sheets
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
+    /// The time between two frames
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// The [`Urls`](Url) of the sprite sheets, in order
+    ///
+    /// Sheets whose url is invalid are skipped.
+    pub fn urls(&self) -> impl Iterator<Item = Url> + '_ {
+        let sheets = match self.columns.checked_mul(self.rows) {
+            Some(per_sheet) if self.name.contains("$M") => self.frame_count.div_ceil(per_sheet),
+            _ => 1,
+        };
+
+        (0..sheets).filter_map(move |sheet| {
+            let name = self.name.replace("$M", &sheet.to_string());
+            let url = self
+                .url
+                .replace("$L", &self.level.to_string())
+                .replace("$N", &name);
+
+            let mut url = Url::parse(&url).ok()?;
+            url.query_pairs_mut().append_pair("sigh", &self.sigh);
+            Some(url)
+        })
+    }
+}
@@ -8,6 +8,9 @@ pub mod browse;
 pub mod innertube;
 pub mod next;
 pub mod player_response;
+pub mod resolve_url;
+pub mod transcript;
+pub mod unknown;
 
 #[derive(Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -24,6 +27,14 @@ pub struct ChannelNameRun {
     pub navigation_endpoint: NavigationEndpoint,
 }
 
+/// A run that only links to a channel if it is a channel name
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionalChannelNameRun {
+    pub text: String,
+    pub navigation_endpoint: Option<NavigationEndpoint>,
+}
+
 #[derive(Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct NavigationEndpoint {
@@ -43,6 +54,16 @@ pub enum Text<ST = SimpleText, R = TitleRuns> {
     Runs(R),
 }
 
+impl Text {
+    /// The full text, with all runs joined together.
+    pub fn text(&self) -> String {
+        match self {
+            Text::SimpleText(simple) => simple.simple_text.clone(),
+            Text::Runs(runs) => runs.runs.iter().map(|x| x.text.as_str()).collect(),
+        }
+    }
+}
+
 #[derive(Deserialize, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct SimpleText {
@@ -110,42 +131,11 @@ pub struct ContinuationCommand {
     pub token: String,
 }
 
-pub fn parse_subscribers(value: &str) -> Option<u64> {
-    let last = value.chars().last()?;
-    if last.is_numeric() {
-        value.parse().ok()
-    } else {
-        let val = &value[..value.len() - 1];
-        let val: f64 = val.parse().ok()?;
-        let mul = match last {
-            'K' => 1_000.0,
-            'M' => 1_000_000.0,
-            modifier => unimplemented!("Unknown modifier '{}'", modifier),
-        };
-
-        Some((val * mul) as u64)
-    }
-}
-
-/// Parse a video length in the format `HH:MM:SS`
-pub fn parse_length(value: &str) -> std::time::Duration {
-    std::time::Duration::from_secs(value.split(':').rev().enumerate().fold(0, |acc, (i, s)| {
-        let s: u64 = s.parse().unwrap();
-        let mul = 60u64.pow(i as u32);
-        acc + (s * mul)
-    }))
-}
-
-/// Parse a video upload data in the format `[Premiered |Premires ]<MONTH_NAME> <DAY>, <YEAR>`
-pub fn parse_date(value: &str) -> Option<chrono::NaiveDate> {
-    const PREFIXES: &[&str] = &["Premiered ", "Premieres "];
-
-    let value = PREFIXES
-        .iter()
-        .find_map(|x| value.strip_prefix(x))
-        .unwrap_or(value);
-
-    chrono::NaiveDate::parse_from_str(value, "%b %e, %Y").ok()
+/// Parse a ISO-8601 date like `2021-04-14` or `2021-04-14T07:00:09-07:00`,
+/// ignoring the time if present
+pub fn parse_iso_date(value: &str) -> Option<chrono::NaiveDate> {
+    let date = value.get(..10)?;
+    chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
 }
 
 /// Strips the various possible domains of a youtube URL
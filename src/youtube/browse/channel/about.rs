@@ -1,6 +1,9 @@
 use serde::Deserialize;
 
-use crate::youtube::SimpleText;
+use crate::{
+    parse,
+    youtube::{SimpleText, Thumbnails, TitleRun},
+};
 
 pub type Result = super::Result<Content>;
 
@@ -38,29 +41,19 @@ pub struct ChannelAboutFullMetadataRenderer {
     pub view_count_text: Option<SimpleText>,
     pub country: Option<SimpleText>,
     pub joined_date_text: JoinedDateText,
+    #[serde(default)]
+    pub avatar: Thumbnails,
 }
 
 impl ChannelAboutFullMetadataRenderer {
-    pub fn views(&self) -> u64 {
-        self.view_count_text
-            .as_ref()
-            .and_then(|x| {
-                x.simple_text
-                    .split_once(' ')
-                    .map(|(start, _)| start.replace(',', "").parse().expect("not int"))
-            })
-            .unwrap_or(0)
+    pub fn views(&self) -> Option<u64> {
+        // `1,234 views`, missing if the channel hides its stats
+        parse::views(&self.view_count_text.as_ref()?.simple_text)
     }
 }
 
 #[derive(Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct JoinedDateText {
-    pub runs: (Text, Text),
-}
-
-#[derive(Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct Text {
-    pub text: String,
+    pub runs: (TitleRun, TitleRun),
 }
@@ -1,8 +1,12 @@
 use serde::Deserialize;
 
-use crate::youtube::{parse_subscribers, Badge, SimpleText, Thumbnails};
+use crate::{
+    parse,
+    youtube::{Badge, SimpleText, Text, Thumbnails},
+};
 
 pub mod about;
+pub mod videos;
 
 pub type Result<T> = super::Result<Ok<T>>;
 
@@ -14,6 +18,16 @@ pub struct Ok<T> {
     pub contents: Contents<T>,
 }
 
+/// A channel response of which only the header and metadata are used.
+pub type HeaderResult = super::Result<HeaderOnly>;
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HeaderOnly {
+    pub header: Header,
+    pub metadata: Metadata,
+}
+
 impl<T> Ok<T> {
     pub fn contents(&self) -> &T {
         self.contents
@@ -45,19 +59,15 @@ pub struct C4TabbedHeaderRenderer {
     #[serde(default)]
     pub badges: Vec<Badge>,
     pub subscriber_count_text: Option<SimpleText>,
+    // `@LinusTechTips`, missing for channels without a handle
+    #[serde(default)]
+    pub channel_handle_text: Option<Text>,
 }
 
 impl C4TabbedHeaderRenderer {
     pub fn subscribers(&self) -> Option<u64> {
-        self.subscriber_count_text.as_ref().map(|x| {
-            parse_subscribers(
-                x.simple_text
-                    .split_once(' ')
-                    .expect("no space in subscriber_count_text")
-                    .0,
-            )
-            .expect("Unable to parse subscriber count")
-        })
+        // `1.2M subscribers` or `1 subscriber`, missing if hidden
+        parse::count(&self.subscriber_count_text.as_ref()?.simple_text)
     }
 }
 
@@ -71,6 +81,26 @@ pub struct Metadata {
 #[serde(rename_all = "camelCase")]
 pub struct ChannelMetadataRenderer {
     pub is_family_safe: bool,
+    // `http://www.youtube.com/@LinusTechTips` or
+    // `http://www.youtube.com/c/LinusTechTips` for older channels
+    #[serde(default)]
+    pub vanity_channel_url: Option<String>,
+}
+
+/// The handle of a channel, e.g. `@LinusTechTips`, from its header or the
+/// last segment of its vanity url
+pub fn handle<'a>(
+    header: &'a C4TabbedHeaderRenderer,
+    metadata: &'a ChannelMetadataRenderer,
+) -> Option<&'a str> {
+    match &header.channel_handle_text {
+        Some(Text::SimpleText(handle)) => Some(&handle.simple_text),
+        Some(Text::Runs(handle)) => handle.runs.first().map(|x| x.text.as_str()),
+        None => {
+            let (_, handle) = metadata.vanity_channel_url.as_deref()?.rsplit_once('/')?;
+            handle.starts_with('@').then_some(handle)
+        }
+    }
 }
 
 #[derive(Deserialize, Clone)]
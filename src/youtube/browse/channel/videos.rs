@@ -0,0 +1,105 @@
+use serde::Deserialize;
+
+use crate::youtube::{
+    unknown::{self, impl_renderer},
+    ContinuationItemRenderer, SimpleText, Text, Thumbnails, TitleRuns,
+};
+
+impl_renderer! {
+    Item => "browse.channel.videos",
+    RichItemContent => "browse.channel.videos.richItemRenderer",
+}
+
+pub type Result = super::Result<Content>;
+
+pub type Root = super::Ok<Content>;
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Content {
+    // Missing if YouTube opened another tab, e.g. for channels without videos
+    #[serde(default)]
+    pub rich_grid_renderer: Option<RichGridRenderer>,
+}
+
+impl Content {
+    pub fn into_items(self) -> impl Iterator<Item = Item> {
+        self.rich_grid_renderer.into_iter().flat_map(|x| x.contents)
+    }
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RichGridRenderer {
+    #[serde(default, deserialize_with = "unknown::vec")]
+    pub contents: Vec<Item>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum Item {
+    RichItemRenderer(RichItemRenderer),
+    ContinuationItemRenderer(ContinuationItemRenderer),
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RichItemRenderer {
+    #[serde(deserialize_with = "unknown::single")]
+    pub content: RichItemContent,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum RichItemContent {
+    VideoRenderer(VideoRenderer),
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoRenderer {
+    pub video_id: crate::video::Id,
+    #[serde(default)]
+    pub thumbnail: Thumbnails,
+    pub title: TitleRuns,
+    // `1,234 views`, `1,234 watching` for livestreams
+    pub view_count_text: Option<Text>,
+    // `3 days ago`, missing for livestreams and upcoming videos
+    pub published_time_text: Option<SimpleText>,
+    // `12:34`, missing for livestreams and upcoming videos
+    pub length_text: Option<SimpleText>,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Continuation {
+    #[serde(default)]
+    pub on_response_received_actions: Vec<OnResponseReceivedAction>,
+}
+
+impl Continuation {
+    pub fn into_items(self) -> impl Iterator<Item = Item> {
+        self.on_response_received_actions
+            .into_iter()
+            .filter_map(|x| x.append_continuation_items_action)
+            .flat_map(|x| x.continuation_items)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OnResponseReceivedAction {
+    pub append_continuation_items_action: Option<AppendContinuationItemsAction>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppendContinuationItemsAction {
+    #[serde(default, deserialize_with = "unknown::vec")]
+    pub continuation_items: Vec<Item>,
+}
@@ -17,7 +17,7 @@ impl<T> Result<T> {
             Self::Error { alerts } => {
                 assert_eq!(alerts.0.alert_renderer.r#type, "ERROR");
 
-                Err(crate::Error::Youtube(crate::error::Youtube(
+                Err(crate::Error::Youtube(crate::error::Youtube::Message(
                     alerts.0.alert_renderer.text(),
                 )))
             }
@@ -3,7 +3,19 @@ use std::time::Duration;
 use serde::Deserialize;
 use serde_with::serde_as;
 
-use crate::youtube::{ChannelNameRuns, ContinuationItemRenderer, Thumbnails, TitleRun, TitleRuns};
+use crate::youtube::{
+    unknown::{self, impl_renderer},
+    ChannelNameRuns, ContinuationItemRenderer, OptionalChannelNameRun, Runs, SimpleText, Text,
+    Thumbnails, TitleRuns,
+};
+use crate::{
+    parse,
+    playlist::{LastUpdated, Privacy},
+};
+
+impl_renderer! {
+    ThumbnailOverlay => "browse.playlist.thumbnailOverlays",
+}
 
 pub type Result = super::Result<Ok>;
 
@@ -12,7 +24,9 @@ pub type Result = super::Result<Ok>;
 pub struct Ok {
     pub contents: Contents,
     pub microformat: Microformat,
-    pub sidebar: Sidebar,
+    // Podcasts have a page header instead of a sidebar
+    pub sidebar: Option<Sidebar>,
+    pub header: Option<Header>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -24,21 +38,78 @@ pub struct Contents {
 }
 
 impl Contents {
-    pub fn into_videos(self) -> impl Iterator<Item = PlaylistItem> {
-        self.two_column_browse_results_renderer
+    fn content(&self) -> &Content {
+        &self
+            .two_column_browse_results_renderer
             .tabs
             .0
             .tab_renderer
             .content
-            .section_list_renderer
-            .contents
-            .0
-            .item_section_renderer
-            .contents
+    }
+
+    pub fn is_rich_grid(&self) -> bool {
+        self.content().rich_grid_renderer.is_some()
+    }
+
+    /// The first available video of the first page
+    pub fn first_video(&self) -> Option<&PlaylistVideo> {
+        let content = self.content();
+
+        let items = match (&content.rich_grid_renderer, &content.section_list_renderer) {
+            (Some(rich_grid), _) => &rich_grid.contents,
+            (None, Some(section_list)) => {
+                &section_list
+                    .contents
+                    .0
+                    .item_section_renderer
+                    .contents
+                    .0
+                    .playlist_video_list_renderer
+                    .contents
+            }
+            (None, None) => return None,
+        };
+
+        items.iter().find_map(|item| {
+            let video = match item {
+                PlaylistItem::PlaylistVideoRenderer(video) => video,
+                PlaylistItem::RichItemRenderer(RichItemRenderer {
+                    content: RichItemContent::PlaylistVideoRenderer(video),
+                }) => video,
+                _ => return None,
+            };
+
+            match video {
+                PlaylistVideoRenderer::Ok(video) => Some(video),
+                PlaylistVideoRenderer::Err { .. } => None,
+            }
+        })
+    }
+
+    pub fn into_videos(self) -> impl Iterator<Item = PlaylistItem> {
+        let content = self
+            .two_column_browse_results_renderer
+            .tabs
             .0
-            .playlist_video_list_renderer
-            .contents
-            .into_iter()
+            .tab_renderer
+            .content;
+
+        let items = match (content.section_list_renderer, content.rich_grid_renderer) {
+            (_, Some(rich_grid)) => rich_grid.contents,
+            (Some(section_list), None) => {
+                section_list
+                    .contents
+                    .0
+                    .item_section_renderer
+                    .contents
+                    .0
+                    .playlist_video_list_renderer
+                    .contents
+            }
+            (None, None) => Vec::new(),
+        };
+
+        items.into_iter()
     }
 }
 
@@ -63,7 +134,16 @@ pub struct TabRenderer {
 #[derive(Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Content {
-    pub section_list_renderer: SectionListRenderer,
+    pub section_list_renderer: Option<SectionListRenderer>,
+    // Used by podcasts
+    pub rich_grid_renderer: Option<RichGridRenderer>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RichGridRenderer {
+    #[serde(default)]
+    pub contents: Vec<PlaylistItem>,
 }
 
 #[derive(Deserialize, Clone)]
@@ -102,6 +182,23 @@ pub struct PlaylistVideoListRenderer {
 pub enum PlaylistItem {
     PlaylistVideoRenderer(PlaylistVideoRenderer),
     ContinuationItemRenderer(ContinuationItemRenderer),
+    RichItemRenderer(RichItemRenderer),
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RichItemRenderer {
+    pub content: RichItemContent,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum RichItemContent {
+    PlaylistVideoRenderer(PlaylistVideoRenderer),
+    #[serde(other)]
+    Other,
 }
 
 #[serde_as]
@@ -113,6 +210,8 @@ pub enum PlaylistVideoRenderer {
         title: TitleRuns,
         #[serde(rename = "videoId")]
         video_id: crate::video::Id,
+        #[serde(default)]
+        index: Option<SimpleText>,
     },
 }
 
@@ -121,13 +220,48 @@ pub enum PlaylistVideoRenderer {
 #[serde(rename_all = "camelCase")]
 pub struct PlaylistVideo {
     pub video_id: crate::video::Id,
+    // 1-based position, e.g. `1`
+    #[serde(default)]
+    pub index: Option<SimpleText>,
 
     pub thumbnail: Thumbnails,
     pub title: TitleRuns,
     pub short_byline_text: ChannelNameRuns,
 
-    #[serde_as(as = "serde_with::DurationSeconds<String>")]
-    pub length_seconds: Duration,
+    // Missing for upcoming premieres and livestreams
+    #[serde_as(as = "Option<serde_with::DurationSeconds<String>>")]
+    #[serde(default)]
+    pub length_seconds: Option<Duration>,
+
+    #[serde(default, deserialize_with = "unknown::vec")]
+    pub thumbnail_overlays: Vec<ThumbnailOverlay>,
+}
+
+impl PlaylistVideo {
+    pub fn is_upcoming(&self) -> bool {
+        self.thumbnail_overlays.iter().any(|x| {
+            matches!(
+                x,
+                ThumbnailOverlay::ThumbnailOverlayTimeStatusRenderer(status)
+                    if status.style.as_deref() == Some("UPCOMING")
+            )
+        })
+    }
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum ThumbnailOverlay {
+    ThumbnailOverlayTimeStatusRenderer(ThumbnailOverlayTimeStatusRenderer),
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ThumbnailOverlayTimeStatusRenderer {
+    // `DEFAULT`, `LIVE` or `UPCOMING`
+    pub style: Option<String>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -148,6 +282,110 @@ pub struct MicroformatDataRenderer {
 }
 ////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Header {
+    pub page_header_renderer: Option<PageHeaderRenderer>,
+}
+
+impl Header {
+    fn view_model(&self) -> Option<&PageHeaderViewModel> {
+        self.page_header_renderer
+            .as_ref()
+            .map(|x| &x.content.page_header_view_model)
+    }
+
+    /// All parts of the metadata rows, e.g. `["Podcast", "123 episodes"]`
+    pub fn metadata_parts(&self) -> impl Iterator<Item = &str> {
+        self.view_model()
+            .and_then(|x| x.metadata.as_ref())
+            .into_iter()
+            .flat_map(|x| &x.content_metadata_view_model.metadata_rows)
+            .flat_map(|x| &x.metadata_parts)
+            .map(|x| x.text.content.as_str())
+    }
+
+    pub fn episode_count(&self) -> Option<u64> {
+        self.metadata_parts()
+            .find(|x| x.ends_with(" episodes") || x.ends_with(" episode"))
+            .and_then(parse::count)
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.view_model()?.description.as_ref().map(|x| {
+            x.description_preview_view_model
+                .description
+                .content
+                .as_str()
+        })
+    }
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PageHeaderRenderer {
+    pub content: PageHeaderContent,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PageHeaderContent {
+    pub page_header_view_model: PageHeaderViewModel,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PageHeaderViewModel {
+    pub metadata: Option<PageHeaderMetadata>,
+    pub description: Option<PageHeaderDescription>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PageHeaderMetadata {
+    pub content_metadata_view_model: ContentMetadataViewModel,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentMetadataViewModel {
+    #[serde(default)]
+    pub metadata_rows: Vec<MetadataRow>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataRow {
+    #[serde(default)]
+    pub metadata_parts: Vec<MetadataPart>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataPart {
+    pub text: ViewModelText,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PageHeaderDescription {
+    pub description_preview_view_model: DescriptionPreviewViewModel,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DescriptionPreviewViewModel {
+    pub description: ViewModelText,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewModelText {
+    pub content: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Sidebar {
@@ -182,49 +420,112 @@ pub struct PlaylistSidebarSecondaryInfo {
 #[derive(Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct PlaylistSidebarPrimaryInfoRenderer {
-    pub stats: (VideoStats, ViewsStats, DateStats),
-}
+    // `<N> videos`, `<N> views` and `Last updated on <DATE>`, followed by
+    // extra rows like `<N> saves` on some playlists
+    #[serde(default)]
+    pub stats: Vec<Text>,
+    // The privacy of the playlist, e.g. `Unlisted` or `Private`. Missing on
+    // public playlists.
+    #[serde(default)]
+    pub badges: Vec<PlaylistBadge>,
+}
+
+impl PlaylistSidebarPrimaryInfoRenderer {
+    pub fn privacy(&self) -> Option<Privacy> {
+        self.badges.iter().find_map(|badge| {
+            match badge
+                .metadata_badge_renderer
+                .as_ref()?
+                .icon
+                .as_ref()?
+                .icon_type
+                .as_str()
+            {
+                "PRIVACY_PUBLIC" => Some(Privacy::Public),
+                "PRIVACY_UNLISTED" => Some(Privacy::Unlisted),
+                "PRIVACY_PRIVATE" => Some(Privacy::Private),
+                _ => None,
+            }
+        })
+    }
 
-#[derive(Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct VideoStats {
-    pub runs: Vec<TitleRun>,
-}
+    /// The amount of the first stat in the form `<N> <UNIT>` or `No <UNIT>`
+    fn stat(&self, units: &[&str]) -> Option<u64> {
+        self.stats.iter().find_map(|stat| {
+            let text = stat.text();
+            let (amount, unit) = text.trim().split_once(' ')?;
+            if !units.contains(&unit) {
+                return None;
+            }
+
+            match amount {
+                "No" => Some(0),
+                amount => parse::count(amount),
+            }
+        })
+    }
+
+    pub fn videos(&self) -> Option<u64> {
+        self.stat(&["video", "videos"])
+    }
 
-impl VideoStats {
-    pub fn as_number(&self) -> u64 {
-        match self.runs[0].text.as_str() {
-            "No videos" => 0,
-            o => o
-                .replace(',', "")
-                .parse()
-                .expect("VideoStats text was not a number"),
+    pub fn views(&self) -> Option<u64> {
+        self.stats
+            .iter()
+            .find_map(|stat| parse::views(&stat.text()))
+    }
+
+    pub fn saves(&self) -> Option<u64> {
+        self.stat(&["save", "saves"])
+    }
+
+    /// `Last updated on Jun 22, 2021`, `Updated today`, `Updated yesterday`
+    /// or `Updated 3 days ago`
+    pub fn last_updated(&self) -> Option<LastUpdated> {
+        self.stats.iter().find_map(|stat| {
+            let text = stat.text();
+            if let Some(date) = text.strip_prefix("Last updated on ") {
+                return Some(parse::date(date).map_or_else(
+                    || LastUpdated::Relative(date.to_string()),
+                    LastUpdated::Date,
+                ));
+            }
+
+            text.strip_prefix("Updated ")
+                .map(|relative| LastUpdated::Relative(relative.to_string()))
+        })
+    }
+
+    pub fn updated(&self, now: chrono::DateTime<chrono::Utc>) -> Option<chrono::NaiveDate> {
+        match self.last_updated()? {
+            LastUpdated::Date(date) => Some(date),
+            LastUpdated::Relative(relative) => match relative.as_str() {
+                "today" => Some(now.date_naive()),
+                "yesterday" => now.date_naive().pred_opt(),
+                relative => parse::relative_date(relative, now),
+            },
         }
     }
 }
 
 #[derive(Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct ViewsStats {
-    pub simple_text: String,
+pub struct PlaylistBadge {
+    pub metadata_badge_renderer: Option<PlaylistBadgeRenderer>,
 }
 
-impl ViewsStats {
-    pub fn as_number(&self) -> u64 {
-        self.simple_text
-            .as_str()
-            .split_once(' ')
-            .expect("No space in ViewsStats text")
-            .0
-            .replace(',', "")
-            .parse()
-            .unwrap_or_default()
-    }
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistBadgeRenderer {
+    pub icon: Option<Icon>,
 }
 
 #[derive(Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct DateStats {}
+pub struct Icon {
+    // `PRIVACY_UNLISTED`
+    pub icon_type: String,
+}
 
 #[derive(Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -241,19 +542,54 @@ pub struct VideoOwner {
 #[derive(Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct VideoOwnerRenderer {
-    pub title: ChannelNameRuns,
+    // Collaborative playlists have multiple owners, separated by runs like
+    // ` and ` without a endpoint
+    pub title: Runs<OptionalChannelNameRun>,
+    #[serde(default)]
+    pub thumbnail: Thumbnails,
+    // `1.2M subscribers`
+    pub subscriber_count_text: Option<Text>,
 }
 
 impl VideoOwnerRenderer {
-    pub fn name(&self) -> &str {
-        &self.title.runs[0].text
+    /// The first owner with a channel
+    pub fn owner(&self) -> Option<(&str, crate::channel::Id)> {
+        self.title.runs.iter().find_map(|run| {
+            let endpoint = run.navigation_endpoint.as_ref()?;
+            Some((run.text.as_str(), endpoint.browse_endpoint.browse_id))
+        })
     }
 
-    pub fn id(&self) -> crate::channel::Id {
-        self.title.runs[0]
-            .navigation_endpoint
-            .browse_endpoint
-            .browse_id
+    pub fn subscribers(&self) -> Option<u64> {
+        parse::count(&self.subscriber_count_text.as_ref()?.text())
+    }
+
+    pub fn contributors(&self) -> Vec<(&str, Option<crate::channel::Id>)> {
+        let contributors: Vec<_> = self
+            .title
+            .runs
+            .iter()
+            .filter(|run| {
+                let text = run.text.trim();
+                !(text.is_empty()
+                    || matches!(text, "and" | "," | "&" | "•")
+                    || text.ends_with(" more"))
+            })
+            .map(|run| {
+                (
+                    run.text.trim(),
+                    run.navigation_endpoint
+                        .as_ref()
+                        .map(|x| x.browse_endpoint.browse_id),
+                )
+            })
+            .collect();
+
+        if contributors.len() > 1 {
+            contributors
+        } else {
+            Vec::new()
+        }
     }
 }
 ////////////////////////////////////////////////////////////////////////////////
@@ -1,9 +1,15 @@
-use std::time::Duration;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
+use crate::{
+    youtube::{player_response, resolve_url, transcript, unknown},
+    Error,
+};
+use futures_util::future::{BoxFuture, FutureExt, Shared};
 use serde::Serialize;
-use env;
-use base64::engine::general_purpose;
-use crate::{youtube::player_response, Error};
 
 const RETRYS: u32 = 5;
 const TIMEOUT: Duration = Duration::from_secs(30);
@@ -20,7 +26,9 @@ const CONTEXT_WEB: Context<'static> = Context {
         gl: "US",
         client_name: "WEB",
         client_version: "2.20210622.10.0",
+        client_screen: None,
     },
+    third_party: None,
 };
 
 const CONTEXT_ANDROID: Context<'static> = Context {
@@ -29,7 +37,9 @@ const CONTEXT_ANDROID: Context<'static> = Context {
         gl: "US",
         client_name: "ANDROID",
         client_version: "16.05",
+        client_screen: None,
     },
+    third_party: None,
 };
 
 const CONTEXT_EMBEDDED: Context<'static> = Context {
@@ -38,13 +48,26 @@ const CONTEXT_EMBEDDED: Context<'static> = Context {
         gl: "US",
         client_name: "TVHTML5_SIMPLY_EMBEDDED_PLAYER",
         client_version: "2.0",
+        client_screen: Some("EMBED"),
     },
+    // Without a embedding page, the embedded player does not bypass the age-restriction
+    third_party: Some(ThirdParty {
+        embed_url: "https://www.youtube.com/",
+    }),
 };
 
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 struct Context<'a> {
     client: Client<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    third_party: Option<ThirdParty<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ThirdParty<'a> {
+    embed_url: &'a str,
 }
 
 #[derive(Serialize)]
@@ -54,10 +77,37 @@ struct Client<'a> {
     gl: &'a str,
     client_name: &'a str,
     client_version: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_screen: Option<&'a str>,
+}
+
+/// The contexts to request streams with, when the urls of the ANDROID context
+/// are forbidden
+#[derive(Clone, Copy)]
+pub enum StreamFallback {
+    Embedded,
+    Web,
+}
+
+impl StreamFallback {
+    pub const ALL: [Self; 2] = [Self::Embedded, Self::Web];
+
+    fn context(self) -> Context<'static> {
+        match self {
+            Self::Embedded => CONTEXT_EMBEDDED,
+            Self::Web => CONTEXT_WEB,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        self.context().client.client_name
+    }
 }
 
 pub enum ChannelPage {
+    Home,
     About,
+    Videos,
 }
 
 pub enum Browse {
@@ -69,27 +119,223 @@ pub enum Browse {
     Continuation(String),
 }
 
+/// A continuation token for the videos of the playlist `id`, starting at
+/// the 0-based `offset`.
+///
+/// The token is a protobuf message, encoded the same way as the tokens
+/// YouTube hands out when scrolling a playlist. YouTube only starts at the
+/// page containing `offset`.
+pub fn playlist_continuation(id: &crate::playlist::Id, offset: u64) -> String {
+    use base64::{engine::general_purpose, Engine as _};
+
+    fn varint(mut value: u64, out: &mut Vec<u8>) {
+        while value >= 0x80 {
+            out.push((value as u8 & 0x7f) | 0x80);
+            value >>= 7;
+        }
+        out.push(value as u8);
+    }
+
+    fn number(field: u64, value: u64, out: &mut Vec<u8>) {
+        varint(field << 3, out);
+        varint(value, out);
+    }
+
+    fn bytes(field: u64, value: &[u8], out: &mut Vec<u8>) {
+        varint(field << 3 | 2, out);
+        varint(value.len() as u64, out);
+        out.extend_from_slice(value);
+    }
+
+    let encode = |value: &[u8]| general_purpose::URL_SAFE.encode(value).replace('=', "%3D");
+
+    let mut position = Vec::new();
+    number(1, offset, &mut position);
+    let position = format!("PT:{}", general_purpose::URL_SAFE_NO_PAD.encode(position));
+
+    // The first field counts the pages requested so far
+    let mut params = Vec::new();
+    number(1, offset / 100, &mut params);
+    bytes(15, position.as_bytes(), &mut params);
+
+    let mut continuation = Vec::new();
+    bytes(2, format!("VL{}", id).as_bytes(), &mut continuation);
+    bytes(3, encode(&params).as_bytes(), &mut continuation);
+    bytes(35, id.to_string().as_bytes(), &mut continuation);
+
+    let mut token = Vec::new();
+    bytes(80226972, &continuation, &mut token);
+    encode(&token)
+}
+
 pub enum Next {
-    Video(crate::video::Id),
+    Video {
+        id: crate::video::Id,
+        playlist: Option<crate::playlist::Id>,
+    },
     Continuation(String),
 }
 
 #[derive(Clone, Default)]
 pub struct Api {
     pub(crate) http: reqwest::Client,
+    pub(crate) on_unknown_renderer: Option<unknown::Hook>,
+    /// Replaces [`BASE_URL`]
+    pub(crate) base_url: Option<reqwest::Url>,
+    /// Replaces `https://www.youtube.com/` for requests to the website
+    pub(crate) web_url: Option<reqwest::Url>,
+    /// Replaces [`TIMEOUT`]
+    pub(crate) timeout: Option<Duration>,
+    in_flight: Arc<InFlight>,
 }
 
 fn dump(endpoint: &'static str, response: &str) {
-    let _ = std::fs::create_dir(endpoint);
+    let _ = std::fs::create_dir_all(endpoint);
     use std::time::SystemTime;
     let time = SystemTime::now()
         .duration_since(SystemTime::UNIX_EPOCH)
         .expect("TIME");
-    std::fs::write(
-        &format!("{}/{}.json", endpoint, time.as_millis()),
-        &response,
-    )
-        .expect("Write");
+    std::fs::write(format!("{}/{}.json", endpoint, time.as_millis()), response).expect("Write");
+}
+
+pub(crate) fn deserialize<T: serde::de::DeserializeOwned>(
+    endpoint: &'static str,
+    response: &str,
+) -> crate::Result<T> {
+    let deserializer = &mut serde_json::Deserializer::from_str(response);
+
+    serde_path_to_error::deserialize(deserializer).map_err(|err| {
+        let path = err.path().to_string();
+        let source = err.into_inner();
+        let snippet = snippet(response, source.line(), source.column());
+
+        log::error!(
+            "Failed to deserialize response from '{}' at '{}': {}",
+            endpoint,
+            path,
+            source
+        );
+
+        Error::Deserialization {
+            endpoint,
+            path,
+            source,
+            snippet,
+        }
+    })
+}
+
+/// Cut out roughly 200 characters of `response` around the 1-based `line` and
+/// `column` reported by [`serde_json`].
+fn snippet(response: &str, line: usize, column: usize) -> String {
+    const RADIUS: usize = 100;
+
+    let offset = response
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum::<usize>()
+        + column.saturating_sub(1);
+    let offset = offset.min(response.len());
+
+    let floor = |mut idx: usize| {
+        while !response.is_char_boundary(idx) {
+            idx -= 1;
+        }
+        idx
+    };
+
+    let start = floor(offset.saturating_sub(RADIUS));
+    let end = floor((offset + RADIUS).min(response.len()));
+
+    response[start..end].to_string()
+}
+
+type InFlight = Mutex<HashMap<(&'static str, String), Shared<BoxFuture<'static, Fetched>>>>;
+
+type Fetched = std::result::Result<Arc<String>, FetchError>;
+
+/// A transport error that can be handed to every waiter of a coalesced request.
+#[derive(Clone)]
+enum FetchError {
+    Request(Arc<reqwest::Error>),
+    Timeout {
+        attempts: u32,
+        last: Arc<reqwest::Error>,
+    },
+}
+
+impl FetchError {
+    fn into_error(self, endpoint: &'static str) -> Error {
+        match self {
+            Self::Request(err) => Error::Request(err),
+            Self::Timeout { attempts, last } => Error::Timeout {
+                endpoint,
+                attempts,
+                source: last,
+            },
+        }
+    }
+}
+
+/// Send a request to a innertube endpoint, retrying on timeouts.
+async fn send(
+    http: reqwest::Client,
+    base_url: Option<reqwest::Url>,
+    timeout: Duration,
+    endpoint: &'static str,
+    body: String,
+) -> Fetched {
+    let api_key = std::env::var("API_KEY");
+    let api_key = api_key.as_deref().unwrap_or(API_KEY);
+    let base_url = base_url
+        .as_ref()
+        .map_or(BASE_URL, |x| x.as_str().trim_end_matches('/'));
+
+    let request = http
+        .post(format!("{}/{}", base_url, endpoint))
+        .header("X-Goog-Api-Key", api_key)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body(body)
+        .timeout(timeout);
+
+    let mut retry = 0;
+
+    loop {
+        let response = request
+            .try_clone()
+            .unwrap()
+            .send()
+            .await
+            .and_then(|x| x.error_for_status());
+
+        match response {
+            Ok(res) => {
+                break res
+                    .text()
+                    .await
+                    .map(Arc::new)
+                    .map_err(|err| FetchError::Request(Arc::new(err)))
+            }
+            Err(err) => {
+                if err.is_timeout() {
+                    if retry == RETRYS {
+                        log::error!("Timed out {} times. Stopping...", RETRYS);
+                        break Err(FetchError::Timeout {
+                            attempts: RETRYS + 1,
+                            last: Arc::new(err),
+                        });
+                    } else {
+                        log::warn!("Timeout reached, retrying...");
+                        retry += 1;
+                        continue;
+                    }
+                } else {
+                    break Err(FetchError::Request(Arc::new(err)));
+                }
+            }
+        }
+    }
 }
 
 impl Api {
@@ -107,75 +353,79 @@ impl Api {
         }
 
         let request = Request { context, request };
+        let body = serde_json::to_string(&request).expect("Request was not serializable");
 
+        let response = self
+            .fetch(endpoint, body)
+            .await
+            .map_err(|err| err.into_error(endpoint))?;
 
-        // self.http.
-
-
-        let env_api_key = std::env::var("API_KEY");
-        let mut g_api_key: &str = "";
-
-
-        match env_api_key.as_ref() {
-            Ok(key) => {
-                // key.
-
-                g_api_key = key.as_str();
-            }
-            Err(e) => {
-                eprintln!("Error: not value found for api key {:?}", e);
-                g_api_key = API_KEY;
-            }
+        let res = self.deserialize::<T>(endpoint, &response);
+        if DUMP || (DUMP_ERR && res.is_err()) {
+            dump(endpoint, &response)
         }
+        res
+    }
 
-        let g_api_key: &str = g_api_key;
-
-
-        let request = self
-            .http
-            .post(format!("{}/{}", BASE_URL, endpoint))
-            .header("X-Goog-Api-Key", g_api_key)
-            .json(&request)
-            .timeout(TIMEOUT);
-
-        let mut retry = 0;
-
-        loop {
-            let response = request
-                .try_clone()
-                .unwrap()
-                .send()
-                .await
-                .and_then(|x| x.error_for_status())
-                .map(|x| x.text());
+    /// The url of `path` on YouTube's website.
+    pub(crate) fn web_url(&self, path: &str) -> String {
+        let base = self
+            .web_url
+            .as_ref()
+            .map_or("https://www.youtube.com", |x| {
+                x.as_str().trim_end_matches('/')
+            });
+        format!("{}/{}", base, path)
+    }
 
-            match response {
-                Ok(res) => {
-                    let response = res.await?;
+    /// [`deserialize`] a response, reporting unknown renderers to the hook of
+    /// this [`Api`].
+    pub(crate) fn deserialize<T: serde::de::DeserializeOwned>(
+        &self,
+        endpoint: &'static str,
+        response: &str,
+    ) -> crate::Result<T> {
+        unknown::scope(self.on_unknown_renderer.as_ref(), || {
+            deserialize(endpoint, response)
+        })
+    }
 
-                    let res = serde_json::from_str::<T>(&response);
-                    if DUMP || (DUMP_ERR && res.is_err()) {
-                        dump(endpoint, &response)
-                    }
-                    let res = res.expect("Failed to parse JSON");
-                    break Ok(res);
-                }
-                Err(err) => {
-                    if err.is_timeout() {
-                        if retry == RETRYS {
-                            log::error!("Timed out {} times. Stopping...", RETRYS);
-                            break Err(Error::Request(err));
-                        } else {
-                            log::warn!("Timeout reached, retrying...");
-                            retry += 1;
-                            continue;
+    /// Fetch the raw response for a request.
+    ///
+    /// Identical requests that are already in flight are coalesced: every
+    /// caller awaits the same request and receives the same result.
+    async fn fetch(&self, endpoint: &'static str, body: String) -> Fetched {
+        let key = (endpoint, body);
+
+        let fetched = {
+            let mut in_flight = self.in_flight.lock().expect("in-flight map was poisoned");
+            in_flight
+                .entry(key.clone())
+                .or_insert_with(|| {
+                    let http = self.http.clone();
+                    let base_url = self.base_url.clone();
+                    let timeout = self.timeout.unwrap_or(TIMEOUT);
+                    let in_flight = Arc::downgrade(&self.in_flight);
+
+                    async move {
+                        let fetched = send(http, base_url, timeout, key.0, key.1.clone()).await;
+
+                        if let Some(in_flight) = in_flight.upgrade() {
+                            in_flight
+                                .lock()
+                                .expect("in-flight map was poisoned")
+                                .remove(&key);
                         }
-                    } else {
-                        break Err(Error::Request(err));
+
+                        fetched
                     }
-                }
-            }
-        }
+                    .boxed()
+                    .shared()
+                })
+                .clone()
+        };
+
+        fetched.await
     }
 
     pub async fn streams(
@@ -210,6 +460,27 @@ impl Api {
         }
     }
 
+    /// Request the streams with the context of `fallback`, see
+    /// [`StreamFallback`]
+    pub async fn streams_fallback(
+        &self,
+        id: crate::video::Id,
+        fallback: StreamFallback,
+    ) -> crate::Result<player_response::StreamPlayerResponse> {
+        #[derive(Debug, Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Request {
+            video_id: crate::video::Id,
+        }
+
+        let request = Request { video_id: id };
+        self.get("player", request, fallback.context())
+            .await
+            .and_then(
+                |x: player_response::Result<player_response::StreamPlayerResponse>| x.into_std(),
+            )
+    }
+
     pub async fn player(
         &self,
         id: crate::video::Id,
@@ -226,16 +497,40 @@ impl Api {
         self.get("player", request, CONTEXT_ANDROID).await
     }
 
+    /// Request only the [`PlayabilityStatus`](player_response::PlayabilityStatus)
+    /// of a video, skipping everything else of the player response.
+    pub async fn playability(
+        &self,
+        id: crate::video::Id,
+    ) -> crate::Result<player_response::PlayabilityStatus> {
+        #[derive(Debug, Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Request {
+            video_id: crate::video::Id,
+        }
+
+        let request = Request { video_id: id };
+        let response: player_response::PlayabilityResponse =
+            self.get("player", request, CONTEXT_ANDROID).await?;
+
+        Ok(response.playability_status)
+    }
+
     pub async fn next<T: serde::de::DeserializeOwned>(&self, next: Next) -> crate::Result<T> {
         match next {
-            Next::Video(video_id) => {
+            Next::Video { id, playlist } => {
                 #[derive(Debug, Serialize)]
                 #[serde(rename_all = "camelCase")]
                 struct Request {
                     video_id: crate::video::Id,
+                    #[serde(skip_serializing_if = "Option::is_none")]
+                    playlist_id: Option<String>,
                 }
 
-                let request = Request { video_id };
+                let request = Request {
+                    video_id: id,
+                    playlist_id: playlist.map(|x| x.to_string()),
+                };
 
                 self.get("next", request, CONTEXT_WEB).await
             }
@@ -253,6 +548,57 @@ impl Api {
         }
     }
 
+    pub async fn transcript(&self, params: String) -> crate::Result<transcript::Root> {
+        #[derive(Debug, Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Request {
+            params: String,
+        }
+
+        self.get("get_transcript", Request { params }, CONTEXT_WEB)
+            .await
+    }
+
+    /// Resolve a url, e.g. `https://www.youtube.com/@handle`, to the page it
+    /// points to.
+    ///
+    /// Returns [`Youtube::NotFound`](crate::error::Youtube::NotFound) if
+    /// YouTube knows no page at `url`.
+    pub async fn resolve_url(&self, url: String) -> crate::Result<resolve_url::Root> {
+        #[derive(Debug, Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Request {
+            url: String,
+        }
+
+        let request = Request { url: url.clone() };
+        match self
+            .get("navigation/resolve_url", request, CONTEXT_WEB)
+            .await
+        {
+            Err(Error::Request(err)) if err.status() == Some(reqwest::StatusCode::NOT_FOUND) => {
+                Err(crate::error::Youtube::NotFound(url).into())
+            }
+            res => res,
+        }
+    }
+
+    /// Request the `next` response of the ANDROID client, see
+    /// [`next::android`](super::next::android).
+    pub async fn next_android<T: serde::de::DeserializeOwned>(
+        &self,
+        id: crate::video::Id,
+    ) -> crate::Result<T> {
+        #[derive(Debug, Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Request {
+            video_id: crate::video::Id,
+        }
+
+        self.get("next", Request { video_id: id }, CONTEXT_ANDROID)
+            .await
+    }
+
     pub async fn browse<T: serde::de::DeserializeOwned>(&self, browse: Browse) -> crate::Result<T> {
         #[derive(Debug, Serialize)]
         #[serde(rename_all = "camelCase")]
@@ -261,17 +607,26 @@ impl Api {
             #[serde(skip_serializing_if = "Option::is_none")]
             params: Option<String>,
         }
-        use base64::{Engine as _, engine::general_purpose};
+        use base64::{engine::general_purpose, Engine as _};
 
         let request = match browse {
             Browse::Playlist(id) => Request {
                 browse_id: format!("VL{}", id),
-                params: Some(general_purpose::STANDARD_NO_PAD.encode([0xc2, 0x06, 0x02, 0x08, 0x00])),
+                params: Some(
+                    general_purpose::STANDARD_NO_PAD.encode([0xc2, 0x06, 0x02, 0x08, 0x00]),
+                ),
             },
             Browse::Channel { id, page } => Request {
                 browse_id: format!("{}", id),
                 params: match page {
-                    ChannelPage::About => Some(general_purpose::STANDARD_NO_PAD.encode(b"\x12\x05about")),
+                    ChannelPage::Home => None,
+                    ChannelPage::About => {
+                        Some(general_purpose::STANDARD_NO_PAD.encode(b"\x12\x05about"))
+                    }
+                    ChannelPage::Videos => Some(
+                        general_purpose::STANDARD_NO_PAD
+                            .encode(b"\x12\x06videos\xf2\x06\x04\x0a\x02\x3a\x00"),
+                    ),
                 },
             },
             Browse::Continuation(continuation) => {
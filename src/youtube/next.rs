@@ -1,15 +1,353 @@
 use std::ops::Deref;
 
 use super::{
-    parse_date, parse_subscribers, Badge, ChannelNameRuns, ContinuationItemRenderer, SimpleText,
-    Text, Thumbnails, TitleRun,
+    unknown::{self, impl_renderer},
+    Badge, ChannelNameRuns, ContinuationItemRenderer, OptionalChannelNameRun, SimpleText, Text,
+    Thumbnails, TitleRun,
 };
+use crate::parse;
 use serde::Deserialize;
 
-#[derive(Clone, Deserialize)]
+pub mod android;
+pub mod entities;
+
+impl_renderer! {
+    Content => "next.results",
+    PlayerBar => "next.playerOverlays.playerBar",
+    MetadataRow => "next.metadataRowContainer.rows",
+    RichMetadataContent => "next.richMetadataRow.contents",
+    SectionItem => "next.secondaryResults",
+    PlaylistPanelItem => "next.playlist.contents",
+    CommentItem => "next.comments",
+    StructuredDescriptionItem => "next.structuredDescription.items",
+}
+
+/// The parts of a `next` response that [`Video`](crate::Video) reads from
+/// the watch page, available from the responses of both the WEB and the
+/// ANDROID client.
+pub trait WatchNextData {
+    fn likes(&self) -> Option<u64>;
+    fn date(&self) -> Option<chrono::NaiveDate>;
+    fn subscribers(&self) -> Option<u64>;
+    fn owner_thumbnails(&self) -> &[crate::Thumbnail];
+
+    // The following are only shown by the WEB client
+
+    fn hashtags(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
+    fn rich_metadata(&self) -> Vec<&RichMetadataRenderer> {
+        Vec::new()
+    }
+
+    fn waiting(&self) -> Option<u64> {
+        None
+    }
+
+    fn countdown(&self) -> Option<&str> {
+        None
+    }
+
+    fn concurrent_viewers(&self) -> Option<u64> {
+        None
+    }
+}
+
+#[derive(Clone, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct Root {
-    pub contents: Contents,
+    // Missing for some region-blocked or removed videos
+    #[serde(default)]
+    pub contents: Option<Contents>,
+    #[serde(default)]
+    pub player_overlays: Option<PlayerOverlays>,
+    #[serde(default)]
+    pub engagement_panels: Vec<EngagementPanel>,
+    // Only checked by `Video::from_parts`
+    #[cfg_attr(not(feature = "unstable"), allow(dead_code))]
+    pub current_video_endpoint: Option<CurrentVideoEndpoint>,
+    #[serde(default)]
+    pub framework_updates: Option<entities::FrameworkUpdates>,
+}
+
+// Only checked by `Video::from_parts`
+#[cfg_attr(not(feature = "unstable"), allow(dead_code))]
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrentVideoEndpoint {
+    pub watch_endpoint: CurrentWatchEndpoint,
+}
+
+#[cfg_attr(not(feature = "unstable"), allow(dead_code))]
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrentWatchEndpoint {
+    pub video_id: crate::video::Id,
+}
+
+impl WatchNextData for Root {
+    fn likes(&self) -> Option<u64> {
+        let primary = self.results()?.primary()?;
+
+        primary.likes().or_else(|| {
+            // Newer responses only reference a entity holding the like count
+            let key = primary.video_actions.menu_renderer.like_count_entity_key();
+            entities::Entities::new(self.framework_updates.as_ref()?)
+                .like_count(key)?
+                .likes()
+        })
+    }
+
+    fn date(&self) -> Option<chrono::NaiveDate> {
+        self.results()?.primary()?.date()
+    }
+
+    fn subscribers(&self) -> Option<u64> {
+        self.results()?
+            .secondary()?
+            .owner
+            .video_owner_renderer
+            .subscribers()
+    }
+
+    fn owner_thumbnails(&self) -> &[crate::Thumbnail] {
+        self.results()
+            .and_then(Results2::secondary)
+            .map_or(&[], |x| x.owner.video_owner_renderer.thumbnails())
+    }
+
+    fn hashtags(&self) -> Vec<&str> {
+        self.results()
+            .and_then(Results2::primary)
+            .into_iter()
+            .flat_map(|x| x.hashtags())
+            .collect()
+    }
+
+    fn rich_metadata(&self) -> Vec<&RichMetadataRenderer> {
+        self.results()
+            .and_then(Results2::secondary)
+            .into_iter()
+            .flat_map(|x| x.rich_metadata())
+            .collect()
+    }
+
+    fn waiting(&self) -> Option<u64> {
+        self.results()?.primary()?.waiting()
+    }
+
+    fn countdown(&self) -> Option<&str> {
+        self.results()?.primary()?.countdown()
+    }
+
+    fn concurrent_viewers(&self) -> Option<u64> {
+        self.results()?.primary()?.concurrent_viewers()
+    }
+}
+
+impl Root {
+    pub fn music_rows(&self) -> Vec<MusicRow<'_>> {
+        let rows = self
+            .results()
+            .into_iter()
+            .filter_map(Results2::secondary)
+            .flat_map(|x| {
+                &x.metadata_row_container
+                    .metadata_row_container_renderer
+                    .rows
+            })
+            .filter_map(|row| match row {
+                MetadataRow::MetadataRowRenderer(row) => {
+                    Some(MusicRow::new(row.title.text(), row.contents.first()?))
+                }
+                _ => None,
+            });
+
+        let description = self
+            .engagement_panels
+            .iter()
+            .filter_map(|x| {
+                x.engagement_panel_section_list_renderer
+                    .as_ref()?
+                    .content
+                    .as_ref()?
+                    .structured_description_content_renderer
+                    .as_ref()
+            })
+            .flat_map(|x| &x.items)
+            .flat_map(|x| x.music_rows());
+
+        rows.chain(description).collect()
+    }
+
+    /// The value of the `Location` row, e.g. `Tokyo`
+    pub fn location(&self) -> Option<&str> {
+        self.results()?
+            .secondary()?
+            .metadata_row_container
+            .metadata_row_container_renderer
+            .rows
+            .iter()
+            .find_map(|row| match row {
+                MetadataRow::MetadataRowRenderer(row) if row.title.text() == "Location" => {
+                    match row.contents.first()? {
+                        Text::SimpleText(simple) => Some(simple.simple_text.as_str()),
+                        Text::Runs(runs) => Some(runs.runs.first()?.text.as_str()),
+                    }
+                }
+                _ => None,
+            })
+    }
+
+    /// Does this response contain everything [`WatchNextData`] needs?
+    pub fn is_complete(&self) -> bool {
+        self.results()
+            .is_some_and(|x| x.primary().is_some() && x.secondary().is_some())
+    }
+
+    pub fn results(&self) -> Option<&Results2> {
+        self.contents
+            .as_ref()
+            .map(|x| &x.two_column_watch_next_results.results.results)
+    }
+
+    pub fn watch_playlist(self) -> Option<WatchPlaylistRenderer> {
+        Some(
+            self.contents?
+                .two_column_watch_next_results
+                .playlist?
+                .playlist,
+        )
+    }
+
+    pub fn secondary_results(&self) -> Option<&SecondaryResults2> {
+        self.contents
+            .as_ref()?
+            .two_column_watch_next_results
+            .secondary_results
+            .as_ref()
+            .map(|x| &x.secondary_results)
+    }
+
+    pub fn comment_count(&self) -> Option<u64> {
+        self.engagement_panels
+            .iter()
+            .filter_map(|x| x.engagement_panel_section_list_renderer.as_ref())
+            .find(|x| x.panel_identifier.as_deref() == Some("engagement-panel-comments-section"))?
+            .header
+            .as_ref()?
+            .engagement_panel_title_header_renderer
+            .as_ref()?
+            .contextual_info
+            .as_ref()
+            .and_then(|x| parse::count(&x.text()))
+    }
+
+    /// The params for requesting the transcript with [`Api::transcript`](super::innertube::Api::transcript)
+    pub fn transcript_params(&self) -> Option<&str> {
+        self.engagement_panels
+            .iter()
+            .filter_map(|x| x.engagement_panel_section_list_renderer.as_ref())
+            .filter_map(|x| x.content.as_ref()?.continuation_item_renderer.as_ref())
+            .find_map(|x| x.continuation_endpoint.get_transcript_endpoint.as_ref())
+            .map(|x| x.params.as_str())
+    }
+
+    pub fn chapters(&self) -> impl Iterator<Item = &ChapterRenderer> {
+        let bar = self
+            .player_overlays
+            .as_ref()
+            .and_then(|x| {
+                x.player_overlay_renderer
+                    .decorated_player_bar_renderer
+                    .as_ref()
+            })
+            .map(|x| &x.decorated_player_bar_renderer.player_bar);
+
+        let chapters: &[ChapterItem] = match bar {
+            Some(PlayerBar::MultiMarkersPlayerBarRenderer { markers_map }) => markers_map
+                .iter()
+                .find(|x| x.key == "DESCRIPTION_CHAPTERS")
+                .or_else(|| markers_map.first())
+                .map(|x| x.value.chapters.as_slice())
+                .unwrap_or_default(),
+            Some(PlayerBar::ChapteredPlayerBarRenderer { chapters }) => chapters,
+            Some(PlayerBar::Other) | None => &[],
+        };
+
+        chapters.iter().map(|x| &x.chapter_renderer)
+    }
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerOverlays {
+    pub player_overlay_renderer: PlayerOverlayRenderer,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerOverlayRenderer {
+    pub decorated_player_bar_renderer: Option<DecoratedPlayerBar>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecoratedPlayerBar {
+    pub decorated_player_bar_renderer: DecoratedPlayerBarRenderer,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecoratedPlayerBarRenderer {
+    #[serde(deserialize_with = "unknown::single")]
+    pub player_bar: PlayerBar,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PlayerBar {
+    #[serde(rename_all = "camelCase")]
+    MultiMarkersPlayerBarRenderer {
+        #[serde(default)]
+        markers_map: Vec<MarkersMapEntry>,
+    },
+    ChapteredPlayerBarRenderer {
+        #[serde(default)]
+        chapters: Vec<ChapterItem>,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkersMapEntry {
+    pub key: String,
+    pub value: MarkersMapValue,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkersMapValue {
+    #[serde(default)]
+    pub chapters: Vec<ChapterItem>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChapterItem {
+    pub chapter_renderer: ChapterRenderer,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChapterRenderer {
+    pub title: SimpleText,
+    pub time_range_start_millis: u64,
+    #[serde(default)]
+    pub thumbnail: Thumbnails,
 }
 
 #[derive(Clone, Deserialize)]
@@ -23,6 +361,262 @@ pub struct Contents {
 pub struct TwoColumnWatchNextResults {
     pub results: Results,
     pub secondary_results: Option<SecondaryResults>,
+    // Only present when requested with a playlist
+    #[serde(default)]
+    pub playlist: Option<WatchPlaylist>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchPlaylist {
+    pub playlist: WatchPlaylistRenderer,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchPlaylistRenderer {
+    // `Mix - <TITLE>`
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default, deserialize_with = "unknown::vec")]
+    pub contents: Vec<PlaylistPanelItem>,
+}
+
+impl WatchPlaylistRenderer {
+    pub fn videos(&self) -> impl Iterator<Item = &PlaylistPanelVideoRenderer> {
+        self.contents.iter().filter_map(|x| match x {
+            PlaylistPanelItem::PlaylistPanelVideoRenderer(video) => Some(video),
+            PlaylistPanelItem::Other => None,
+        })
+    }
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PlaylistPanelItem {
+    PlaylistPanelVideoRenderer(PlaylistPanelVideoRenderer),
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistPanelVideoRenderer {
+    pub video_id: crate::video::Id,
+    pub title: Text,
+    #[serde(default)]
+    pub thumbnail: Thumbnails,
+    // `3:33`, missing for livestreams
+    #[serde(default)]
+    pub length_text: Option<SimpleText>,
+    // 1-based position, e.g. `1`
+    #[serde(default)]
+    pub index_text: Option<SimpleText>,
+    // channel name
+    #[serde(default)]
+    pub short_byline_text: Option<ChannelNameRuns>,
+}
+
+impl PlaylistPanelVideoRenderer {
+    pub fn index(&self) -> Option<u64> {
+        self.index_text.as_deref().and_then(parse::digits)
+    }
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EngagementPanel {
+    pub engagement_panel_section_list_renderer: Option<EngagementPanelSectionListRenderer>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EngagementPanelSectionListRenderer {
+    pub panel_identifier: Option<String>,
+    pub header: Option<EngagementPanelHeader>,
+    pub content: Option<EngagementPanelContent>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EngagementPanelContent {
+    pub continuation_item_renderer: Option<EngagementPanelContinuation>,
+    pub structured_description_content_renderer: Option<StructuredDescriptionContentRenderer>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StructuredDescriptionContentRenderer {
+    #[serde(default, deserialize_with = "unknown::vec")]
+    pub items: Vec<StructuredDescriptionItem>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StructuredDescriptionItem {
+    HorizontalCardListRenderer(HorizontalCardListRenderer),
+    VideoDescriptionMusicSectionRenderer(VideoDescriptionMusicSectionRenderer),
+    #[serde(other)]
+    Other,
+}
+
+impl StructuredDescriptionItem {
+    fn music_rows(&self) -> Vec<MusicRow<'_>> {
+        match self {
+            // "Music" cards: title is the song, subtitle the artist and
+            // secondary subtitle the album
+            Self::HorizontalCardListRenderer(list) if list.is_music() => list
+                .cards
+                .iter()
+                .filter_map(|x| x.video_attribute_view_model.as_ref())
+                .take(1)
+                .flat_map(|card| {
+                    [
+                        Some(("Song", card.title.clone())),
+                        card.subtitle.clone().map(|x| ("Artist", x)),
+                        card.secondary_subtitle
+                            .as_ref()
+                            .map(|x| ("Album", x.content.clone())),
+                    ]
+                })
+                .flatten()
+                .map(|(title, value)| MusicRow {
+                    title: title.to_string(),
+                    value,
+                    link: None,
+                })
+                .collect(),
+            // "Music in this video" section with labeled rows
+            Self::VideoDescriptionMusicSectionRenderer(section) => section
+                .carousel_lockups
+                .iter()
+                .take(1)
+                .flat_map(|x| &x.carousel_lockup_renderer.info_rows)
+                .filter_map(|x| {
+                    let row = &x.info_row_renderer;
+                    let value = row
+                        .default_metadata
+                        .as_ref()
+                        .or(row.expanded_metadata.as_ref())?;
+                    Some(MusicRow::new(row.title.text(), value))
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HorizontalCardListRenderer {
+    pub header: Option<HorizontalCardListHeader>,
+    #[serde(default)]
+    pub cards: Vec<HorizontalCard>,
+}
+
+impl HorizontalCardListRenderer {
+    fn is_music(&self) -> bool {
+        self.header
+            .as_ref()
+            .is_some_and(|x| x.rich_list_header_renderer.title.text() == "Music")
+    }
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HorizontalCardListHeader {
+    pub rich_list_header_renderer: RichListHeaderRenderer,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RichListHeaderRenderer {
+    pub title: Text,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HorizontalCard {
+    pub video_attribute_view_model: Option<VideoAttributeViewModel>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoAttributeViewModel {
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub secondary_subtitle: Option<ViewModelText>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewModelText {
+    pub content: String,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoDescriptionMusicSectionRenderer {
+    #[serde(default)]
+    pub carousel_lockups: Vec<CarouselLockup>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CarouselLockup {
+    pub carousel_lockup_renderer: CarouselLockupRenderer,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CarouselLockupRenderer {
+    #[serde(default)]
+    pub info_rows: Vec<InfoRow>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InfoRow {
+    pub info_row_renderer: InfoRowRenderer,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InfoRowRenderer {
+    pub title: Text,
+    pub default_metadata: Option<LinkedText>,
+    pub expanded_metadata: Option<LinkedText>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EngagementPanelContinuation {
+    pub continuation_endpoint: EngagementPanelContinuationEndpoint,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EngagementPanelContinuationEndpoint {
+    pub get_transcript_endpoint: Option<GetTranscriptEndpoint>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTranscriptEndpoint {
+    pub params: String,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EngagementPanelHeader {
+    pub engagement_panel_title_header_renderer: Option<EngagementPanelTitleHeaderRenderer>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EngagementPanelTitleHeaderRenderer {
+    // The comment count, missing if comments are turned off
+    pub contextual_info: Option<Text>,
 }
 
 #[derive(Clone, Deserialize)]
@@ -34,28 +628,23 @@ pub struct Results {
 #[derive(Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Results2 {
+    #[serde(deserialize_with = "unknown::vec")]
     pub contents: Vec<Content>,
 }
 
 impl Results2 {
-    pub fn primary(&self) -> &VideoPrimaryInfoRenderer {
-        self.contents
-            .iter()
-            .find_map(|x| match x {
-                Content::VideoPrimaryInfoRenderer(ret) => Some(ret),
-                _ => None,
-            })
-            .expect("VideoPrimaryInfoRenderer was not found")
+    pub fn primary(&self) -> Option<&VideoPrimaryInfoRenderer> {
+        self.contents.iter().find_map(|x| match x {
+            Content::VideoPrimaryInfoRenderer(ret) => Some(ret),
+            _ => None,
+        })
     }
 
-    pub fn secondary(&self) -> &VideoSecondaryInfoRenderer {
-        self.contents
-            .iter()
-            .find_map(|x| match x {
-                Content::VideoSecondaryInfoRenderer(ret) => Some(ret),
-                _ => None,
-            })
-            .expect("VideoSecondaryInfoRenderer was not found")
+    pub fn secondary(&self) -> Option<&VideoSecondaryInfoRenderer> {
+        self.contents.iter().find_map(|x| match x {
+            Content::VideoSecondaryInfoRenderer(ret) => Some(ret),
+            _ => None,
+        })
     }
 
     pub fn comments(&self) -> Option<&ContinuationItemRenderer> {
@@ -86,38 +675,92 @@ pub struct VideoPrimaryInfoRenderer {
     #[serde(default)]
     pub super_title_link: SuperTitleLink,
     pub date_text: SimpleText,
+    pub view_count: Option<ViewCount>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewCount {
+    pub video_view_count_renderer: VideoViewCountRenderer,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoViewCountRenderer {
+    pub view_count: Option<Text>,
+    #[serde(default)]
+    pub is_live: bool,
 }
 
 impl VideoPrimaryInfoRenderer {
-    pub fn date(&self) -> chrono::NaiveDate {
+    pub fn date(&self) -> Option<chrono::NaiveDate> {
         let date_str = self
             .date_text
             .deref()
             .trim_start_matches("Streamed live on ");
 
-        parse_date(date_str).expect("Unable to parse date")
+        parse::date(date_str)
     }
 
     pub fn likes(&self) -> Option<u64> {
-        // `like this video along with 4,457 other people` or `I like this`
-        let label = &self
-            .video_actions
-            .menu_renderer
-            .like_button()?
-            .accessibility
-            .as_ref()?
-            .label;
+        let button = self.video_actions.menu_renderer.like_button()?;
 
-        let (_, likes) = label.split_once("like this video along with ")?;
-        let (likes, _) = likes.split_once(" other people")?;
+        if let Some(likes) = button.like_count {
+            return Some(likes);
+        }
 
-        let likes = likes.replace(',', "");
+        // `4,457 likes`, `4.457 „Mag ich“-Bewertungen`,
+        // `like this video along with 4,457 other people` or `1.2M likes`,
+        // but not `I like this`
+        button
+            .default_text
+            .as_ref()
+            .and_then(|x| x.accessibility.as_ref())
+            .map(|x| &x.accessibility_data)
+            .into_iter()
+            .chain(button.accessibility.as_ref())
+            .find_map(|x| parse::digits(&x.label).or_else(|| parse::count(&x.label)))
+    }
+
+    pub fn concurrent_viewers(&self) -> Option<u64> {
+        // `12,345 watching now`
+        let view_count = &self.view_count.as_ref()?.video_view_count_renderer;
+        if !view_count.is_live {
+            return None;
+        }
 
-        let likes = likes
+        view_count
+            .view_count
+            .as_ref()?
+            .text()
+            .split_whitespace()
+            .next()?
+            .replace(',', "")
             .parse()
-            .expect("Likes we not parsable as a unsigned integer");
+            .ok()
+    }
+
+    pub fn waiting(&self) -> Option<u64> {
+        // `1,234 waiting` for upcoming premieres and livestreams
+        let text = self
+            .view_count
+            .as_ref()?
+            .video_view_count_renderer
+            .view_count
+            .as_ref()?
+            .text();
+
+        if text.trim_end().ends_with("waiting") {
+            parse::count(&text)
+        } else {
+            None
+        }
+    }
 
-        Some(likes)
+    pub fn countdown(&self) -> Option<&str> {
+        // `Premieres in 35 minutes`
+        let text: &str = &self.date_text;
+        parse::countdown(text).map(|_| text)
     }
 
     pub fn hashtags(&self) -> impl Iterator<Item = &str> {
@@ -140,9 +783,23 @@ impl MenuRenderer {
     fn like_button(&self) -> Option<&ToggleButtonRenderer> {
         self.top_level_buttons.iter().find_map(|x| match x {
             TopLevelButton::ToggleButtonRenderer(ref button) => Some(button),
+            TopLevelButton::SegmentedLikeDislikeButtonRenderer(ref segmented) => segmented
+                .like_button
+                .as_ref()
+                .map(|x| &x.toggle_button_renderer),
+            TopLevelButton::SegmentedLikeDislikeButtonViewModel(_) => None,
             TopLevelButton::ButtonRenderer {} => None,
             TopLevelButton::DownloadButtonRenderer {} => None,
-            TopLevelButton::SegmentedLikeDislikeButtonRenderer {} => None,
+        })
+    }
+
+    fn like_count_entity_key(&self) -> Option<&str> {
+        self.top_level_buttons.iter().find_map(|x| match x {
+            TopLevelButton::SegmentedLikeDislikeButtonViewModel(view_model) => view_model
+                .like_count_entity
+                .as_ref()
+                .map(|x| x.key.as_str()),
+            _ => None,
         })
     }
 }
@@ -153,15 +810,50 @@ pub enum TopLevelButton {
     ToggleButtonRenderer(ToggleButtonRenderer),
     DownloadButtonRenderer {},
     ButtonRenderer {},
-    SegmentedLikeDislikeButtonRenderer {},
+    SegmentedLikeDislikeButtonRenderer(SegmentedLikeDislikeButtonRenderer),
+    SegmentedLikeDislikeButtonViewModel(SegmentedLikeDislikeButtonViewModel),
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SegmentedLikeDislikeButtonViewModel {
+    pub like_count_entity: Option<entities::EntityRef>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SegmentedLikeDislikeButtonRenderer {
+    pub like_button: Option<LikeButton>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LikeButton {
+    pub toggle_button_renderer: ToggleButtonRenderer,
 }
 
 #[derive(Clone, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct ToggleButtonRenderer {
+    #[serde(default)]
+    pub like_count: Option<u64>,
+    #[serde(default)]
+    pub default_text: Option<AccessibleText>,
     pub accessibility: Option<AccessibilityData>,
 }
 
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessibleText {
+    pub accessibility: Option<Accessibility>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Accessibility {
+    pub accessibility_data: AccessibilityData,
+}
+
 #[derive(Clone, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct AccessibilityData {
@@ -178,9 +870,28 @@ pub struct SuperTitleLink {
 #[serde(rename_all = "camelCase")]
 pub struct VideoSecondaryInfoRenderer {
     pub owner: Owner,
+    #[serde(default)]
     pub metadata_row_container: MetadataRowContainer,
 }
 
+impl VideoSecondaryInfoRenderer {
+    pub fn rich_metadata(&self) -> impl Iterator<Item = &RichMetadataRenderer> {
+        self.metadata_row_container
+            .metadata_row_container_renderer
+            .rows
+            .iter()
+            .filter_map(|row| match row {
+                MetadataRow::RichMetadataRowRenderer(row) => Some(row.contents.iter()),
+                MetadataRow::MetadataRowRenderer(_) | MetadataRow::Other => None,
+            })
+            .flatten()
+            .filter_map(|content| match content {
+                RichMetadataContent::RichMetadataRenderer(renderer) => Some(renderer),
+                RichMetadataContent::Other => None,
+            })
+    }
+}
+
 #[derive(Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Owner {
@@ -195,15 +906,8 @@ pub struct VideoOwnerRenderer {
 }
 impl VideoOwnerRenderer {
     pub fn subscribers(&self) -> Option<u64> {
-        self.subscriber_count_text.as_ref().map(|x| {
-            parse_subscribers(
-                x.simple_text
-                    .split_once(' ')
-                    .expect("no space in subscriber_count_text")
-                    .0,
-            )
-            .expect("Unable to parse subscriber count")
-        })
+        // `1.2M subscribers` or `1 subscriber`, missing if hidden
+        parse::count(&self.subscriber_count_text.as_ref()?.simple_text)
     }
 
     pub fn thumbnails(&self) -> &Vec<crate::Thumbnail> {
@@ -211,16 +915,123 @@ impl VideoOwnerRenderer {
     }
 }
 
-#[derive(Clone, Deserialize)]
+#[derive(Clone, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct MetadataRowContainer {
     pub metadata_row_container_renderer: MetadataRowContainerRenderer,
 }
 
-#[derive(Clone, Deserialize)]
+#[derive(Clone, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct MetadataRowContainerRenderer {
-    pub collapsed_item_count: i64,
+    #[serde(default, deserialize_with = "unknown::vec")]
+    pub rows: Vec<MetadataRow>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MetadataRow {
+    RichMetadataRowRenderer(RichMetadataRowRenderer),
+    MetadataRowRenderer(MetadataRowRenderer),
+    #[serde(other)]
+    Other,
+}
+
+/// A row like `Song: Gangnam Style`
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataRowRenderer {
+    pub title: Text,
+    #[serde(default)]
+    pub contents: Vec<LinkedText>,
+}
+
+pub type LinkedText = Text<SimpleText, super::Runs<LinkedRun>>;
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkedRun {
+    pub text: String,
+    pub navigation_endpoint: Option<LinkedEndpoint>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkedEndpoint {
+    pub browse_endpoint: Option<RichMetadataBrowseEndpoint>,
+}
+
+/// A labeled value describing the music in a video, taken from either the
+/// metadata rows or the structured description
+pub struct MusicRow<'a> {
+    pub title: String,
+    pub value: String,
+    // The browse id the value links to, e.g. the channel of an artist
+    pub link: Option<&'a str>,
+}
+
+impl<'a> MusicRow<'a> {
+    fn new(title: String, text: &'a LinkedText) -> Self {
+        match text {
+            Text::SimpleText(simple) => Self {
+                title,
+                value: simple.simple_text.clone(),
+                link: None,
+            },
+            Text::Runs(runs) => Self {
+                title,
+                value: runs.runs.iter().map(|x| x.text.as_str()).collect(),
+                link: runs.runs.iter().find_map(|x| {
+                    Some(
+                        x.navigation_endpoint
+                            .as_ref()?
+                            .browse_endpoint
+                            .as_ref()?
+                            .browse_id
+                            .as_str(),
+                    )
+                }),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RichMetadataRowRenderer {
+    #[serde(default, deserialize_with = "unknown::vec")]
+    pub contents: Vec<RichMetadataContent>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RichMetadataContent {
+    RichMetadataRenderer(RichMetadataRenderer),
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RichMetadataRenderer {
+    pub style: String,
+    #[serde(default)]
+    pub thumbnail: Thumbnails,
+    pub title: Text,
+    pub subtitle: Option<Text>,
+    pub endpoint: Option<RichMetadataEndpoint>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RichMetadataEndpoint {
+    pub browse_endpoint: Option<RichMetadataBrowseEndpoint>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RichMetadataBrowseEndpoint {
+    pub browse_id: String,
 }
 
 #[derive(Clone, Deserialize)]
@@ -249,7 +1060,7 @@ pub enum SecondaryResults2 {
         results: Vec<SecondaryResultsItem>,
     },
     WithoutChips {
-        #[serde(default)]
+        #[serde(default, deserialize_with = "unknown::vec")]
         results: Vec<SectionItem>,
     },
 }
@@ -279,7 +1090,7 @@ pub enum SecondaryResultsItem {
 #[derive(Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SecondaryItemSectionRenderer {
-    #[serde(default)]
+    #[serde(default, deserialize_with = "unknown::vec")]
     pub contents: Vec<SectionItem>,
 }
 
@@ -305,9 +1116,16 @@ pub struct CompactVideoRenderer {
     pub short_byline_text: ChannelNameRuns,
     pub view_count_text: Option<Text>,
     pub length_text: Option<SimpleText>,
+    // Missing for livestreams
+    #[serde(default)]
+    pub published_time_text: Option<SimpleText>,
 
     #[serde(default)]
     pub owner_badges: Vec<Badge>,
+    #[serde(default)]
+    pub badges: Vec<Badge>,
+    #[serde(default)]
+    pub channel_thumbnail: Thumbnails,
 }
 
 #[derive(Clone, Deserialize)]
@@ -318,8 +1136,6 @@ pub struct CompactPlaylistRenderer {
     pub title: SimpleText,
     // channel name
     pub short_byline_text: Text<SimpleText, super::Runs<OptionalChannelNameRun>>,
-    // length
-    pub video_count_short_text: SimpleText,
 
     #[serde(default)]
     pub owner_badges: Vec<Badge>,
@@ -361,13 +1177,6 @@ pub struct CompactMovieRenderer {
     pub length_text: SimpleText,
 }
 
-#[derive(Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct OptionalChannelNameRun {
-    pub text: String,
-    pub navigation_endpoint: Option<super::NavigationEndpoint>,
-}
-
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Continuation {
@@ -396,5 +1205,78 @@ pub struct OnResponseReceivedEndpoint {
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AppendContinuationItemsAction {
+    #[serde(deserialize_with = "unknown::vec")]
     pub continuation_items: Vec<SectionItem>,
 }
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentsContinuation {
+    #[serde(default)]
+    pub on_response_received_endpoints: Vec<CommentsEndpoint>,
+}
+
+impl CommentsContinuation {
+    pub fn into_items(self) -> impl Iterator<Item = CommentItem> {
+        self.on_response_received_endpoints
+            .into_iter()
+            .filter_map(|x| {
+                x.reload_continuation_items_command
+                    .or(x.append_continuation_items_action)
+            })
+            .flat_map(|x| x.continuation_items)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentsEndpoint {
+    // The first page reloads the comment section, every further page appends to it
+    pub reload_continuation_items_command: Option<CommentItems>,
+    pub append_continuation_items_action: Option<CommentItems>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentItems {
+    #[serde(default, deserialize_with = "unknown::vec")]
+    pub continuation_items: Vec<CommentItem>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CommentItem {
+    CommentThreadRenderer(CommentThreadRenderer),
+    CommentsHeaderRenderer {},
+    ContinuationItemRenderer(ContinuationItemRenderer),
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentThreadRenderer {
+    pub comment: Option<CommentThreadComment>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentThreadComment {
+    pub comment_renderer: CommentRenderer,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentRenderer {
+    pub comment_id: String,
+    // Missing for deleted or terminated accounts
+    #[serde(default)]
+    pub author_text: SimpleText,
+    pub author_endpoint: Option<super::NavigationEndpoint>,
+    pub content_text: Text,
+    pub published_time_text: Text,
+    // Missing if the comment has no likes
+    pub vote_count: Option<SimpleText>,
+    #[serde(default)]
+    pub reply_count: u64,
+}
@@ -0,0 +1,193 @@
+//! The `next` response of the ANDROID client.
+//!
+//! This is a different shape than the WEB response, but it changes far less
+//! often. It is only used as a fallback, see [`WatchNextData`].
+
+use serde::Deserialize;
+
+use crate::parse;
+
+use super::{
+    super::{Text, Thumbnails},
+    unknown::{self, impl_renderer},
+    WatchNextData,
+};
+
+impl_renderer! {
+    Content => "next.android.results",
+    MetadataContent => "next.android.slimVideoMetadataSection",
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Root {
+    pub contents: Contents,
+    // Only checked by `Video::from_parts`
+    #[cfg_attr(not(feature = "unstable"), allow(dead_code))]
+    pub current_video_endpoint: Option<super::CurrentVideoEndpoint>,
+}
+
+impl Root {
+    fn metadata(&self) -> impl Iterator<Item = &MetadataContent> {
+        self.contents
+            .single_column_watch_next_results
+            .results
+            .results
+            .contents
+            .iter()
+            .filter_map(|x| match x {
+                Content::SlimVideoMetadataSectionRenderer(section) => Some(section.contents.iter()),
+                Content::Other => None,
+            })
+            .flatten()
+    }
+
+    fn owner(&self) -> Option<&SlimOwnerRenderer> {
+        self.metadata().find_map(|x| match x {
+            MetadataContent::SlimOwnerRenderer(owner) => Some(owner),
+            _ => None,
+        })
+    }
+}
+
+impl WatchNextData for Root {
+    fn likes(&self) -> Option<u64> {
+        // `12,345 likes`, `12.345 „Mag ich“-Bewertungen` or `1.2M likes`
+        let label = self
+            .metadata()
+            .filter_map(|x| match x {
+                MetadataContent::SlimVideoActionBarRenderer(bar) => Some(bar.buttons.iter()),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|x| x.slim_metadata_toggle_button_renderer.as_ref())
+            .find(|x| x.is_like)?
+            .button
+            .toggle_button_renderer
+            .default_text
+            .accessibility
+            .as_ref()?
+            .accessibility_data
+            .label
+            .as_str();
+
+        parse::digits(label).or_else(|| parse::count(label))
+    }
+
+    fn date(&self) -> Option<chrono::NaiveDate> {
+        self.metadata().find_map(|x| match x {
+            MetadataContent::SlimVideoInformationRenderer(info) => info
+                .date_text
+                .as_ref()
+                .and_then(|x| parse::date(x.text().trim_start_matches("Streamed live on "))),
+            _ => None,
+        })
+    }
+
+    fn subscribers(&self) -> Option<u64> {
+        // `1.2M subscribers`
+        parse::count(&self.owner()?.expanded_subtitle.as_ref()?.text())
+    }
+
+    fn owner_thumbnails(&self) -> &[crate::Thumbnail] {
+        self.owner()
+            .map_or(&[], |x| x.thumbnail.thumbnails.as_slice())
+    }
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Contents {
+    pub single_column_watch_next_results: SingleColumnWatchNextResults,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SingleColumnWatchNextResults {
+    pub results: Results,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Results {
+    pub results: Results2,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Results2 {
+    #[serde(default, deserialize_with = "unknown::vec")]
+    pub contents: Vec<Content>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Content {
+    SlimVideoMetadataSectionRenderer(SlimVideoMetadataSectionRenderer),
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlimVideoMetadataSectionRenderer {
+    #[serde(default, deserialize_with = "unknown::vec")]
+    pub contents: Vec<MetadataContent>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MetadataContent {
+    SlimVideoInformationRenderer(SlimVideoInformationRenderer),
+    SlimVideoActionBarRenderer(SlimVideoActionBarRenderer),
+    SlimOwnerRenderer(SlimOwnerRenderer),
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlimVideoInformationRenderer {
+    pub date_text: Option<Text>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlimVideoActionBarRenderer {
+    #[serde(default)]
+    pub buttons: Vec<ActionBarButton>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionBarButton {
+    pub slim_metadata_toggle_button_renderer: Option<SlimMetadataToggleButtonRenderer>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlimMetadataToggleButtonRenderer {
+    #[serde(default)]
+    pub is_like: bool,
+    pub button: SlimToggleButton,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlimToggleButton {
+    pub toggle_button_renderer: SlimToggleButtonRenderer,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlimToggleButtonRenderer {
+    pub default_text: super::AccessibleText,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlimOwnerRenderer {
+    #[serde(default)]
+    pub thumbnail: Thumbnails,
+    pub expanded_subtitle: Option<Text>,
+}
@@ -0,0 +1,104 @@
+//! The entities of `frameworkUpdates.entityBatchUpdate.mutations`.
+//!
+//! Newer responses move some data out of the renderers into entities. A
+//! renderer then only references an entity by its key, e.g. the like button
+//! references a `likeCountEntity`.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::parse;
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FrameworkUpdates {
+    #[serde(default)]
+    pub entity_batch_update: EntityBatchUpdate,
+}
+
+#[derive(Clone, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityBatchUpdate {
+    #[serde(default)]
+    pub mutations: Vec<Mutation>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Mutation {
+    pub entity_key: String,
+    // Missing for deletions
+    #[serde(default)]
+    pub payload: Payload,
+}
+
+/// A payload holds exactly one entity, keyed by its type. Unknown types are
+/// ignored.
+#[derive(Clone, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Payload {
+    pub like_count_entity: Option<LikeCountEntity>,
+}
+
+#[serde_with::serde_as]
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LikeCountEntity {
+    #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+    #[serde(default)]
+    pub like_count_if_indifferent_number: Option<u64>,
+    // `4,457`
+    pub expanded_like_count_if_indifferent: Option<Content>,
+}
+
+impl LikeCountEntity {
+    pub fn likes(&self) -> Option<u64> {
+        self.like_count_if_indifferent_number
+            .or_else(|| parse::digits(&self.expanded_like_count_if_indifferent.as_ref()?.content))
+    }
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Content {
+    pub content: String,
+}
+
+/// A reference to an entity from a renderer
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityRef {
+    pub key: String,
+}
+
+/// The payloads of all mutations, keyed by their entity key
+pub struct Entities<'a>(HashMap<&'a str, &'a Payload>);
+
+impl<'a> Entities<'a> {
+    pub fn new(updates: &'a FrameworkUpdates) -> Self {
+        Self(
+            updates
+                .entity_batch_update
+                .mutations
+                .iter()
+                .map(|x| (x.entity_key.as_str(), &x.payload))
+                .collect(),
+        )
+    }
+
+    /// The [`LikeCountEntity`] with the given key, or the only one if no key
+    /// is known
+    pub fn like_count(&self, key: Option<&str>) -> Option<&'a LikeCountEntity> {
+        let like_count = |payload: &&'a Payload| payload.like_count_entity.as_ref();
+
+        match key {
+            Some(key) => self.0.get(key).and_then(like_count),
+            None => {
+                let mut like_counts = self.0.values().filter_map(like_count);
+                let entity = like_counts.next()?;
+                like_counts.next().is_none().then_some(entity)
+            }
+        }
+    }
+}
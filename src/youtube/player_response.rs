@@ -3,24 +3,49 @@ use std::time::Duration;
 use reqwest::Url;
 use serde::Deserialize;
 
-use super::Thumbnails;
+use super::{unknown, Thumbnails};
 
-#[derive(Deserialize)]
-#[serde(rename_all = "camelCase", untagged)]
 pub enum Result<T> {
     Ok(T),
     Error {
-        #[serde(rename = "playabilityStatus")]
         playability_status: PlayabilityStatus,
     },
 }
 
+impl<'de, T: serde::de::DeserializeOwned> Deserialize<'de> for Result<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+
+        let err = match serde_path_to_error::deserialize(&value) {
+            Ok(ok) => return Ok(Self::Ok(ok)),
+            Err(err) => err,
+        };
+
+        // Only a response that is not playable is a error from YouTube, a
+        // playable one that does not match `T` is malformed
+        let status = &value["playabilityStatus"];
+        if status["status"].as_str().is_some_and(|x| x != "OK") {
+            if let Ok(playability_status) = PlayabilityStatus::deserialize(status) {
+                return Ok(Self::Error { playability_status });
+            }
+        }
+
+        Err(serde::de::Error::custom(format!(
+            "{} at '{}'",
+            err.inner(),
+            err.path()
+        )))
+    }
+}
+
 impl<T> Result<T> {
     pub fn into_std(self) -> crate::Result<T> {
         match self {
-            Self::Error { playability_status } => Err(crate::Error::Youtube(
-                crate::error::Youtube(playability_status.reason),
-            )),
+            Self::Error { playability_status } => {
+                Err(crate::Error::Youtube(playability_status.as_error()))
+            }
             Self::Ok(ok) => Ok(ok),
         }
     }
@@ -30,6 +55,298 @@ impl<T> Result<T> {
 #[serde(rename_all = "camelCase")]
 pub struct PlayerResponse {
     pub video_details: VideoDetails,
+    #[serde(default)]
+    pub playability_status: PlayabilityStatus,
+    pub microformat: Option<Microformat>,
+    pub paid_content_overlay: Option<PaidContentOverlay>,
+    pub captions: Option<Captions>,
+    pub storyboards: Option<Storyboards>,
+    #[serde(default, deserialize_with = "unknown::lenient")]
+    pub endscreen: Option<Endscreen>,
+    #[serde(default, deserialize_with = "unknown::lenient")]
+    pub cards: Option<Cards>,
+    pub playback_tracking: Option<PlaybackTracking>,
+    // Missing for videos that can not be played, e.g. age-restricted ones
+    pub streaming_data: Option<StreamingData>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaybackTracking {
+    pub videostats_playback_url: TrackingUrl,
+    pub videostats_watchtime_url: Option<TrackingUrl>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackingUrl {
+    pub base_url: Url,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Cards {
+    pub card_collection_renderer: CardCollectionRenderer,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CardCollectionRenderer {
+    #[serde(default, deserialize_with = "unknown::lenient_vec")]
+    pub cards: Vec<CardItem>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CardItem {
+    pub card_renderer: CardRenderer,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CardRenderer {
+    pub teaser: Option<CardTeaser>,
+    #[serde(default)]
+    pub cue_ranges: Vec<CueRange>,
+    #[serde(default)]
+    pub content: CardContent,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CardTeaser {
+    pub simple_card_teaser_renderer: Option<SimpleCardTeaserRenderer>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SimpleCardTeaserRenderer {
+    pub message: super::Text,
+}
+
+#[serde_with::serde_as]
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CueRange {
+    #[serde_as(as = "serde_with::DurationMilliSeconds<String>")]
+    pub start_card_active_ms: Duration,
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CardContent {
+    pub video_info_card_content_renderer: Option<VideoInfoCardContentRenderer>,
+    pub playlist_info_card_content_renderer: Option<PlaylistInfoCardContentRenderer>,
+    pub simple_card_content_renderer: Option<SimpleCardContentRenderer>,
+    pub poll_renderer: Option<PollRenderer>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoInfoCardContentRenderer {
+    pub action: CardAction,
+    pub video_title: Option<super::Text>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistInfoCardContentRenderer {
+    pub action: CardAction,
+    pub playlist_title: Option<super::Text>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SimpleCardContentRenderer {
+    pub command: CardAction,
+    pub title: Option<super::Text>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PollRenderer {}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CardAction {
+    pub watch_endpoint: Option<EndscreenWatchEndpoint>,
+    pub url_endpoint: Option<EndscreenUrlEndpoint>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Endscreen {
+    pub endscreen_renderer: EndscreenRenderer,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EndscreenRenderer {
+    #[serde(default, deserialize_with = "unknown::lenient_vec")]
+    pub elements: Vec<EndscreenElement>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EndscreenElement {
+    pub endscreen_element_renderer: EndscreenElementRenderer,
+}
+
+#[serde_with::serde_as]
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EndscreenElementRenderer {
+    // `VIDEO`, `PLAYLIST`, `CHANNEL`, `WEBSITE`, `SUBSCRIBE`, ...
+    pub style: String,
+    #[serde(default)]
+    pub image: Option<Thumbnails>,
+    pub title: Option<super::Text>,
+    #[serde_as(as = "serde_with::DurationMilliSeconds<String>")]
+    pub start_ms: Duration,
+    #[serde_as(as = "serde_with::DurationMilliSeconds<String>")]
+    pub end_ms: Duration,
+    #[serde(default)]
+    pub endpoint: EndscreenEndpoint,
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct EndscreenEndpoint {
+    pub watch_endpoint: Option<EndscreenWatchEndpoint>,
+    pub browse_endpoint: Option<EndscreenBrowseEndpoint>,
+    pub url_endpoint: Option<EndscreenUrlEndpoint>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EndscreenWatchEndpoint {
+    pub video_id: Option<String>,
+    pub playlist_id: Option<String>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EndscreenBrowseEndpoint {
+    pub browse_id: String,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EndscreenUrlEndpoint {
+    pub url: Url,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Storyboards {
+    // Livestreams have a `playerLiveStoryboardSpecRenderer` instead
+    pub player_storyboard_spec_renderer: Option<PlayerStoryboardSpecRenderer>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerStoryboardSpecRenderer {
+    pub spec: String,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Captions {
+    pub player_captions_tracklist_renderer: PlayerCaptionsTracklistRenderer,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerCaptionsTracklistRenderer {
+    #[serde(default)]
+    pub caption_tracks: Vec<CaptionTrack>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptionTrack {
+    pub base_url: Url,
+    pub name: super::Text,
+    pub language_code: String,
+    // `asr` for automatically generated captions
+    pub kind: Option<String>,
+    #[serde(default)]
+    pub is_translatable: bool,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PaidContentOverlay {
+    pub paid_content_overlay_renderer: Option<PaidContentOverlayRenderer>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PaidContentOverlayRenderer {}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveStreamability {
+    pub live_streamability_renderer: LiveStreamabilityRenderer,
+}
+
+impl LiveStreamability {
+    pub fn scheduled_start(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        let time = self
+            .live_streamability_renderer
+            .offline_slate
+            .as_ref()?
+            .live_stream_offline_slate_renderer
+            .scheduled_start_time?;
+        chrono::TimeZone::timestamp_opt(&chrono::Utc, time, 0).single()
+    }
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveStreamabilityRenderer {
+    pub offline_slate: Option<OfflineSlate>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OfflineSlate {
+    pub live_stream_offline_slate_renderer: LiveStreamOfflineSlateRenderer,
+}
+
+#[serde_with::serde_as]
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveStreamOfflineSlateRenderer {
+    #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+    #[serde(default)]
+    pub scheduled_start_time: Option<i64>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Microformat {
+    pub player_microformat_renderer: PlayerMicroformatRenderer,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerMicroformatRenderer {
+    pub embed: Option<crate::video::EmbedInfo>,
+    pub category: Option<String>,
+    pub publish_date: Option<String>,
+    pub upload_date: Option<String>,
+    pub is_family_safe: Option<bool>,
+    pub live_broadcast_details: Option<LiveBroadcastDetails>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveBroadcastDetails {
+    #[serde(default)]
+    pub is_live_now: bool,
+    pub start_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    pub end_timestamp: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 #[serde_with::serde_as]
@@ -38,8 +355,9 @@ pub struct PlayerResponse {
 pub struct VideoDetails {
     pub title: String,
     pub video_id: crate::video::Id,
-    #[serde_as(as = "serde_with::DurationSeconds<String>")]
-    pub length_seconds: Duration,
+    #[serde_as(as = "Option<serde_with::DurationSeconds<String>>")]
+    #[serde(default)]
+    pub length_seconds: Option<Duration>,
 
     #[serde(default)]
     pub keywords: Vec<String>,
@@ -51,6 +369,9 @@ pub struct VideoDetails {
     pub view_count: u64,
 
     pub is_live_content: bool,
+    #[serde(default)]
+    pub is_upcoming: bool,
+    pub allow_ratings: Option<bool>,
 
     pub thumbnail: Thumbnails,
 }
@@ -59,13 +380,73 @@ pub struct VideoDetails {
 #[serde(rename_all = "camelCase")]
 pub struct StreamPlayerResponse {
     pub streaming_data: StreamingData,
+    pub video_details: Option<StreamVideoDetails>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamVideoDetails {
+    pub video_id: crate::video::Id,
 }
 
+#[serde_with::serde_as]
 #[derive(Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct StreamingData {
-    #[serde(default)]
+    // Muxed formats, containing both video and audio
+    #[serde(default, deserialize_with = "deciphered_formats")]
+    pub formats: Vec<Format>,
+    #[serde(default, deserialize_with = "deciphered_formats")]
     pub adaptive_formats: Vec<Format>,
+    // Only present for livestreams, which have no adaptive formats
+    pub hls_manifest_url: Option<Url>,
+    // May contain formats that are missing from the adaptive formats
+    pub dash_manifest_url: Option<Url>,
+    #[serde_as(as = "Option<serde_with::DurationSeconds<String>>")]
+    #[serde(default)]
+    pub expires_in_seconds: Option<Duration>,
+}
+
+impl StreamingData {
+    /// Has the url of any format expired?
+    pub fn is_expired(&self) -> bool {
+        self.formats
+            .iter()
+            .chain(&self.adaptive_formats)
+            .filter_map(|x| x.base.expires_at())
+            .any(|expire| expire <= chrono::Utc::now())
+    }
+}
+
+/// Deserialize the formats that carry a plain `url`.
+///
+/// Formats of some videos, e.g. music, only carry a `signatureCipher`, which
+/// needs the cipher of the player JS to become a usable url. This library
+/// does not implement that, so these formats are skipped. Formats that fail
+/// to deserialize are skipped as well, so one new format does not make all
+/// streams unavailable.
+fn deciphered_formats<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Vec<Format>, D::Error> {
+    Ok(Vec::<serde_json::Value>::deserialize(deserializer)?
+        .into_iter()
+        .filter(|format| {
+            let ciphered = format.get("url").is_none() && format.get("signatureCipher").is_some();
+            if ciphered {
+                log::warn!(
+                    "Skipping format with itag {} protected by a signatureCipher",
+                    format["itag"]
+                );
+            }
+            !ciphered
+        })
+        .filter_map(|format| {
+            let itag = format["itag"].clone();
+            Format::deserialize(format)
+                .map_err(|err| log::warn!("Skipping format with itag {}: {}", itag, err))
+                .ok()
+        })
+        .collect())
 }
 
 #[derive(Deserialize, Clone)]
@@ -90,11 +471,42 @@ pub struct CommonFormat {
     #[serde_as(as = "Option<serde_with::DurationMilliSeconds<String>>")]
     #[serde(default, rename = "approxDurationMs")]
     pub duration: Option<Duration>,
+    #[serde(default, rename = "type")]
+    pub stream_type: Option<String>,
+    pub average_bitrate: Option<u64>,
+    // Missing for muxed and OTF formats
+    pub init_range: Option<ByteRange>,
+    pub index_range: Option<ByteRange>,
+}
+
+impl CommonFormat {
+    /// When the `url` expires, from its `expire` parameter
+    pub fn expires_at(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        let (_, expire) = self.url.query_pairs().find(|(key, _)| key == "expire")?;
+        chrono::DateTime::from_timestamp(expire.parse().ok()?, 0)
+    }
+}
+
+#[serde_with::serde_as]
+#[derive(Deserialize, Clone, Copy)]
+pub struct ByteRange {
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub start: u64,
+    // Inclusive
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub end: u64,
 }
 
 #[derive(Deserialize, Clone)]
 #[serde(untagged)]
 pub enum FormatType {
+    // Must come first, as it also matches the other variants
+    Muxed {
+        #[serde(flatten)]
+        video: VideoFormat,
+        #[serde(flatten)]
+        audio: AudioFormat,
+    },
     Audio(AudioFormat),
     Video(VideoFormat),
 }
@@ -106,6 +518,26 @@ pub struct VideoFormat {
     pub height: u64,
     pub fps: u64,
     pub quality_label: String,
+    // Only present for VR and 360° videos, e.g. `EQUIRECTANGULAR`
+    #[serde(default)]
+    pub projection_type: Option<String>,
+    // e.g. `STEREO_LAYOUT_TOP_BOTTOM`
+    #[serde(default)]
+    pub stereo_layout: Option<String>,
+    // Missing for some SDR formats
+    #[serde(default)]
+    pub color_info: Option<ColorInfo>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ColorInfo {
+    // e.g. `COLOR_PRIMARIES_BT2020`
+    pub primaries: Option<String>,
+    // e.g. `COLOR_TRANSFER_CHARACTERISTICS_SMPTEST2084`
+    pub transfer_characteristics: Option<String>,
+    // e.g. `COLOR_MATRIX_COEFFICIENTS_BT2020_NCL`
+    pub matrix_coefficients: Option<String>,
 }
 
 #[serde_with::serde_as]
@@ -117,10 +549,64 @@ pub struct AudioFormat {
     pub audio_sample_rate: u64,
     pub audio_quality: String,
     pub audio_channels: u64,
+    // Only present for videos with multiple, e.g. dubbed, audio tracks
+    #[serde(default)]
+    pub audio_track: Option<AudioTrack>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioTrack {
+    // `<LANGUAGE>.<NUMBER>`, e.g. `en.4` or `pt-BR.3`
+    pub id: String,
+    pub display_name: String,
+    #[serde(default)]
+    pub audio_is_default: bool,
 }
 
+/// Only the [`PlayabilityStatus`] of a player response, see
+/// [`Api::playability`](super::innertube::Api::playability).
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
+pub struct PlayabilityResponse {
+    pub playability_status: PlayabilityStatus,
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
 pub struct PlayabilityStatus {
+    pub status: Option<String>,
+    #[serde(default)]
     pub reason: String,
+    pub live_streamability: Option<LiveStreamability>,
+    #[serde(default)]
+    pub playable_in_embed: bool,
+    pub desktop_legacy_age_gate_reason: Option<u64>,
+}
+
+impl PlayabilityStatus {
+    pub fn is_age_gated(&self) -> bool {
+        self.desktop_legacy_age_gate_reason.is_some()
+            || (self.status.as_deref() == Some("LOGIN_REQUIRED") && self.reason.contains("age"))
+    }
+
+    pub fn is_playable(&self) -> bool {
+        self.status.as_deref() == Some("OK")
+    }
+
+    pub fn as_error(&self) -> crate::error::Youtube {
+        // Premieres and scheduled livestreams have no streams until they start
+        if self.status.as_deref() == Some("LIVE_STREAM_OFFLINE")
+            || self.live_streamability.is_some()
+        {
+            crate::error::Youtube::NotYetAvailable {
+                starts_at: self
+                    .live_streamability
+                    .as_ref()
+                    .and_then(LiveStreamability::scheduled_start),
+            }
+        } else {
+            crate::error::Youtube::Message(self.reason.clone())
+        }
+    }
 }
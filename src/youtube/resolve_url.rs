@@ -0,0 +1,29 @@
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Root {
+    // Missing if the url does not point to a page
+    pub endpoint: Option<Endpoint>,
+}
+
+impl Root {
+    /// The id of the browse page the url resolved to, e.g. `UC...` for
+    /// channels or `VL...` for playlists
+    pub fn browse_id(&self) -> Option<&str> {
+        Some(&self.endpoint.as_ref()?.browse_endpoint.as_ref()?.browse_id)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Endpoint {
+    // Missing for watch urls and other urls that are not browsed
+    pub browse_endpoint: Option<BrowseEndpoint>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrowseEndpoint {
+    pub browse_id: String,
+}
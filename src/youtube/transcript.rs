@@ -0,0 +1,102 @@
+use serde::Deserialize;
+
+use super::{
+    unknown::{self, impl_renderer},
+    Text,
+};
+
+impl_renderer! {
+    Segment => "transcript.initialSegments",
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Root {
+    #[serde(default)]
+    pub actions: Vec<Action>,
+}
+
+impl Root {
+    pub fn into_segments(self) -> Option<Vec<Segment>> {
+        self.actions.into_iter().find_map(|x| {
+            Some(
+                x.update_engagement_panel_action?
+                    .content
+                    .transcript_renderer
+                    .content
+                    .transcript_search_panel_renderer
+                    .body
+                    .transcript_segment_list_renderer
+                    .initial_segments,
+            )
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Action {
+    pub update_engagement_panel_action: Option<UpdateEngagementPanelAction>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateEngagementPanelAction {
+    pub content: UpdateEngagementPanelContent,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateEngagementPanelContent {
+    pub transcript_renderer: TranscriptRenderer,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptRenderer {
+    pub content: TranscriptContent,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptContent {
+    pub transcript_search_panel_renderer: TranscriptSearchPanelRenderer,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptSearchPanelRenderer {
+    pub body: TranscriptBody,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptBody {
+    pub transcript_segment_list_renderer: TranscriptSegmentListRenderer,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptSegmentListRenderer {
+    #[serde(default, deserialize_with = "unknown::vec")]
+    pub initial_segments: Vec<Segment>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Segment {
+    TranscriptSegmentRenderer(TranscriptSegmentRenderer),
+    // The chapter headers, these are taken from the chapters of the video instead
+    TranscriptSectionHeaderRenderer {},
+    #[serde(other)]
+    Other,
+}
+
+#[serde_with::serde_as]
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptSegmentRenderer {
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub start_ms: u64,
+    pub snippet: Text,
+}
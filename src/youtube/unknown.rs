@@ -0,0 +1,138 @@
+//! Reporting of renderers that are skipped because they are unknown.
+//!
+//! Enums with a `#[serde(other)]` fallback implement [`Renderer`] and are
+//! deserialized with [`vec`] or [`single`]. These deserialize through a
+//! intermediate [`Value`]: `#[serde(other)]` only matches unit variants, so a
+//! unknown renderer with content, e.g. `{"fooRenderer": {..}}`, is retried
+//! by its name alone. With a hook installed, the raw value of every unknown
+//! item is handed to the hook.
+//!
+//! Parts of a response that are only nice to have, e.g. the cards of a video,
+//! are deserialized with [`lenient`] or [`lenient_vec`] instead, which skip
+//! them with a warning if they are malformed.
+
+use std::{cell::RefCell, sync::Arc};
+
+use serde::{de::DeserializeOwned, Deserialize, Deserializer};
+use serde_json::Value;
+
+/// A callback receiving the context and raw value of a unknown renderer.
+pub type Hook = Arc<dyn Fn(&str, Value) + Send + Sync>;
+
+thread_local! {
+    static HOOK: RefCell<Option<Hook>> = const { RefCell::new(None) };
+}
+
+/// A enum that has a fallback variant for unknown renderers.
+pub trait Renderer: DeserializeOwned {
+    /// A description of where the renderer was found.
+    const CONTEXT: &'static str;
+
+    /// Is this the fallback variant?
+    fn is_unknown(&self) -> bool;
+}
+
+/// Run `f` with `hook` installed for the current thread.
+pub fn scope<R>(hook: Option<&Hook>, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<Hook>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            HOOK.with(|x| *x.borrow_mut() = self.0.take());
+        }
+    }
+
+    let _restore = Restore(HOOK.with(|x| x.replace(hook.cloned())));
+    f()
+}
+
+fn hook() -> Option<Hook> {
+    HOOK.with(|x| x.borrow().clone())
+}
+
+fn from_value<'de, D: Deserializer<'de>, T: Renderer>(
+    hook: Option<&Hook>,
+    value: Value,
+) -> Result<T, D::Error> {
+    let item: T = match serde_path_to_error::deserialize(&value) {
+        Ok(item) => item,
+        Err(err) => unit(&value).ok_or_else(|| {
+            serde::de::Error::custom(format!("{} at '{}'", err.inner(), err.path()))
+        })?,
+    };
+
+    if let Some(hook) = hook.filter(|_| item.is_unknown()) {
+        hook(T::CONTEXT, value);
+    }
+    Ok(item)
+}
+
+/// Deserialize the name of the only renderer in `value`, if it is unknown
+fn unit<T: Renderer>(value: &Value) -> Option<T> {
+    let object = value.as_object().filter(|x| x.len() == 1)?;
+    let (name, _) = object.iter().next()?;
+
+    T::deserialize(Value::String(name.clone()))
+        .ok()
+        .filter(T::is_unknown)
+}
+
+/// Deserialize a list of renderers, reporting unknown ones.
+pub fn vec<'de, D: Deserializer<'de>, T: Renderer>(deserializer: D) -> Result<Vec<T>, D::Error> {
+    let hook = hook();
+    Vec::<Value>::deserialize(deserializer)?
+        .into_iter()
+        .map(|value| from_value::<D, T>(hook.as_ref(), value))
+        .collect()
+}
+
+/// Deserialize a single renderer, reporting it if it is unknown.
+pub fn single<'de, D: Deserializer<'de>, T: Renderer>(deserializer: D) -> Result<T, D::Error> {
+    from_value::<D, T>(hook().as_ref(), Value::deserialize(deserializer)?)
+}
+
+fn lenient_value<T: DeserializeOwned>(value: Value) -> Option<T> {
+    serde_path_to_error::deserialize(&value)
+        .map_err(|err| {
+            log::warn!(
+                "Skipping malformed {}: {} at '{}'",
+                std::any::type_name::<T>(),
+                err.inner(),
+                err.path()
+            )
+        })
+        .ok()
+}
+
+/// Deserialize a optional value, skipping it if it is malformed.
+pub fn lenient<'de, D: Deserializer<'de>, T: DeserializeOwned>(
+    deserializer: D,
+) -> Result<Option<T>, D::Error> {
+    Ok(Option::<Value>::deserialize(deserializer)?.and_then(lenient_value))
+}
+
+/// Deserialize a list, skipping the items that are malformed.
+pub fn lenient_vec<'de, D: Deserializer<'de>, T: DeserializeOwned>(
+    deserializer: D,
+) -> Result<Vec<T>, D::Error> {
+    Ok(Vec::<Value>::deserialize(deserializer)?
+        .into_iter()
+        .filter_map(lenient_value)
+        .collect())
+}
+
+macro_rules! impl_renderer {
+    ($($ty:ty => $context:literal),* $(,)?) => {
+        $(
+            impl $crate::youtube::unknown::Renderer for $ty {
+                const CONTEXT: &'static str = $context;
+
+                fn is_unknown(&self) -> bool {
+                    matches!(self, Self::Other)
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use impl_renderer;
@@ -1,5 +1,8 @@
 use ytextract::Client;
 
+#[cfg(feature = "unstable")]
+mod common;
+
 #[tokio::test]
 async fn get() -> Result<(), Box<dyn std::error::Error>> {
     let id = "UCdktGrgQlqxPsvHo6cHF0Ng".parse()?;
@@ -11,7 +14,7 @@ async fn get() -> Result<(), Box<dyn std::error::Error>> {
     assert_eq!(channel.country(), Some("United States"));
     assert!(channel.avatar().next().is_some());
     assert!(channel.banner().next().is_some());
-    assert!(channel.views() >= 90_969_900);
+    assert!(channel.views().expect("views") >= 90_969_900);
     assert!(channel.subscribers() >= Some(146_000));
     assert!(channel.uploads().await.is_ok());
     assert!(channel.badges().next().is_some());
@@ -30,6 +33,44 @@ async fn eq() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[tokio::test]
+async fn refresh() -> Result<(), Box<dyn std::error::Error>> {
+    let id = "UCdktGrgQlqxPsvHo6cHF0Ng".parse()?;
+    let mut channel = Client::new().channel(id).await?;
+
+    let previous = channel.refresh().await?;
+
+    assert_eq!(previous, channel);
+    assert!(channel.views() >= previous.views());
+    assert!(channel.fetched_at() >= previous.fetched_at());
+    assert!(channel.published().is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn videos() -> Result<(), Box<dyn std::error::Error>> {
+    use futures::StreamExt;
+
+    let id = "UCdktGrgQlqxPsvHo6cHF0Ng".parse()?;
+    let channel = Client::new().channel(id).await?;
+
+    let videos: Vec<_> = channel.videos().take(40).collect().await;
+    let videos = videos.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+    // The first page holds 30 videos
+    assert_eq!(videos.len(), 40);
+    for video in &videos {
+        assert!(!video.title().is_empty());
+        assert!(!video.thumbnails().is_empty());
+        assert!(video.published_time_text().is_some());
+        assert!(video.length().is_some());
+        assert!(video.views().is_some());
+    }
+
+    Ok(())
+}
+
 mod metadata {
     use ytextract::Client;
 
@@ -130,3 +171,274 @@ mod subscribers {
 
     define_test!(million, "UC7tD6Ifrwbiy-BoaAHEinmQ", 1_000_000);
 }
+
+#[tokio::test]
+async fn header() -> Result<(), Box<dyn std::error::Error>> {
+    let id = "UCdktGrgQlqxPsvHo6cHF0Ng".parse()?;
+    let client = Client::new();
+    let header = client.channel_header(id).await?;
+    let channel = client.channel(id).await?;
+
+    assert_eq!(header.id(), id);
+    assert_eq!(header.name(), channel.name());
+    assert_eq!(header.subscribers(), channel.subscribers());
+    assert!(header.avatar().next().is_some());
+    assert!(header.banner().next().is_some());
+    assert_eq!(
+        header.badges().collect::<Vec<_>>(),
+        channel.badges().collect::<Vec<_>>()
+    );
+    assert_eq!(header.upgrade().await?, channel);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn with_uploads() -> Result<(), Box<dyn std::error::Error>> {
+    let id = "UCdktGrgQlqxPsvHo6cHF0Ng".parse()?;
+    let (channel, uploads) = Client::new().channel_with_uploads(id).await?;
+
+    assert_eq!(channel.id(), id);
+    assert_eq!(uploads.id(), id.uploads());
+    assert_eq!(uploads.channel().expect("channel").id(), id);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn bulk() -> Result<(), Box<dyn std::error::Error>> {
+    let id: ytextract::channel::Id = "UCZqdX9k5eyv1aO7i2746bXg".parse()?;
+    let other: ytextract::channel::Id = "UCdktGrgQlqxPsvHo6cHF0Ng".parse()?;
+
+    let channels = Client::new().channels_bulk([id, id, other, id], 2).await;
+
+    assert_eq!(channels.len(), 2);
+    assert_eq!(channels[&id].as_ref().expect("Channel failed").id(), id);
+    assert_eq!(
+        channels[&other].as_ref().expect("Channel failed").id(),
+        other
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn bulk_duplicates() -> Result<(), Box<dyn std::error::Error>> {
+    let id: ytextract::channel::Id = "UCZqdX9k5eyv1aO7i2746bXg".parse()?;
+
+    // Each id has a single entry, whether or not requesting it succeeded
+    let channels = Client::new().channels_bulk([id, id, id], 0).await;
+
+    assert_eq!(channels.len(), 1);
+    assert!(channels.contains_key(&id));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn resolve() -> Result<(), Box<dyn std::error::Error>> {
+    let id: ytextract::channel::Id = "UCXuqSBlHAE6Xw-yeJA0Tunw".parse()?;
+    let client = Client::new();
+
+    for value in [
+        "@LinusTechTips",
+        "https://www.youtube.com/@LinusTechTips",
+        "youtube.com/c/LinusTechTips",
+        "https://www.youtube.com/user/LinusTechTips",
+        "https://www.youtube.com/channel/UCXuqSBlHAE6Xw-yeJA0Tunw",
+    ] {
+        assert_eq!(client.resolve_channel(value).await?, id, "{}", value);
+    }
+
+    assert_eq!(client.channel_by_handle("@LinusTechTips").await?.id(), id);
+
+    assert!(matches!(
+        client
+            .resolve_channel("@this-handle-does-not-exist-1234567890")
+            .await,
+        Err(ytextract::Error::Youtube(
+            ytextract::error::Youtube::NotFound(_)
+        ))
+    ));
+
+    Ok(())
+}
+
+#[cfg(feature = "unstable")]
+mod parts {
+    use serde_json::json;
+    use ytextract::{channel::Badge, Channel, Client};
+
+    #[cfg(feature = "serde")]
+    use crate::common::round_trip;
+    use crate::common::{serve_api, CHANNEL};
+
+    #[test]
+    fn get() -> Result<(), Box<dyn std::error::Error>> {
+        let mut response = json!({
+            "header": {
+                "c4TabbedHeaderRenderer": {
+                    "title": "Linus Tech Tips",
+                    "channelId": CHANNEL,
+                    "avatar": {
+                        "thumbnails": [
+                            { "url": "https://yt3.ggpht.com/avatar=s48-c-k", "width": 48, "height": 48 },
+                            { "url": "https://yt3.ggpht.com/avatar=s88-c-k", "width": 88, "height": 88 }
+                        ]
+                    },
+                    "subscriberCountText": { "simpleText": "13.8M subscribers" },
+                    "channelHandleText": { "runs": [{ "text": "@LinusTechTips" }] },
+                    "badges": [
+                        { "metadataBadgeRenderer": { "style": "BADGE_STYLE_TYPE_VERIFIED" } },
+                        { "metadataBadgeRenderer": { "style": "BADGE_STYLE_TYPE_MADE_UP" } }
+                    ]
+                }
+            },
+            "metadata": {
+                "channelMetadataRenderer": {
+                    "isFamilySafe": true,
+                    "vanityChannelUrl": "http://www.youtube.com/@LinusTechTips"
+                }
+            },
+            "contents": {
+                "twoColumnBrowseResultsRenderer": {
+                    "tabs": [{}, {
+                        "tabRenderer": {
+                            "content": {
+                                "sectionListRenderer": {
+                                    "contents": [{
+                                        "itemSectionRenderer": {
+                                            "contents": [{
+                                                "channelAboutFullMetadataRenderer": {
+                                                    "description": { "simpleText": "Tech" },
+                                                    "avatar": {
+                                                        "thumbnails": [
+                                                            { "url": "https://yt3.ggpht.com/avatar=s176-c-k", "width": 176, "height": 176 },
+                                                            { "url": "https://yt3.ggpht.com/avatar=s48-c-k", "width": 48, "height": 48 }
+                                                        ]
+                                                    },
+                                                    "viewCountText": { "simpleText": "1,234 views" },
+                                                    "country": { "simpleText": "Canada" },
+                                                    "joinedDateText": {
+                                                        "runs": [{ "text": "Joined " }, { "text": "Nov 24, 2008" }]
+                                                    }
+                                                }
+                                            }]
+                                        }
+                                    }]
+                                }
+                            }
+                        }
+                    }]
+                }
+            }
+        });
+        let channel = |response: &serde_json::Value| {
+            Channel::from_parts(&response.to_string(), Client::new())
+        };
+
+        let full = channel(&response)?;
+        assert_eq!(full.id(), CHANNEL.parse()?);
+        assert_eq!(full.name(), "Linus Tech Tips");
+        assert_eq!(full.description(), "Tech");
+        assert_eq!(full.country(), Some("Canada"));
+        assert_eq!(full.views(), Some(1234));
+        assert_eq!(full.subscribers(), Some(13_800_000));
+        assert_eq!(full.handle(), Some("@LinusTechTips"));
+        // Every size is kept, only exact duplicates are removed
+        assert_eq!(
+            full.avatar().map(|x| x.width).collect::<Vec<_>>(),
+            [48, 88, 176]
+        );
+        assert_eq!(
+            full.badges().collect::<Vec<_>>(),
+            [
+                Badge::Verified,
+                Badge::Unknown("BADGE_STYLE_TYPE_MADE_UP".to_string())
+            ]
+        );
+        assert_eq!(
+            full.vanity_url(),
+            Some("http://www.youtube.com/@LinusTechTips")
+        );
+
+        assert_eq!(
+            full.published(),
+            chrono::NaiveDate::from_ymd_opt(2008, 11, 24)
+        );
+        assert!(full.fetched_at() <= chrono::Utc::now());
+
+        #[cfg(feature = "serde")]
+        {
+            let snapshot = full.snapshot();
+            assert_eq!(snapshot.country.as_deref(), Some("Canada"));
+            assert_eq!(snapshot.published, full.published());
+            assert_eq!(snapshot.fetched_at, full.fetched_at());
+            round_trip(snapshot)?;
+        }
+
+        // Without a handle text, the handle is taken from the vanity url
+        let header = &mut response["header"]["c4TabbedHeaderRenderer"];
+        header.as_object_mut().unwrap().remove("channelHandleText");
+        assert_eq!(channel(&response)?.handle(), Some("@LinusTechTips"));
+
+        response["metadata"]["channelMetadataRenderer"]["vanityChannelUrl"] =
+            json!("http://www.youtube.com/c/LinusTechTips");
+        assert_eq!(channel(&response)?.handle(), None);
+
+        let header = &mut response["header"]["c4TabbedHeaderRenderer"];
+        header["subscriberCountText"] = json!({ "simpleText": "1 subscriber" });
+        assert_eq!(channel(&response)?.subscribers(), Some(1));
+
+        let header = &mut response["header"]["c4TabbedHeaderRenderer"];
+        header["subscriberCountText"] = json!({ "simpleText": "subscribers" });
+        assert_eq!(channel(&response)?.subscribers(), None);
+
+        // Channels hiding their subscriber count have no text at all
+        let header = &mut response["header"]["c4TabbedHeaderRenderer"];
+        header
+            .as_object_mut()
+            .unwrap()
+            .remove("subscriberCountText");
+        assert_eq!(channel(&response)?.subscribers(), None);
+
+        // Channels hiding their stats have no view count
+        let about = "/contents/twoColumnBrowseResultsRenderer/tabs/1/tabRenderer/content\
+            /sectionListRenderer/contents/0/itemSectionRenderer/contents/0\
+            /channelAboutFullMetadataRenderer";
+        response
+            .pointer_mut(about)
+            .and_then(|x| x.as_object_mut())
+            .unwrap()
+            .remove("viewCountText");
+        let hidden = channel(&response)?;
+        assert_eq!(hidden.views(), None);
+        #[cfg(feature = "serde")]
+        assert_eq!(hidden.snapshot().views, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn header_request() -> Result<(), Box<dyn std::error::Error>> {
+        let (url, requests) = serve_api(|_, _| json!({}));
+        let client = Client::builder().api_url(url).build();
+
+        // Only the requests matter here, not the empty responses
+        let _ = client.channel_header(CHANNEL.parse()?).await;
+        let _ = client.channel(CHANNEL.parse()?).await;
+
+        let requests: Vec<_> = requests.try_iter().collect();
+        assert_eq!(requests.len(), 2);
+        assert!(requests
+            .iter()
+            .all(|(endpoint, body)| { endpoint == "browse" && body["browseId"] == CHANNEL }));
+
+        // The header is part of every page, so the home page without the about
+        // params is requested
+        assert!(requests[0].1.get("params").is_none());
+        assert_eq!(requests[1].1["params"], "EgVhYm91dA");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,386 @@
+//! Responses and local servers for the tests that build videos, playlists and
+//! channels from responses fetched elsewhere, see the `unstable` feature.
+
+// Every test binary only uses some of these
+#![allow(dead_code)]
+
+use serde_json::json;
+use ytextract::{video::Parts, Client, Video};
+
+pub const VIDEO: &str = "7B2PIVSWtJA";
+pub const CHANNEL: &str = "UCXuqSBlHAE6Xw-yeJA0Tunw";
+pub const PLAYLIST: &str = "PLCSusC_jlo14BH5hHnOh9b0O18HtGT3eP";
+
+pub fn player_response() -> serde_json::Value {
+    json!({
+        "playabilityStatus": { "status": "OK", "playableInEmbed": true },
+        "videoDetails": {
+            "videoId": VIDEO,
+            "title": "I Sent Corridor Digital the WORST VFX Workstation",
+            "lengthSeconds": "1358",
+            "keywords": ["vfx"],
+            "channelId": CHANNEL,
+            "shortDescription": "A description",
+            "viewCount": "1068917",
+            "author": "Linus Tech Tips",
+            "isLiveContent": false,
+            "allowRatings": true,
+            "thumbnail": { "thumbnails": [] }
+        },
+        "microformat": {
+            "playerMicroformatRenderer": {
+                "category": "Science & Technology",
+                "publishDate": "2021-06-22",
+                "uploadDate": "2021-06-22",
+                "isFamilySafe": true
+            }
+        }
+    })
+}
+
+pub fn next_response(id: &str) -> serde_json::Value {
+    json!({ "currentVideoEndpoint": { "watchEndpoint": { "videoId": id } } })
+}
+
+/// The [`next_response`] of [`VIDEO`] with the `contents` of the watch page of
+/// the WEB client.
+pub fn watch_next(contents: serde_json::Value) -> serde_json::Value {
+    let mut next = next_response(VIDEO);
+    next["contents"] = json!({
+        "twoColumnWatchNextResults": { "results": { "results": { "contents": contents } } }
+    });
+    next
+}
+
+pub fn parts(player: serde_json::Value, next: serde_json::Value) -> Parts {
+    Parts {
+        player_response: player.to_string(),
+        next_response: next.to_string(),
+    }
+}
+
+/// A [`Video`] built from `player` and `next` with a default [`Client`].
+pub fn video(player: serde_json::Value, next: serde_json::Value) -> ytextract::Result<Video> {
+    Video::from_parts(parts(player, next), Client::new())
+}
+
+/// The player response of [`VIDEO`] with a video and a audio stream that
+/// expire in 2100.
+pub fn streams_response() -> serde_json::Value {
+    let mut response = player_response();
+    response["streamingData"] = json!({
+        "expiresInSeconds": "21540",
+        "adaptiveFormats": [
+            {
+                "itag": 137,
+                "url": "https://rr1---sn-abcdefgh.googlevideo.com/videoplayback?expire=4102444800&ei=AAAA&ip=0.0.0.0&id=o-AAAA&itag=137&source=youtube&requiressl=yes&mime=video%2Fmp4&gir=yes&clen=123456&dur=1358.000&c=WEB&sig=AAAA",
+                "mimeType": "video/mp4; codecs=\"avc1.640028\"",
+                "bitrate": 4000000,
+                "width": 1920,
+                "height": 1080,
+                "fps": 30,
+                "qualityLabel": "1080p",
+                "contentLength": "123456",
+                "approxDurationMs": "1358000",
+                "averageBitrate": 2500000,
+                "initRange": { "start": "0", "end": "740" },
+                "indexRange": { "start": "741", "end": "3992" }
+            },
+            {
+                "itag": 251,
+                "url": "https://rr1---sn-abcdefgh.googlevideo.com/videoplayback?expire=4102444800&itag=251&source=youtube&mime=audio%2Fwebm&clen=654321&ratebypass=yes&c=ANDROID&sig=AAAA",
+                "mimeType": "audio/webm; codecs=\"opus\"",
+                "bitrate": 160000,
+                "contentLength": "111111",
+                "audioQuality": "AUDIO_QUALITY_MEDIUM",
+                "audioSampleRate": "48000",
+                "audioChannels": 2,
+                "loudnessDb": -2.5
+            }
+        ]
+    });
+    response
+}
+
+/// [`streams_response`] with urls that expire in a second, as the streaming
+/// data of a [`Video`] is only reused while it is valid.
+pub fn streams_response_expiring() -> (serde_json::Value, i64) {
+    let expire = chrono::Utc::now().timestamp() + 1;
+    let response = streams_response()
+        .to_string()
+        .replace("expire=4102444800", &format!("expire={}", expire));
+    (serde_json::from_str(&response).unwrap(), expire)
+}
+
+pub fn playlist_response() -> serde_json::Value {
+    json!({
+        "contents": {
+            "twoColumnBrowseResultsRenderer": {
+                "tabs": [{
+                    "tabRenderer": {
+                        "content": {
+                            "sectionListRenderer": {
+                                "contents": [{
+                                    "itemSectionRenderer": {
+                                        "contents": [{
+                                            "playlistVideoListRenderer": { "contents": [] }
+                                        }]
+                                    }
+                                }]
+                            }
+                        }
+                    }
+                }]
+            }
+        },
+        "microformat": {
+            "microformatDataRenderer": {
+                "urlCanonical": format!("http://www.youtube.com/playlist?list={}", PLAYLIST),
+                "title": "ytextract - test",
+                "description": "A",
+                "thumbnail": { "thumbnails": [] },
+                "unlisted": true
+            }
+        },
+        "sidebar": {
+            "playlistSidebarRenderer": {
+                "items": [{
+                    "playlistSidebarPrimaryInfoRenderer": {
+                        "stats": [
+                            { "runs": [{ "text": "122" }, { "text": " videos" }] },
+                            { "simpleText": "4 views" },
+                            { "runs": [{ "text": "Last updated on " }, { "text": "Jun 22, 2021" }] }
+                        ]
+                    }
+                }]
+            }
+        }
+    })
+}
+
+/// [`playlist_response`] with the renderers in `videos` as its first page.
+pub fn playlist_with_videos(videos: serde_json::Value) -> serde_json::Value {
+    let mut response = playlist_response();
+    response["contents"]["twoColumnBrowseResultsRenderer"]["tabs"][0]["tabRenderer"]["content"]
+        ["sectionListRenderer"]["contents"][0]["itemSectionRenderer"]["contents"][0]
+        ["playlistVideoListRenderer"]["contents"] = videos;
+    response
+}
+
+/// The `playlistVideoRenderer` of a video by [`CHANNEL`] at `index`.
+pub fn playlist_video(id: &str, index: u64) -> serde_json::Value {
+    json!({
+        "videoId": id,
+        "index": { "simpleText": index.to_string() },
+        "thumbnail": { "thumbnails": [] },
+        "title": { "runs": [{ "text": "A video" }] },
+        "shortBylineText": {
+            "runs": [{
+                "text": "Linus Tech Tips",
+                "navigationEndpoint": { "browseEndpoint": { "browseId": CHANNEL } }
+            }]
+        },
+        "lengthSeconds": "1358"
+    })
+}
+
+/// The `playlistSidebarPrimaryInfoRenderer` of a [`playlist_response`].
+pub fn playlist_primary_info(response: &mut serde_json::Value) -> &mut serde_json::Value {
+    &mut response["sidebar"]["playlistSidebarRenderer"]["items"][0]
+        ["playlistSidebarPrimaryInfoRenderer"]
+}
+
+pub fn round_trip<T>(value: T) -> Result<(), Box<dyn std::error::Error>>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned + PartialEq + std::fmt::Debug,
+{
+    let json = serde_json::to_string(&value)?;
+    assert_eq!(serde_json::from_str::<T>(&json)?, value);
+    Ok(())
+}
+
+/// Serve YouTube's internal API on a local port, answering every request with
+/// `respond(endpoint, body)`. The endpoints and bodies of the requests are
+/// sent to the returned receiver.
+pub fn serve_api(
+    respond: impl Fn(&str, &serde_json::Value) -> serde_json::Value + Send + Sync + 'static,
+) -> (
+    reqwest::Url,
+    std::sync::mpsc::Receiver<(String, serde_json::Value)>,
+) {
+    use std::io::{BufRead, Read, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let respond = std::sync::Arc::new(respond);
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let sender = sender.clone();
+            let respond = respond.clone();
+
+            std::thread::spawn(move || {
+                let mut reader = std::io::BufReader::new(stream.try_clone().unwrap());
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let endpoint = line
+                    .split(' ')
+                    .nth(1)
+                    .and_then(|x| x.split('?').next())
+                    .and_then(|x| x.rsplit('/').next())
+                    .unwrap_or_default()
+                    .to_string();
+
+                let mut length = 0;
+                loop {
+                    line.clear();
+                    if reader.read_line(&mut line).unwrap() <= 2 {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            length = value.trim().parse().unwrap();
+                        }
+                    }
+                }
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+                let response = respond(&endpoint, &body).to_string();
+                let _ = sender.send((endpoint, body));
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    response.len(),
+                    response
+                );
+            });
+        }
+    });
+
+    (url.parse().unwrap(), receiver)
+}
+
+/// Serve `body` on localhost, honoring the `range` query parameter.
+///
+/// If `short`, the first response is cut short, like a connection that ended
+/// early. The request line and headers of every request are sent to the
+/// receiver.
+pub fn serve_stream(body: &'static [u8], short: bool) -> (u16, std::sync::mpsc::Receiver<String>) {
+    use std::io::{BufRead, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let (sender, receiver) = std::sync::mpsc::channel();
+
+    std::thread::spawn(move || {
+        for (i, stream) in listener.incoming().enumerate() {
+            let mut stream = stream.unwrap();
+            let mut reader = std::io::BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            // Until the empty line ending the headers
+            while reader.read_line(&mut request).unwrap() > 2 {}
+
+            let (start, end) = request
+                .split(['?', '&', ' '])
+                .find_map(|x| x.strip_prefix("range="))
+                .and_then(|x| x.split_once('-'))
+                .map_or((0, body.len() - 1), |(start, end)| {
+                    (start.parse().unwrap(), end.parse().unwrap())
+                });
+            let mut chunk = &body[start..=end];
+            if short && i == 0 {
+                chunk = &chunk[..chunk.len() / 2];
+            }
+
+            let _ = sender.send(request);
+            let _ = write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                chunk.len()
+            );
+            let _ = stream.write_all(chunk);
+        }
+    });
+
+    (port, receiver)
+}
+
+/// Serve all of `body` on a local port to every request, ignoring the `range`
+/// query parameter.
+pub fn serve_unranged_stream(body: &'static [u8]) -> u16 {
+    use std::io::{BufRead, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = std::io::BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            while reader.read_line(&mut request).unwrap() > 2 {}
+
+            let _ = write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                body.len()
+            );
+            let _ = stream.write_all(body);
+        }
+    });
+
+    port
+}
+
+/// Answer every request on a local port with the raw HTTP `response`.
+pub fn serve_response(response: &'static str) -> u16 {
+    use std::io::{BufRead, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = std::io::BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            while reader.read_line(&mut request).unwrap() > 2 {}
+
+            let _ = stream.write_all(response.as_bytes());
+        }
+    });
+
+    port
+}
+
+/// Serve the first half of `body` on a local port, then drop the connection
+/// and stop listening.
+pub fn serve_broken_stream(body: &'static [u8]) -> u16 {
+    use std::io::{BufRead, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = std::io::BufReader::new(stream.try_clone().unwrap());
+        let mut request = String::new();
+        while reader.read_line(&mut request).unwrap() > 2 {}
+
+        let _ = write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            body.len()
+        );
+        let _ = stream.write_all(&body[..body.len() / 2]);
+        // hyper drops received bytes if the connection breaks before they
+        // were read
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    });
+
+    port
+}
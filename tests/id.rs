@@ -0,0 +1,193 @@
+use assert_matches::assert_matches;
+use ytextract::error::{Id, IdKind};
+
+const VIDEO: &str = "7B2PIVSWtJA";
+const CHANNEL: &str = "UCXuqSBlHAE6Xw-yeJA0Tunw";
+const PLAYLIST: &str = "PLCSusC_jlo14BH5hHnOh9b0O18HtGT3eP";
+const UPLOADS: &str = "UUXuqSBlHAE6Xw-yeJA0Tunw";
+
+#[test]
+fn correct_kind() {
+    assert!(VIDEO.parse::<ytextract::video::Id>().is_ok());
+    assert!(CHANNEL.parse::<ytextract::channel::Id>().is_ok());
+    assert!(PLAYLIST.parse::<ytextract::playlist::Id>().is_ok());
+    assert!(UPLOADS.parse::<ytextract::playlist::Id>().is_ok());
+}
+
+mod video {
+    use super::*;
+
+    #[test]
+    fn shorts_url() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(
+            format!("https://www.youtube.com/shorts/{}", VIDEO).parse::<ytextract::video::Id>()?,
+            VIDEO.parse::<ytextract::video::Id>()?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn channel() {
+        assert_matches!(
+            CHANNEL.parse::<ytextract::video::Id>(),
+            Err(Id::WrongKind {
+                expected: IdKind::Video,
+                found: IdKind::Channel
+            })
+        );
+        assert_matches!(
+            format!("https://www.youtube.com/channel/{}", CHANNEL).parse::<ytextract::video::Id>(),
+            Err(Id::WrongKind {
+                expected: IdKind::Video,
+                found: IdKind::Channel
+            })
+        );
+    }
+
+    #[test]
+    fn playlist() {
+        for id in [PLAYLIST, UPLOADS, "RDdQw4w9WgXcQ"] {
+            assert_matches!(
+                id.parse::<ytextract::video::Id>(),
+                Err(Id::WrongKind {
+                    expected: IdKind::Video,
+                    found: IdKind::Playlist
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn playlist_prefix() {
+        // Video ids can start with a playlist prefix by chance
+        for id in ["PLxxxxxxxxx", "RDdQw4w9WgX", "WLxxxxxxxxx"] {
+            assert!(id.parse::<ytextract::video::Id>().is_ok(), "{}", id);
+        }
+    }
+
+    #[test]
+    fn unknown_shape() {
+        assert_matches!(
+            "abc".parse::<ytextract::video::Id>(),
+            Err(Id::InvalidLength(3))
+        );
+    }
+}
+
+mod channel {
+    use super::*;
+
+    #[test]
+    fn video() {
+        assert_matches!(
+            VIDEO.parse::<ytextract::channel::Id>(),
+            Err(Id::WrongKind {
+                expected: IdKind::Channel,
+                found: IdKind::Video
+            })
+        );
+    }
+
+    #[test]
+    fn playlist() {
+        for id in [PLAYLIST, UPLOADS] {
+            assert_matches!(
+                id.parse::<ytextract::channel::Id>(),
+                Err(Id::WrongKind {
+                    expected: IdKind::Channel,
+                    found: IdKind::Playlist
+                })
+            );
+        }
+    }
+}
+
+mod playlist {
+    use super::*;
+
+    #[test]
+    fn video() {
+        assert_matches!(
+            VIDEO.parse::<ytextract::playlist::Id>(),
+            Err(Id::WrongKind {
+                expected: IdKind::Playlist,
+                found: IdKind::Video
+            })
+        );
+    }
+
+    #[test]
+    fn video_length() {
+        // Prefixed ids of the length of a video id are still playlists
+        assert_matches!(
+            "PLxxxxxxxxx".parse::<ytextract::playlist::Id>(),
+            Err(Id::InvalidLength(11))
+        );
+        assert!("ULxxxxxxxxx".parse::<ytextract::playlist::Id>().is_ok());
+    }
+
+    #[test]
+    fn channel() {
+        assert_matches!(
+            CHANNEL.parse::<ytextract::playlist::Id>(),
+            Err(Id::WrongKind {
+                expected: IdKind::Playlist,
+                found: IdKind::Channel
+            })
+        );
+    }
+
+    #[test]
+    fn unknown_shape() {
+        assert_matches!(
+            "XXCSusC_jlo14BH5hHnOh9b0O18HtGT3eP".parse::<ytextract::playlist::Id>(),
+            Err(Id::InvalidId(_))
+        );
+    }
+
+    #[test]
+    fn urls() -> Result<(), Box<dyn std::error::Error>> {
+        let expected: ytextract::playlist::Id = PLAYLIST.parse()?;
+        for url in [
+            format!("https://www.youtube.com/playlist?list={}", PLAYLIST),
+            format!("https://music.youtube.com/playlist?list={}", PLAYLIST),
+            format!("youtube.com/watch?v={}&list={}", VIDEO, PLAYLIST),
+            format!(
+                "https://www.youtube.com/watch?v={}&list={}&index=2",
+                VIDEO, PLAYLIST
+            ),
+        ] {
+            assert_eq!(url.parse::<ytextract::playlist::Id>()?, expected, "{}", url);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_length() {
+        assert_matches!(
+            "PLCSusC_jlo14BH5hHnOh9b0O18HtGT3".parse::<ytextract::playlist::Id>(),
+            Err(Id::InvalidLength(32))
+        );
+        assert_matches!(
+            "UUXuqSBlHAE6Xw-yeJA0Tun".parse::<ytextract::playlist::Id>(),
+            Err(Id::InvalidLength(23))
+        );
+        assert!("PL601B2E69B03FAB9D"
+            .parse::<ytextract::playlist::Id>()
+            .is_ok());
+        assert!("WL".parse::<ytextract::playlist::Id>().is_ok());
+    }
+
+    #[test]
+    fn serde() -> Result<(), Box<dyn std::error::Error>> {
+        let id: ytextract::playlist::Id = PLAYLIST.parse()?;
+        let json = serde_json::to_string(&id)?;
+        assert_eq!(json, format!("\"{}\"", PLAYLIST));
+        assert_eq!(serde_json::from_str::<ytextract::playlist::Id>(&json)?, id);
+        assert!(serde_json::from_str::<ytextract::playlist::Id>("\"PLxxx\"").is_err());
+
+        let ids: std::collections::HashSet<_> = vec![id.clone(), id].into_iter().collect();
+        assert_eq!(ids.len(), 1);
+        Ok(())
+    }
+}
@@ -0,0 +1,329 @@
+use std::time::Duration;
+
+use chrono::{NaiveDate, TimeZone, Utc};
+use ytextract::parse;
+
+mod count {
+    use super::parse;
+
+    #[test]
+    fn plain() {
+        assert_eq!(parse::count("0"), Some(0));
+        assert_eq!(parse::count("1234"), Some(1234));
+        assert_eq!(parse::count("1,234,567"), Some(1_234_567));
+    }
+
+    #[test]
+    fn abbreviated() {
+        assert_eq!(parse::count("1K"), Some(1_000));
+        assert_eq!(parse::count("1.2K"), Some(1_200));
+        assert_eq!(parse::count("999K"), Some(999_000));
+        assert_eq!(parse::count("3M"), Some(3_000_000));
+        assert_eq!(parse::count("1.2M"), Some(1_200_000));
+        assert_eq!(parse::count("13.8M"), Some(13_800_000));
+        assert_eq!(parse::count("1B"), Some(1_000_000_000));
+        assert_eq!(parse::count("1.23B"), Some(1_230_000_000));
+    }
+
+    #[test]
+    fn lowercase() {
+        assert_eq!(parse::count("1.2k"), Some(1_200));
+        assert_eq!(parse::count("3m"), Some(3_000_000));
+        assert_eq!(parse::count("1b"), Some(1_000_000_000));
+    }
+
+    #[test]
+    fn decimal_comma() {
+        assert_eq!(parse::count("1,2K"), Some(1_200));
+        assert_eq!(parse::count("1,23B"), Some(1_230_000_000));
+    }
+
+    #[test]
+    fn trailing_words() {
+        assert_eq!(parse::count("1.2M subscribers"), Some(1_200_000));
+        assert_eq!(parse::count("4,457 views"), Some(4457));
+        assert_eq!(parse::count("  12 Comments"), Some(12));
+        assert_eq!(parse::count("1,234 waiting"), Some(1234));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(parse::count(""), None);
+        assert_eq!(parse::count("   "), None);
+        assert_eq!(parse::count("No views"), None);
+        assert_eq!(parse::count("K"), None);
+        assert_eq!(parse::count("1.2X"), None);
+        assert_eq!(parse::count("-1"), None);
+        assert_eq!(parse::count("+1"), None);
+        assert_eq!(parse::count("1,2 Mio."), None);
+        assert_eq!(parse::count("1,2,3"), None);
+        assert_eq!(parse::count("1.2.3M"), None);
+        assert_eq!(parse::count("1,2.3M"), None);
+        assert_eq!(parse::count("infM"), None);
+        assert_eq!(parse::count("1e3K"), None);
+    }
+
+    #[test]
+    fn boundaries() {
+        assert_eq!(parse::count("999"), Some(999));
+        assert_eq!(parse::count("999,999"), Some(999_999));
+        assert_eq!(parse::count("1,000"), Some(1_000));
+    }
+}
+
+mod digits {
+    use super::parse;
+
+    #[test]
+    fn english() {
+        assert_eq!(parse::digits("4,457 likes"), Some(4457));
+        assert_eq!(
+            parse::digits("like this video along with 1,234,567 other people"),
+            Some(1_234_567)
+        );
+    }
+
+    #[test]
+    fn german() {
+        assert_eq!(parse::digits("4.457 „Mag ich“-Bewertungen"), Some(4457));
+        assert_eq!(
+            parse::digits("Mag ich – zusammen mit 1.234.567 anderen Nutzern"),
+            Some(1_234_567)
+        );
+    }
+
+    #[test]
+    fn french() {
+        // Separated by a narrow no-break space
+        assert_eq!(parse::digits("4\u{202f}457 mentions J’aime"), Some(4457));
+        // Separated by a no-break space
+        assert_eq!(
+            parse::digits("1\u{a0}234\u{a0}567 mentions J’aime"),
+            Some(1_234_567)
+        );
+    }
+
+    #[test]
+    fn no_digits() {
+        assert_eq!(parse::digits("I like this"), None);
+        assert_eq!(parse::digits(""), None);
+    }
+
+    #[test]
+    fn abbreviated() {
+        assert_eq!(parse::digits("1.2M likes"), None);
+        assert_eq!(parse::digits("12K likes"), None);
+        assert_eq!(parse::digits("1,2 Mio. „Mag ich“-Bewertungen"), None);
+        assert_eq!(parse::digits("12 Mio. „Mag ich“-Bewertungen"), None);
+
+        assert_eq!(parse::count("1.2M likes"), Some(1_200_000));
+        assert_eq!(parse::count("1,2 Mio."), None);
+    }
+
+    #[test]
+    fn malformed_groups() {
+        assert_eq!(parse::digits("1,23 likes"), None);
+        assert_eq!(parse::digits("1234,567 likes"), None);
+        assert_eq!(parse::digits("1234 likes"), Some(1234));
+        assert_eq!(parse::digits("4'457 likes"), Some(4457));
+    }
+}
+
+mod views {
+    use super::parse;
+
+    #[test]
+    fn english() {
+        assert_eq!(parse::views("1,234,567 views"), Some(1_234_567));
+        assert_eq!(parse::views("1 view"), Some(1));
+        assert_eq!(parse::views("1.2K views"), Some(1_200));
+        assert_eq!(parse::views("1.2B views"), Some(1_200_000_000));
+        assert_eq!(parse::views("No views"), Some(0));
+    }
+
+    #[test]
+    fn german() {
+        assert_eq!(parse::views("1.234.567 Aufrufe"), Some(1_234_567));
+        assert_eq!(parse::views("1 Aufruf"), Some(1));
+        assert_eq!(parse::views("Keine Aufrufe"), Some(0));
+    }
+
+    #[test]
+    fn french() {
+        assert_eq!(
+            parse::views("1\u{202f}234\u{202f}567 vues"),
+            Some(1_234_567)
+        );
+        assert_eq!(parse::views("1\u{a0}234 vues"), Some(1_234));
+        assert_eq!(parse::views("1 234 vues"), Some(1_234));
+        assert_eq!(parse::views("Aucune vue"), Some(0));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(parse::views("views"), None);
+        assert_eq!(parse::views("1,234 videos"), None);
+        assert_eq!(parse::views("many views"), None);
+        assert_eq!(parse::views("1.2.3x views"), None);
+    }
+}
+
+mod length {
+    use super::{parse, Duration};
+
+    #[test]
+    fn seconds() {
+        assert_eq!(parse::length("0"), Some(Duration::from_secs(0)));
+        assert_eq!(parse::length("59"), Some(Duration::from_secs(59)));
+    }
+
+    #[test]
+    fn minutes() {
+        assert_eq!(parse::length("0:05"), Some(Duration::from_secs(5)));
+        assert_eq!(parse::length("4:05"), Some(Duration::from_secs(245)));
+        assert_eq!(parse::length("22:38"), Some(Duration::from_secs(1358)));
+    }
+
+    #[test]
+    fn hours() {
+        assert_eq!(parse::length("1:02:03"), Some(Duration::from_secs(3723)));
+        assert_eq!(parse::length("12:00:00"), Some(Duration::from_secs(43200)));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(parse::length(""), None);
+        assert_eq!(parse::length("LIVE"), None);
+        assert_eq!(parse::length("1::2"), None);
+        assert_eq!(parse::length("1:-2"), None);
+    }
+}
+
+mod date {
+    use super::{parse, NaiveDate};
+
+    #[test]
+    fn plain() {
+        assert_eq!(
+            parse::date("Jun 22, 2021"),
+            NaiveDate::from_ymd_opt(2021, 6, 22)
+        );
+        assert_eq!(
+            parse::date("Jan 1, 2020"),
+            NaiveDate::from_ymd_opt(2020, 1, 1)
+        );
+        assert_eq!(
+            parse::date("Sep 9, 2019"),
+            NaiveDate::from_ymd_opt(2019, 9, 9)
+        );
+    }
+
+    #[test]
+    fn premiere() {
+        assert_eq!(
+            parse::date("Premiered Jun 22, 2021"),
+            NaiveDate::from_ymd_opt(2021, 6, 22)
+        );
+        assert_eq!(
+            parse::date("Premieres Dec 24, 2030"),
+            NaiveDate::from_ymd_opt(2030, 12, 24)
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(parse::date(""), None);
+        assert_eq!(parse::date("2021-06-22"), None);
+        assert_eq!(parse::date("Jun 31, 2021"), None);
+        assert_eq!(parse::date("Streamed live 2 hours ago"), None);
+    }
+}
+
+mod countdown {
+    use super::{parse, Duration};
+
+    #[test]
+    fn units() {
+        assert_eq!(
+            parse::countdown("Premieres in 35 minutes"),
+            Some(Duration::from_secs(35 * 60))
+        );
+        assert_eq!(
+            parse::countdown("Premieres in 1 minute"),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            parse::countdown("Premieres in 2 hours"),
+            Some(Duration::from_secs(2 * 60 * 60))
+        );
+        assert_eq!(
+            parse::countdown("Starts in 3 days"),
+            Some(Duration::from_secs(3 * 24 * 60 * 60))
+        );
+        assert_eq!(
+            parse::countdown("Premieres in 1 week"),
+            Some(Duration::from_secs(7 * 24 * 60 * 60))
+        );
+        assert_eq!(
+            parse::countdown("Premieres in 30 seconds"),
+            Some(Duration::from_secs(30))
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(parse::countdown(""), None);
+        assert_eq!(parse::countdown("Premiered Jun 22, 2021"), None);
+        assert_eq!(parse::countdown("Premieres in a few moments"), None);
+        assert_eq!(parse::countdown("Premieres in 2 fortnights"), None);
+        assert_eq!(parse::countdown("Premieres in 2 hours ago"), None);
+        assert_eq!(parse::countdown("1,234 waiting"), None);
+    }
+}
+
+mod relative_date {
+    use super::{parse, NaiveDate, TimeZone, Utc};
+
+    fn now() -> chrono::DateTime<Utc> {
+        Utc.with_ymd_and_hms(2021, 3, 31, 12, 0, 0).unwrap()
+    }
+
+    fn date(value: &str) -> Option<NaiveDate> {
+        parse::relative_date(value, now())
+    }
+
+    #[test]
+    fn units() {
+        let ymd = NaiveDate::from_ymd_opt;
+
+        assert_eq!(date("30 seconds ago"), ymd(2021, 3, 31));
+        assert_eq!(date("1 second ago"), ymd(2021, 3, 31));
+        assert_eq!(date("59 minutes ago"), ymd(2021, 3, 31));
+        assert_eq!(date("13 hours ago"), ymd(2021, 3, 30));
+        assert_eq!(date("1 day ago"), ymd(2021, 3, 30));
+        assert_eq!(date("2 weeks ago"), ymd(2021, 3, 17));
+        assert_eq!(date("1 month ago"), ymd(2021, 2, 28));
+        assert_eq!(date("3 months ago"), ymd(2020, 12, 31));
+        assert_eq!(date("1 year ago"), ymd(2020, 3, 31));
+        assert_eq!(date("10 years ago"), ymd(2011, 3, 31));
+    }
+
+    #[test]
+    fn prefixes() {
+        let ymd = NaiveDate::from_ymd_opt;
+
+        assert_eq!(date("Streamed 2 days ago"), ymd(2021, 3, 29));
+        assert_eq!(date("Premiered 1 week ago"), ymd(2021, 3, 24));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(date(""), None);
+        assert_eq!(date("ago"), None);
+        assert_eq!(date("2 days"), None);
+        assert_eq!(date("two days ago"), None);
+        assert_eq!(date("2 fortnights ago"), None);
+        assert_eq!(date("2 days ago!"), None);
+        assert_eq!(date("Jun 22, 2021"), None);
+    }
+}
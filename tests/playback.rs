@@ -0,0 +1,63 @@
+use ytextract::video::PlaybackTracking;
+
+fn tracking() -> PlaybackTracking {
+    PlaybackTracking {
+        playback_url:
+            "https://s.youtube.com/api/stats/playback?cl=123&docid=7B2PIVSWtJA&ns=yt&el=detailpage"
+                .parse()
+                .unwrap(),
+        watchtime_url: None,
+    }
+}
+
+fn cpn(url: &reqwest::Url) -> String {
+    url.query_pairs()
+        .find(|(key, _)| key == "cpn")
+        .expect("No cpn")
+        .1
+        .into_owned()
+}
+
+#[test]
+fn ping_url() {
+    let url = tracking().ping_url();
+
+    assert_eq!(url.host_str(), Some("s.youtube.com"));
+    assert_eq!(url.path(), "/api/stats/playback");
+
+    let pairs: Vec<_> = url
+        .query_pairs()
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect();
+    for expected in ["cl", "docid", "ns", "el", "ver", "cpn"] {
+        assert!(pairs.iter().any(|(key, _)| key == expected), "{}", expected);
+    }
+    assert!(pairs.contains(&("ver".to_string(), "2".to_string())));
+}
+
+#[test]
+fn nonce() {
+    let first = cpn(&tracking().ping_url());
+    let second = cpn(&tracking().ping_url());
+
+    assert_eq!(first.len(), 16);
+    assert!(first
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+    assert_ne!(first, second);
+}
+
+#[tokio::test]
+async fn video() -> Result<(), Box<dyn std::error::Error>> {
+    let video = ytextract::Client::new()
+        .video("7B2PIVSWtJA".parse()?)
+        .await?;
+
+    let tracking = video.playback_tracking().expect("No playback tracking");
+    assert!(tracking
+        .playback_url
+        .query_pairs()
+        .any(|(key, value)| key == "docid" && value == "7B2PIVSWtJA"));
+
+    Ok(())
+}
@@ -1,5 +1,8 @@
 use futures::StreamExt;
-use ytextract::Client;
+use ytextract::{Client, Error};
+
+#[cfg(feature = "unstable")]
+mod common;
 
 #[tokio::test]
 async fn get() -> Result<(), Box<dyn std::error::Error>> {
@@ -16,8 +19,15 @@ async fn get() -> Result<(), Box<dyn std::error::Error>> {
     assert_eq!(channel.id(), channel.upgrade().await?.id());
     assert!(playlist.unlisted());
     assert!(!playlist.thumbnails().is_empty());
-    assert!(playlist.views() >= 4);
+    assert!(playlist
+        .display_thumbnail()
+        .is_some_and(|x| !x.is_placeholder()));
+    assert!(playlist.views() >= Some(4));
     assert_eq!(playlist.length(), 122);
+    assert!(!playlist.is_podcast());
+    assert_eq!(playlist.episode_count(), None);
+    assert!(playlist.contributors().is_empty());
+    assert_eq!(playlist.saves(), None);
 
     let videos: Vec<_> = playlist.videos().collect().await;
 
@@ -65,7 +75,9 @@ async fn video() -> Result<(), Box<dyn std::error::Error>> {
                 let video = video?;
                 assert_eq!(video.id(), "1_ozXudbN-4".parse()?);
                 assert_eq!(video.title(), "Team Grimoire - C18H27NO3");
-                assert_eq!(video.length(), std::time::Duration::from_secs(5 * 60 + 38));
+                assert_eq!(video.index(), Some(1));
+                assert_eq!(video.length(), Some(std::time::Duration::from_secs(5 * 60 + 38)));
+                assert!(!video.is_upcoming());
                 assert!(!video.thumbnails().is_empty());
                 assert_eq!(video.channel().id(), "UCkc7SaDsN0MS6GNWFXEB0Lg".parse()?);
                 assert_eq!(video.upgrade().await?.id(), video.id());
@@ -153,15 +165,705 @@ mod error {
         let videos: Vec<_> = playlist.videos().collect().await;
 
         for (video, (reason, id)) in videos.iter().zip(ERROR_IDS) {
-            assert_eq!(
+            let id: ytextract::video::Id = id.parse()?;
+            assert!(matches!(
                 video,
-                &Err(ytextract::playlist::video::Error {
-                    reason,
-                    id: id.parse()?
-                })
-            );
+                Err(ytextract::Error::Unavailable(err)) if err.id == id && err.reason == reason
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn contains() -> Result<(), Box<dyn std::error::Error>> {
+        let playlist = Client::new()
+            .playlist("PLCSusC_jlo146Bv2QRvW7jvV0wZYiSf8N".parse()?)
+            .await?;
+
+        assert!(playlist.contains("MwCXB2byk58".parse()?).await?);
+        assert_eq!(playlist.find_video("MwCXB2byk58".parse()?).await?, None);
+
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn refresh() -> Result<(), Box<dyn std::error::Error>> {
+    let mut playlist = Client::new()
+        .playlist("PLCSusC_jlo14BH5hHnOh9b0O18HtGT3eP".parse()?)
+        .await?;
+
+    let previous = playlist.refresh().await?;
+
+    assert_eq!(previous, playlist);
+    assert_eq!(playlist.length(), previous.length());
+    assert!(playlist.fetched_at() >= previous.fetched_at());
+    assert!(playlist.updated().is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn index() -> Result<(), Box<dyn std::error::Error>> {
+    let playlist = Client::new()
+        .playlist("PLCSusC_jlo14BH5hHnOh9b0O18HtGT3eP".parse()?)
+        .await?;
+
+    let indices: Vec<_> = playlist
+        .videos()
+        .map(|x| match x {
+            Ok(video) => Ok(video.index()),
+            // Unavailable videos keep their position
+            Err(ytextract::Error::Unavailable(err)) => Ok(err.index),
+            Err(err) => Err(err),
+        })
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<Result<Option<Vec<_>>, _>>()?
+        .expect("Video without index");
+
+    assert!(!indices.is_empty());
+    assert_eq!(indices.len() as u64, playlist.length());
+    assert_eq!(indices, (1..=indices.len() as u64).collect::<Vec<_>>());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn radio() -> Result<(), Box<dyn std::error::Error>> {
+    let seed: ytextract::video::Id = "dQw4w9WgXcQ".parse()?;
+    let radio = Client::new().radio("RDdQw4w9WgXcQ".parse()?, seed).await?;
+
+    assert_eq!(radio.seed(), seed);
+    assert!(radio.title().is_some());
+
+    let videos: Vec<_> = radio.videos().take(50).collect().await;
+    let videos = videos.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+    assert_eq!(videos[0].id(), seed);
+    assert!(videos.iter().all(|x| x.channel().is_some()));
+    let indices: Vec<_> = videos.iter().filter_map(|x| x.index()).collect();
+    assert_eq!(indices, (1..=videos.len() as u64).collect::<Vec<_>>());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn videos_from() -> Result<(), Box<dyn std::error::Error>> {
+    let playlist = Client::new()
+        .playlist("PLWwAypAcFRgKFlxtLbn_u14zddtDJj3mk".parse()?)
+        .await?;
+
+    let first = playlist
+        .videos_from(250)
+        .next()
+        .await
+        .expect("playlist has more than 250 videos")?;
+
+    let index = first.index().expect("video has a index");
+    assert!((150..=250).contains(&index), "{}", index);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn contains() -> Result<(), Box<dyn std::error::Error>> {
+    let playlist = Client::new()
+        .playlist("PLCSusC_jlo14F22jss8ZtDLbpmRQIVLzr".parse()?)
+        .await?;
+    let id = "1_ozXudbN-4".parse()?;
+
+    assert!(playlist.contains(id).await?);
+    assert_eq!(playlist.find_video(id).await?.map(|x| x.id()), Some(id));
+    assert!(!playlist.contains("7B2PIVSWtJA".parse()?).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn videos_upgraded() -> Result<(), Box<dyn std::error::Error>> {
+    let playlist = Client::new()
+        .playlist("PLCSusC_jlo14BH5hHnOh9b0O18HtGT3eP".parse()?)
+        .await?;
+
+    let videos: Vec<_> = playlist.videos().collect().await;
+    let upgraded: Vec<_> = playlist.videos_upgraded(4).collect().await;
+
+    assert!(!videos.is_empty());
+    assert_eq!(videos.len(), upgraded.len());
+    for (video, upgraded) in videos.iter().zip(&upgraded) {
+        match (video, upgraded) {
+            (Ok(video), Ok(upgraded)) => assert_eq!(video.id(), upgraded.id()),
+            (Err(Error::Unavailable(video)), Err(Error::Unavailable(upgraded))) => {
+                assert_eq!(video.id, upgraded.id);
+                assert_eq!(video.index, upgraded.index);
+            }
+            (video, upgraded) => panic!("{:?} was upgraded to {:?}", video, upgraded),
+        }
+    }
+
+    Ok(())
+}
+
+mod personal {
+    use ytextract::{error::Youtube, playlist::PlaylistKind, Client, Error};
+
+    #[test]
+    fn kind() -> Result<(), Box<dyn std::error::Error>> {
+        for id in ["WL", "LL", "FLUCXuqSBlHAE6Xw-yeJA0Tunw"] {
+            let id: ytextract::playlist::Id = id.parse()?;
+            assert_eq!(id.kind(), PlaylistKind::RequiresAuth, "{}", id);
+        }
+
+        let id: ytextract::playlist::Id = "PLCSusC_jlo14BH5hHnOh9b0O18HtGT3eP".parse()?;
+        assert_eq!(id.kind(), PlaylistKind::Regular);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn short_circuit() -> Result<(), Box<dyn std::error::Error>> {
+        let res = Client::new().playlist("WL".parse()?).await;
+
+        assert!(matches!(
+            res,
+            Err(Error::Youtube(Youtube::RequiresAuthentication))
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn override_requests() -> Result<(), Box<dyn std::error::Error>> {
+        let client = Client::builder().personal_playlists(true).build();
+
+        // Without cookies YouTube refuses the request itself
+        let res = client.playlist("WL".parse()?).await;
+        assert!(!matches!(
+            res,
+            Err(Error::Youtube(Youtube::RequiresAuthentication))
+        ));
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "unstable")]
+mod parts {
+    use futures::StreamExt;
+    use serde_json::json;
+    use ytextract::{
+        playlist::{LastUpdated, Privacy},
+        Client, Playlist,
+    };
+
+    #[cfg(feature = "serde")]
+    use crate::common::round_trip;
+    use crate::common::{
+        playlist_primary_info, playlist_response, playlist_video, playlist_with_videos, serve_api,
+        CHANNEL, PLAYLIST, VIDEO,
+    };
+
+    fn playlist(response: &serde_json::Value) -> ytextract::Result<Playlist> {
+        Playlist::from_parts(&response.to_string(), Client::new())
+    }
+
+    /// A video that was deleted from YouTube at `index`.
+    fn deleted_video(index: u64) -> serde_json::Value {
+        json!({
+            "playlistVideoRenderer": {
+                "videoId": "MwCXB2byk58",
+                "index": { "simpleText": index.to_string() },
+                "title": { "runs": [{ "text": "[Deleted video]" }] }
+            }
+        })
+    }
+
+    #[test]
+    fn get() -> Result<(), Box<dyn std::error::Error>> {
+        let playlist = playlist(&playlist_response())?;
+
+        assert_eq!(playlist.id(), PLAYLIST.parse()?);
+        assert_eq!(playlist.title(), "ytextract - test");
+        assert_eq!(playlist.description(), "A");
+        assert!(playlist.unlisted());
+        assert_eq!(playlist.privacy(), Privacy::Unlisted);
+        assert_eq!(playlist.length(), 122);
+        assert_eq!(playlist.views(), Some(4));
+        assert_eq!(playlist.channel(), None);
+
+        let videos = playlist.videos();
+        assert_eq!(videos.expected_len(), Some(122));
+        assert_eq!(futures::Stream::size_hint(&videos), (0, None));
+        assert_eq!(playlist.videos_from(10).expected_len(), None);
+
+        assert_eq!(
+            playlist.updated(),
+            chrono::NaiveDate::from_ymd_opt(2021, 6, 22)
+        );
+        assert_eq!(
+            playlist.last_updated(),
+            chrono::NaiveDate::from_ymd_opt(2021, 6, 22).map(LastUpdated::Date)
+        );
+        assert!(playlist.fetched_at() <= chrono::Utc::now());
+
+        #[cfg(feature = "serde")]
+        {
+            let snapshot = playlist.snapshot();
+            assert_eq!(snapshot.id, PLAYLIST.parse()?);
+            assert_eq!(snapshot.updated, playlist.updated());
+            assert_eq!(snapshot.fetched_at, playlist.fetched_at());
+            round_trip(snapshot)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn url_canonical() -> Result<(), Box<dyn std::error::Error>> {
+        let with_url = |url: &str| {
+            let mut response = playlist_response();
+            response["microformat"]["microformatDataRenderer"]["urlCanonical"] = url.into();
+            playlist(&response)
+        };
+
+        let cases = vec![
+            (
+                format!("https://www.youtube.com/playlist?list={}", PLAYLIST),
+                PLAYLIST,
+            ),
+            (
+                "https://music.youtube.com/playlist?list=OLAK5uy_mtOdjCW76nDvf5yOzgcAVMYpJ5gcW5uKU"
+                    .to_string(),
+                "OLAK5uy_mtOdjCW76nDvf5yOzgcAVMYpJ5gcW5uKU",
+            ),
+            (
+                format!(
+                    "https://www.youtube.com/watch?v={}&list=RD{}&start_radio=1",
+                    VIDEO, VIDEO
+                ),
+                "RD7B2PIVSWtJA",
+            ),
+            (PLAYLIST.to_string(), PLAYLIST),
+        ];
+        for (url, id) in cases {
+            assert_eq!(with_url(&url)?.id(), id.parse()?, "{}", url);
         }
 
+        assert!(matches!(
+            with_url("https://www.youtube.com/playlist"),
+            Err(ytextract::Error::InvalidUrl(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn privacy() -> Result<(), Box<dyn std::error::Error>> {
+        let with = |unlisted: bool, icon: Option<&str>| {
+            let mut response = playlist_response();
+            response["microformat"]["microformatDataRenderer"]["unlisted"] = unlisted.into();
+            if let Some(icon) = icon {
+                playlist_primary_info(&mut response)["badges"] = json!([{
+                    "metadataBadgeRenderer": { "icon": { "iconType": icon }, "label": "Badge" }
+                }]);
+            }
+            playlist(&response)
+        };
+
+        assert_eq!(with(false, None)?.privacy(), Privacy::Public);
+        assert_eq!(with(true, None)?.privacy(), Privacy::Unlisted);
+        assert_eq!(
+            with(false, Some("PRIVACY_UNLISTED"))?.privacy(),
+            Privacy::Unlisted
+        );
+        assert_eq!(
+            with(false, Some("PRIVACY_PRIVATE"))?.privacy(),
+            Privacy::Private
+        );
+        assert!(!with(false, Some("PRIVACY_PRIVATE"))?.unlisted());
+        assert_eq!(with(false, Some("CHECK"))?.privacy(), Privacy::Public);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn videos_from() -> Result<(), Box<dyn std::error::Error>> {
+        let (url, requests) = serve_api(|_, _| {
+            json!({
+                "onResponseReceivedActions": [{
+                    "appendContinuationItemsAction": { "continuationItems": [] }
+                }]
+            })
+        });
+        let client = Client::builder().api_url(url).build();
+        let playlist = Playlist::from_parts(&playlist_response().to_string(), client)?;
+
+        let continuation = |index| {
+            let playlist = &playlist;
+            let requests = &requests;
+            async move {
+                assert_eq!(playlist.videos_from(index).count().await, 0);
+                let (endpoint, body) = requests.recv().unwrap();
+                assert_eq!(endpoint, "browse");
+                body["continuation"].as_str().unwrap().to_string()
+            }
+        };
+
+        assert_eq!(
+            continuation(1).await,
+            "4qmFsgJhEiRWTFBMQ1N1c0NfamxvMTRCSDVoSG5PaDliME8xOEh0R1QzZVAaFENBQjZCbEJVT2tOQlFRJTNEJTNEmgIiUExDU3VzQ19qbG8xNEJINWhIbk9oOWIwTzE4SHRHVDNlUA%3D%3D"
+        );
+        assert_eq!(
+            continuation(100).await,
+            "4qmFsgJhEiRWTFBMQ1N1c0NfamxvMTRCSDVoSG5PaDliME8xOEh0R1QzZVAaFENBQjZCbEJVT2tOSFRRJTNEJTNEmgIiUExDU3VzQ19qbG8xNEJINWhIbk9oOWIwTzE4SHRHVDNlUA%3D%3D"
+        );
+        assert_eq!(
+            continuation(101).await,
+            "4qmFsgJhEiRWTFBMQ1N1c0NfamxvMTRCSDVoSG5PaDliME8xOEh0R1QzZVAaFENBRjZCbEJVT2tOSFVRJTNEJTNEmgIiUExDU3VzQ19qbG8xNEJINWhIbk9oOWIwTzE4SHRHVDNlUA%3D%3D"
+        );
+        assert_eq!(
+            continuation(8000).await,
+            "4qmFsgJfEiRWTFBMQ1N1c0NfamxvMTRCSDVoSG5PaDliME8xOEh0R1QzZVAaEkNFOTZCMUJVT2tOTU9DMCUzRJoCIlBMQ1N1c0NfamxvMTRCSDVoSG5PaDliME8xOEh0R1QzZVA%3D"
+        );
+        assert_eq!(continuation(0).await, continuation(1).await);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn continuation_in_middle() -> Result<(), Box<dyn std::error::Error>> {
+        use ytextract::playlist::video::UnavailabilityReason;
+
+        let (url, requests) = serve_api(|_, _| {
+            json!({
+                "onResponseReceivedActions": [{
+                    "appendContinuationItemsAction": {
+                        "continuationItems": [
+                            { "playlistVideoRenderer": playlist_video("1_ozXudbN-4", 3) },
+                            {
+                                "playlistVideoRenderer": {
+                                    "videoId": "Tk4J8s5T790",
+                                    "title": { "runs": [{ "text": "[Unavailable video]" }] }
+                                }
+                            }
+                        ]
+                    }
+                }]
+            })
+        });
+        let client = Client::builder().api_url(url).build();
+        let response = playlist_with_videos(json!([
+            { "playlistVideoRenderer": playlist_video(VIDEO, 1) },
+            {
+                "continuationItemRenderer": {
+                    "continuationEndpoint": { "continuationCommand": { "token": "next" } }
+                }
+            },
+            { "playlistVideoRenderer": playlist_video("MwCXB2byk58", 2) },
+        ]));
+        let playlist = Playlist::from_parts(&response.to_string(), client)?;
+
+        // The videos after the continuation are yielded before its videos
+        let videos: Vec<_> = playlist.videos().collect().await;
+        assert_eq!(videos.len(), 4);
+        let ids: Vec<_> = videos[..3]
+            .iter()
+            .map(|x| x.as_ref().expect("Video unavailable").id().to_string())
+            .collect();
+        assert_eq!(ids, [VIDEO, "MwCXB2byk58", "1_ozXudbN-4"]);
+
+        // Unknown reasons for unavailable videos are kept
+        assert!(matches!(
+            &videos[3],
+            Err(ytextract::Error::Unavailable(err))
+                if err.reason == UnavailabilityReason::Unknown("[Unavailable video]".to_string())
+        ));
+
+        let (_, body) = requests.recv()?;
+        assert_eq!(body["continuation"], "next");
+        assert!(requests.try_recv().is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn videos_upgraded() -> Result<(), Box<dyn std::error::Error>> {
+        let playlist = playlist(&playlist_with_videos(json!([deleted_video(1)])))?;
+
+        // A concurrency of 0 does not panic
+        let upgraded = playlist.videos_upgraded(0).collect::<Vec<_>>().await;
+        assert!(matches!(
+            upgraded[..],
+            [Err(ytextract::Error::Unavailable(ref err))] if err.index == Some(1)
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn contains() -> Result<(), Box<dyn std::error::Error>> {
+        let playlist = playlist(&playlist_with_videos(json!([
+            { "playlistVideoRenderer": playlist_video(VIDEO, 1) },
+            deleted_video(2),
+        ])))?;
+
+        assert!(playlist.contains(VIDEO.parse()?).await?);
+        assert!(playlist.contains("MwCXB2byk58".parse()?).await?);
+        assert!(!playlist.contains("1_ozXudbN-4".parse()?).await?);
+
+        let video = playlist.find_video(VIDEO.parse()?).await?;
+        assert_eq!(video.map(|x| x.index()), Some(Some(1)));
+        assert!(playlist.find_video("MwCXB2byk58".parse()?).await?.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn upcoming() -> Result<(), Box<dyn std::error::Error>> {
+        let video = |id: &str, index: u64, overlay: &str| {
+            let mut video = playlist_video(id, index);
+            video["thumbnailOverlays"] = json!([{
+                "thumbnailOverlayTimeStatusRenderer": {
+                    "text": { "simpleText": overlay },
+                    "style": overlay
+                }
+            }]);
+            // Premieres and scheduled livestreams have no length yet
+            if overlay == "UPCOMING" {
+                video.as_object_mut().unwrap().remove("lengthSeconds");
+            }
+            json!({ "playlistVideoRenderer": video })
+        };
+
+        let playlist = playlist(&playlist_with_videos(json!([
+            video(VIDEO, 1, "DEFAULT"),
+            video("9bZkp7q19f0", 2, "UPCOMING"),
+        ])))?;
+        let videos = playlist
+            .videos()
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+
+        assert_eq!(videos.len(), 2);
+        assert!(!videos[0].is_upcoming());
+        assert_eq!(
+            videos[0].length(),
+            Some(std::time::Duration::from_secs(1358))
+        );
+        assert!(videos[1].is_upcoming());
+        assert_eq!(videos[1].length(), None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn podcast() -> Result<(), Box<dyn std::error::Error>> {
+        let podcast = |episodes: &str| {
+            let mut response = playlist_response();
+            response.as_object_mut().unwrap().remove("sidebar");
+            response["microformat"]["microformatDataRenderer"]["description"] = json!("");
+            response["contents"]["twoColumnBrowseResultsRenderer"]["tabs"][0]["tabRenderer"]
+                ["content"] = json!({
+                "richGridRenderer": {
+                    "contents": [{
+                        "richItemRenderer": {
+                            "content": { "playlistVideoRenderer": playlist_video(VIDEO, 1) }
+                        }
+                    }]
+                }
+            });
+            response["header"] = json!({
+                "pageHeaderRenderer": {
+                    "content": {
+                        "pageHeaderViewModel": {
+                            "metadata": {
+                                "contentMetadataViewModel": {
+                                    "metadataRows": [{
+                                        "metadataParts": [
+                                            { "text": { "content": "Podcast" } },
+                                            { "text": { "content": episodes } }
+                                        ]
+                                    }]
+                                }
+                            },
+                            "description": {
+                                "descriptionPreviewViewModel": {
+                                    "description": { "content": "A podcast" }
+                                }
+                            }
+                        }
+                    }
+                }
+            });
+            playlist(&response)
+        };
+
+        let playlist = podcast("12 episodes")?;
+        assert!(playlist.is_podcast());
+        assert_eq!(playlist.episode_count(), Some(12));
+        assert_eq!(playlist.length(), 12);
+        assert_eq!(playlist.videos().expected_len(), Some(12));
+        assert_eq!(playlist.views(), None);
+        assert_eq!(playlist.description(), "A podcast");
+
+        let videos = playlist
+            .videos()
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(videos.len(), 1);
+        assert_eq!(videos[0].id(), VIDEO.parse()?);
+
+        // An unparsable count is unknown instead of a panic
+        let playlist = podcast("Many episodes")?;
+        assert!(playlist.is_podcast());
+        assert_eq!(playlist.episode_count(), None);
+        assert_eq!(playlist.length(), 0);
+        assert_eq!(playlist.videos().expected_len(), None);
+
+        Ok(())
+    }
+
+    /// [`playlist_response`] with the `videoOwnerRenderer` `owner`.
+    fn owned_by(owner: serde_json::Value) -> serde_json::Value {
+        let mut response = playlist_response();
+        response["sidebar"]["playlistSidebarRenderer"]["items"]
+            .as_array_mut()
+            .unwrap()
+            .push(json!({
+                "playlistSidebarSecondaryInfoRenderer": {
+                    "videoOwner": { "videoOwnerRenderer": owner }
+                }
+            }));
+        response
+    }
+
+    #[test]
+    fn channel() -> Result<(), Box<dyn std::error::Error>> {
+        let playlist = playlist(&owned_by(json!({
+            "title": {
+                "runs": [{
+                    "text": "Linus Tech Tips",
+                    "navigationEndpoint": { "browseEndpoint": { "browseId": CHANNEL } }
+                }]
+            },
+            "thumbnail": {
+                "thumbnails": [{ "url": "https://yt3.ggpht.com/a", "width": 48, "height": 48 }]
+            },
+            "subscriberCountText": { "simpleText": "13.8M subscribers" }
+        })))?;
+
+        let channel = playlist.channel().expect("playlist has a channel");
+        assert_eq!(channel.id(), CHANNEL.parse()?);
+        assert_eq!(channel.name(), "Linus Tech Tips");
+        assert_eq!(channel.subscribers(), Some(13_800_000));
+        assert_eq!(channel.thumbnails().count(), 1);
+        #[cfg(feature = "serde")]
+        assert_eq!(
+            playlist.snapshot().channel.and_then(|x| x.subscribers),
+            Some(13_800_000)
+        );
+        // A single owner is not a collaboration
+        assert!(playlist.contributors().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn contributors() -> Result<(), Box<dyn std::error::Error>> {
+        const SHORT_CIRCUIT: &str = "UCdBK94H6oZT2Q7l0-b0xmMg";
+
+        let playlist = playlist(&owned_by(json!({
+            "title": {
+                "runs": [
+                    {
+                        "text": "Linus Tech Tips",
+                        "navigationEndpoint": { "browseEndpoint": { "browseId": CHANNEL } }
+                    },
+                    { "text": ", " },
+                    {
+                        "text": "Short Circuit",
+                        "navigationEndpoint": { "browseEndpoint": { "browseId": SHORT_CIRCUIT } }
+                    },
+                    { "text": " and " },
+                    { "text": "TechLinked" },
+                    { "text": " and 2 more" }
+                ]
+            },
+            "thumbnail": { "thumbnails": [] }
+        })))?;
+
+        assert_eq!(
+            playlist.contributors(),
+            [
+                ("Linus Tech Tips", Some(CHANNEL.parse()?)),
+                ("Short Circuit", Some(SHORT_CIRCUIT.parse()?)),
+                ("TechLinked", None),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn saves() -> Result<(), Box<dyn std::error::Error>> {
+        let with = |stat: serde_json::Value| {
+            let mut response = playlist_response();
+            playlist_primary_info(&mut response)["stats"]
+                .as_array_mut()
+                .unwrap()
+                .push(stat);
+            playlist(&response)
+        };
+
+        assert_eq!(
+            with(json!({ "simpleText": "1,234 saves" }))?.saves(),
+            Some(1234)
+        );
+        assert_eq!(with(json!({ "simpleText": "1 save" }))?.saves(), Some(1));
+        assert_eq!(with(json!({ "simpleText": "No saves" }))?.saves(), Some(0));
+        // The other stats are not mistaken for saves
+        assert_eq!(playlist(&playlist_response())?.saves(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn last_updated() -> Result<(), Box<dyn std::error::Error>> {
+        let with = |stat: serde_json::Value| {
+            let mut response = playlist_response();
+            playlist_primary_info(&mut response)["stats"][2] = stat;
+            playlist(&response)
+        };
+
+        let today = with(json!({ "simpleText": "Updated today" }))?;
+        assert_eq!(
+            today.last_updated(),
+            Some(LastUpdated::Relative("today".into()))
+        );
+        assert_eq!(today.updated(), Some(today.fetched_at().date_naive()));
+
+        let days = with(json!({ "simpleText": "Updated 3 days ago" }))?;
+        assert_eq!(
+            days.last_updated(),
+            Some(LastUpdated::Relative("3 days ago".into()))
+        );
+
+        let unknown = with(json!({
+            "runs": [{ "text": "Last updated on " }, { "text": "5 janv. 2023" }]
+        }))?;
+        assert_eq!(
+            unknown.last_updated(),
+            Some(LastUpdated::Relative("5 janv. 2023".into()))
+        );
+        assert_eq!(unknown.updated(), None);
+
         Ok(())
     }
 }
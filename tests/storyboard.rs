@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use ytextract::{video::Storyboard, Client};
+
+const SPEC: &str = "https://i.ytimg.com/sb/nI2e-J6fsuk/storyboard3_L$L/$N.jpg?sqp=-oaymwENSDfyq4qpAwVwAcABBqLzl_8DBgj8yP2GBg==|48#27#100#10#10#0#default#rs$AOn4CLBzWUE6uW-oPwGRgGRnpyHeRKkzrQ|80#45#136#10#10#10000#M$M#rs$AOn4CLCQW0ZOmSBP-yyFi1VdwFCT6kwWZQ|160#90#136#5#5#10000#M$M#rs$AOn4CLBbVYqMvEP7Cb3Nv5PLH5mYr-XRgg";
+
+#[test]
+fn from_spec() {
+    let storyboards = Storyboard::from_spec(SPEC, Duration::from_secs(1358));
+    assert_eq!(storyboards.len(), 3);
+
+    let first = &storyboards[0];
+    assert_eq!((first.width(), first.height()), (48, 27));
+    assert_eq!((first.columns(), first.rows()), (10, 10));
+    assert_eq!(first.frame_count(), 100);
+    assert_eq!(first.interval(), Duration::from_millis(13_580));
+    let urls: Vec<_> = first.urls().collect();
+    assert_eq!(urls.len(), 1);
+    assert_eq!(
+        urls[0].as_str(),
+        "https://i.ytimg.com/sb/nI2e-J6fsuk/storyboard3_L0/default.jpg?sqp=-oaymwENSDfyq4qpAwVwAcABBqLzl_8DBgj8yP2GBg==&sigh=rs%24AOn4CLBzWUE6uW-oPwGRgGRnpyHeRKkzrQ"
+    );
+
+    let last = &storyboards[2];
+    assert_eq!((last.width(), last.height()), (160, 90));
+    assert_eq!(last.interval(), Duration::from_secs(10));
+    let urls: Vec<_> = last.urls().collect();
+    // 136 frames on sheets of 5x5
+    assert_eq!(urls.len(), 6);
+    assert!(urls[5].path().ends_with("/storyboard3_L2/M5.jpg"));
+}
+
+#[test]
+fn malformed() {
+    assert!(Storyboard::from_spec("", Duration::ZERO).is_empty());
+    assert!(
+        Storyboard::from_spec("https://i.ytimg.com/$L/$N.jpg|48#27", Duration::ZERO).is_empty()
+    );
+
+    // Levels without an interval need a usable frame count
+    for frames in ["0", "4294967296"] {
+        let spec = format!(
+            "https://i.ytimg.com/$L/$N.jpg|48#27#{}#10#10#0#M$M#s",
+            frames
+        );
+        assert!(Storyboard::from_spec(&spec, Duration::from_secs(60)).is_empty());
+    }
+
+    // Huge sheets do not overflow
+    let spec = format!(
+        "https://i.ytimg.com/$L/$N.jpg|48#27#100#{0}#{0}#1000#M$M#s",
+        u64::MAX
+    );
+    let storyboards = Storyboard::from_spec(&spec, Duration::ZERO);
+    assert_eq!(storyboards[0].urls().count(), 1);
+
+    // Invalid urls are skipped instead of panicking
+    let storyboards = Storyboard::from_spec("not a url $N|48#27#100#10#10#0#M$M#s", Duration::ZERO);
+    assert_eq!(storyboards.len(), 1);
+    assert_eq!(storyboards[0].urls().count(), 0);
+}
+
+#[tokio::test]
+async fn video() -> Result<(), Box<dyn std::error::Error>> {
+    let video = Client::new().video("nI2e-J6fsuk".parse()?).await?;
+    let storyboards = video.storyboards();
+
+    assert!(!storyboards.is_empty());
+    assert!(storyboards.iter().all(|x| x.urls().next().is_some()));
+
+    Ok(())
+}
@@ -1,5 +1,8 @@
 use ytextract::Client;
 
+#[cfg(feature = "unstable")]
+mod common;
+
 macro_rules! define_test {
     ($fn:ident, $id:literal, $($attr:meta)?) => {
         $(#[$attr])?
@@ -22,6 +25,7 @@ define_test!(high_quality_streams, "V5Fsj_sCKdg");
 define_test!(vr, "-xNN-bJQ4vI");
 define_test!(hdr, "vX2vsvdq8nw");
 define_test!(subtitles, "YltHGKX80Y8");
+define_test!(age_restricted, "SkRSXFQerZs");
 
 mod embed_restricted {
     use ytextract::Client;
@@ -29,3 +33,1457 @@ mod embed_restricted {
     define_test!(youtube, "_kmeFXjjGfk");
     define_test!(author, "MeJVWBSsPAY");
 }
+
+#[tokio::test]
+async fn muxed() -> Result<(), Box<dyn std::error::Error>> {
+    let mut streams = Client::new().streams("9bZkp7q19f0".parse()?).await?;
+
+    assert!(streams.any(|x| x.is_muxed()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn hls() -> Result<(), Box<dyn std::error::Error>> {
+    let variants = Client::new().hls_streams("5qap5aO4i9A".parse()?).await?;
+
+    assert!(!variants.is_empty());
+    assert!(variants.iter().all(|x| x.bandwidth() > 0));
+
+    Ok(())
+}
+
+#[test]
+fn hls_parse() -> Result<(), Box<dyn std::error::Error>> {
+    let master = r#"#EXTM3U
+#EXT-X-INDEPENDENT-SEGMENTS
+#EXT-X-STREAM-INF:BANDWIDTH=290288,CODECS="avc1.4D400C,mp4a.40.5",RESOLUTION=256x144,FRAME-RATE=30,VIDEO-RANGE=SDR
+https://manifest.googlevideo.com/api/manifest/hls_playlist/itag/91/index.m3u8
+#EXT-X-STREAM-INF:BANDWIDTH=64000,CODECS="mp4a.40.2"
+audio/index.m3u8
+#EXT-X-STREAM-INF:RESOLUTION=1920x1080
+missing/bandwidth.m3u8
+"#;
+    let base = "https://manifest.googlevideo.com/api/manifest/hls_variant/master.m3u8".parse()?;
+
+    let variants = ytextract::stream::Hls::parse(master, &base);
+    assert_eq!(variants.len(), 2);
+
+    assert_eq!(variants[0].bandwidth(), 290288);
+    assert_eq!(variants[0].resolution(), Some((256, 144)));
+    assert_eq!(variants[0].codecs(), Some("avc1.4D400C,mp4a.40.5"));
+    assert_eq!(variants[0].frame_rate(), Some(30.0));
+    assert_eq!(
+        variants[0].url().as_str(),
+        "https://manifest.googlevideo.com/api/manifest/hls_playlist/itag/91/index.m3u8"
+    );
+
+    assert_eq!(variants[1].resolution(), None);
+    assert_eq!(
+        variants[1].url().as_str(),
+        "https://manifest.googlevideo.com/api/manifest/hls_variant/audio/index.m3u8"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn dash() -> Result<(), Box<dyn std::error::Error>> {
+    let representations = Client::new().dash_streams("AI7ULzgf8RU".parse()?).await?;
+
+    assert!(!representations.is_empty());
+    assert!(representations
+        .iter()
+        .all(|x| x.segments().next().is_some()));
+
+    Ok(())
+}
+
+#[test]
+fn dash_parse() -> Result<(), Box<dyn std::error::Error>> {
+    let manifest = r#"<?xml version="1.0" encoding="UTF-8"?>
+<MPD xmlns="urn:mpeg:dash:schema:mpd:2011" type="static" mediaPresentationDuration="PT10.5S">
+  <Period>
+    <AdaptationSet mimeType="audio/mp4">
+      <Representation id="140" codecs="mp4a.40.2" audioSamplingRate="44100" bandwidth="144000">
+        <BaseURL>https://rr1---sn-abcdefgh.googlevideo.com/videoplayback/itag/140/</BaseURL>
+        <SegmentList>
+          <Initialization sourceURL="sq/0"/>
+          <SegmentURL media="sq/1"/>
+          <SegmentURL media="sq/2"/>
+        </SegmentList>
+      </Representation>
+    </AdaptationSet>
+    <AdaptationSet mimeType="video/mp4">
+      <SegmentTemplate timescale="1000" duration="5000" startNumber="0"
+        initialization="$RepresentationID$/init.mp4" media="$RepresentationID$/seg-$Number%03d$.m4s"/>
+      <Representation id="137" codecs="avc1.640028" width="1920" height="1080" frameRate="30000/1001" bandwidth="4000000"/>
+    </AdaptationSet>
+    <AdaptationSet mimeType="video/webm">
+      <Representation id="248" bandwidth="2000000">
+        <SegmentTemplate media="t/$Time$" timescale="1">
+          <SegmentTimeline>
+            <S t="10" d="4" r="1"/>
+            <S d="3"/>
+          </SegmentTimeline>
+        </SegmentTemplate>
+      </Representation>
+    </AdaptationSet>
+  </Period>
+</MPD>"#;
+    let base = "https://manifest.googlevideo.com/api/manifest/dash/".parse()?;
+
+    let representations = ytextract::stream::Dash::parse(manifest, &base)?;
+    assert_eq!(representations.len(), 3);
+
+    let audio = &representations[0];
+    assert_eq!(audio.itag(), Some(140));
+    assert_eq!(audio.mime_type(), "audio/mp4");
+    assert_eq!(audio.sample_rate(), Some(44100));
+    assert_eq!(
+        audio.initialization().map(|x| x.as_str()),
+        Some("https://rr1---sn-abcdefgh.googlevideo.com/videoplayback/itag/140/sq/0")
+    );
+    assert_eq!(audio.segments().count(), 2);
+
+    let video = &representations[1];
+    assert_eq!(video.itag(), Some(137));
+    assert_eq!((video.width(), video.height()), (Some(1920), Some(1080)));
+    assert!((video.frame_rate().unwrap() - 29.97).abs() < 0.01);
+    assert_eq!(
+        video.initialization().map(|x| x.as_str()),
+        Some("https://manifest.googlevideo.com/api/manifest/dash/137/init.mp4")
+    );
+    let segments: Vec<_> = video.segments().map(|x| x.path().to_string()).collect();
+    assert_eq!(
+        segments,
+        [
+            "/api/manifest/dash/137/seg-000.m4s",
+            "/api/manifest/dash/137/seg-001.m4s",
+            "/api/manifest/dash/137/seg-002.m4s",
+        ]
+    );
+
+    let timeline: Vec<_> = representations[2]
+        .segments()
+        .map(|x| x.path().to_string())
+        .collect();
+    assert_eq!(
+        timeline,
+        [
+            "/api/manifest/dash/t/10",
+            "/api/manifest/dash/t/14",
+            "/api/manifest/dash/t/18"
+        ]
+    );
+
+    assert!(ytextract::stream::Dash::parse("<MPD>", &base).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn dash_malformed() -> Result<(), Box<dyn std::error::Error>> {
+    let base: reqwest::Url = "https://manifest.googlevideo.com/api/manifest/dash/".parse()?;
+    let manifest = |duration: &str, timeline: &str| {
+        format!(
+            r#"<MPD mediaPresentationDuration="{}">
+  <Period>
+    <AdaptationSet mimeType="video/mp4">
+      <Representation id="137" bandwidth="4000000">
+        <SegmentTemplate media="t/$Time$" timescale="1">
+          <SegmentTimeline>{}</SegmentTimeline>
+        </SegmentTemplate>
+      </Representation>
+      <Representation id="136" bandwidth="2000000">
+        <SegmentTemplate media="n/$Number$" timescale="1" duration="5"/>
+      </Representation>
+    </AdaptationSet>
+  </Period>
+</MPD>"#,
+            duration, timeline
+        )
+    };
+    let segments = |duration, timeline| -> Result<Vec<usize>, Box<dyn std::error::Error>> {
+        let representations = ytextract::stream::Dash::parse(&manifest(duration, timeline), &base)?;
+        Ok(representations
+            .iter()
+            .map(|x| x.segments().count())
+            .collect())
+    };
+
+    // A repeat until the end, that starts after the end
+    assert_eq!(segments("PT10S", r#"<S t="20" d="4" r="-1"/>"#)?, [1, 2]);
+    // A huge repeat count is capped
+    assert_eq!(
+        segments("PT10S", r#"<S d="1" r="9223372036854775807"/>"#)?,
+        [100_000, 2]
+    );
+    // A negative or overflowing duration is ignored
+    assert_eq!(segments("PT-5S", r#"<S d="4" r="-1"/>"#)?, [1, 0]);
+    assert_eq!(
+        segments("P99999999999999999999D", r#"<S d="4" r="-1"/>"#)?,
+        [1, 0]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn mime_types() {
+    use ytextract::stream::{Codec, Container};
+
+    let cases = vec![
+        (
+            "video/mp4; codecs=\"avc1.640028\"",
+            Container::Mp4,
+            vec![Codec::Avc1],
+        ),
+        (
+            "video/mp4; codecs=\"avc1.4d401f\"",
+            Container::Mp4,
+            vec![Codec::Avc1],
+        ),
+        (
+            "video/mp4; codecs=\"av01.0.08M.08\"",
+            Container::Mp4,
+            vec![Codec::Av01],
+        ),
+        (
+            "video/webm; codecs=\"vp9\"",
+            Container::WebM,
+            vec![Codec::Vp9],
+        ),
+        (
+            "video/webm; codecs=\"vp09.02.51.10.01.09.16.09.00\"",
+            Container::WebM,
+            vec![Codec::Vp9],
+        ),
+        (
+            "audio/mp4; codecs=\"mp4a.40.2\"",
+            Container::Mp4,
+            vec![Codec::Mp4a],
+        ),
+        (
+            "audio/mp4; codecs=\"mp4a.40.5\"",
+            Container::Mp4,
+            vec![Codec::Mp4a],
+        ),
+        (
+            "audio/webm; codecs=\"opus\"",
+            Container::WebM,
+            vec![Codec::Opus],
+        ),
+        (
+            "video/mp4; codecs=\"avc1.42001E, mp4a.40.2\"",
+            Container::Mp4,
+            vec![Codec::Avc1, Codec::Mp4a],
+        ),
+        (
+            "video/3gpp; codecs=\"mp4v.20.3, mp4a.40.2\"",
+            Container::ThreeGp,
+            vec![Codec::Other("mp4v.20.3".to_string()), Codec::Mp4a],
+        ),
+        (
+            "audio/mp4; codecs=\"ec-3\"",
+            Container::Mp4,
+            vec![Codec::Other("ec-3".to_string())],
+        ),
+        ("audio/ogg", Container::Other("ogg".to_string()), vec![]),
+    ];
+
+    for (mime_type, container, codecs) in cases {
+        assert_eq!(
+            Container::from_mime_type(mime_type),
+            container,
+            "{}",
+            mime_type
+        );
+        assert_eq!(Codec::from_mime_type(mime_type), codecs, "{}", mime_type);
+    }
+}
+
+#[test]
+fn video_quality() {
+    use ytextract::stream::VideoQuality;
+
+    assert_eq!(VideoQuality::from_label("144p"), Some(VideoQuality::P144));
+    assert_eq!(
+        VideoQuality::from_label("1080p60"),
+        Some(VideoQuality::P1080)
+    );
+    assert_eq!(
+        VideoQuality::from_label("2160p60 HDR"),
+        Some(VideoQuality::P2160)
+    );
+    assert_eq!(VideoQuality::from_label("4320p"), Some(VideoQuality::P4320));
+    assert_eq!(VideoQuality::from_label("tiny"), None);
+
+    assert_eq!(VideoQuality::from_lines(1088), VideoQuality::P1080);
+    assert_eq!(VideoQuality::from_lines(100), VideoQuality::P144);
+
+    assert!(VideoQuality::P144 < VideoQuality::P240);
+    assert!(VideoQuality::P720 < VideoQuality::P1080);
+    assert!(VideoQuality::P2160 < VideoQuality::P4320);
+}
+
+#[test]
+fn audio_quality() {
+    use ytextract::stream::AudioQuality;
+
+    assert_eq!(AudioQuality::new("AUDIO_QUALITY_LOW"), AudioQuality::Low);
+    assert_eq!(
+        AudioQuality::new("AUDIO_QUALITY_MEDIUM"),
+        AudioQuality::Medium
+    );
+    assert_eq!(AudioQuality::new("AUDIO_QUALITY_HIGH"), AudioQuality::High);
+    assert_eq!(
+        AudioQuality::new("AUDIO_QUALITY_ULTRALOW"),
+        AudioQuality::Other("AUDIO_QUALITY_ULTRALOW".to_string())
+    );
+
+    assert!(AudioQuality::Other(String::new()) < AudioQuality::Low);
+    assert!(AudioQuality::Low < AudioQuality::Medium);
+    assert!(AudioQuality::Medium < AudioQuality::High);
+}
+
+#[tokio::test]
+async fn download() -> Result<(), Box<dyn std::error::Error>> {
+    let stream = Client::new()
+        .streams("9bZkp7q19f0".parse()?)
+        .await?
+        .filter(|x| x.is_audio())
+        .min_by_key(|x| x.bitrate())
+        .expect("No audio stream");
+
+    let path = std::env::temp_dir().join("ytextract-download-test");
+    let _ = std::fs::remove_file(&path);
+
+    let options = ytextract::stream::DownloadOptions::new();
+    let written = stream.download(&path, &options).await?;
+
+    assert_eq!(written, stream.content_length().await?);
+    assert_eq!(std::fs::metadata(&path)?.len(), written);
+    assert!(!path.with_extension("part").exists());
+
+    std::fs::remove_file(&path)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn download_with_progress() -> Result<(), Box<dyn std::error::Error>> {
+    let stream = Client::new()
+        .streams("9bZkp7q19f0".parse()?)
+        .await?
+        .filter(|x| x.is_audio())
+        .min_by_key(|x| x.bitrate())
+        .expect("No audio stream");
+
+    let path = std::env::temp_dir().join("ytextract-download-progress-test");
+    let _ = std::fs::remove_file(&path);
+
+    let mut reports = Vec::new();
+    let written = stream
+        .download_with_progress(&path, |progress| reports.push(progress))
+        .await?;
+
+    assert_eq!(reports.first().map(|x| x.bytes_done), Some(0));
+    assert_eq!(reports.last().map(|x| x.bytes_done), Some(written));
+    assert!(reports
+        .windows(2)
+        .all(|x| x[0].bytes_done <= x[1].bytes_done));
+    assert!(reports.iter().all(|x| x.total == written));
+
+    std::fs::remove_file(&path)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn chunked() -> Result<(), Box<dyn std::error::Error>> {
+    use futures::StreamExt;
+
+    let stream = Client::new()
+        .streams("9bZkp7q19f0".parse()?)
+        .await?
+        .filter(|x| x.is_audio())
+        .min_by_key(|x| x.bitrate())
+        .expect("No audio stream");
+    let total = stream.content_length().await?;
+
+    let mut range = Box::pin(stream.get_range(0..1000).await?);
+    let mut ranged = 0;
+    while let Some(bytes) = range.next().await {
+        ranged += bytes?.len();
+    }
+    assert_eq!(ranged, 1000);
+
+    const CHUNK_SIZE: u64 = 256 * 1024;
+    let chunks: Vec<_> = stream
+        .get_chunked(CHUNK_SIZE)
+        .map(|x| x.map(|x| x.len() as u64))
+        .collect()
+        .await;
+    let chunks = chunks.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+    assert_eq!(chunks.iter().sum::<u64>(), total);
+    assert!(chunks.iter().all(|x| *x <= CHUNK_SIZE));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn resume_download() -> Result<(), Box<dyn std::error::Error>> {
+    use futures::StreamExt;
+    use std::io::Write;
+
+    let stream = Client::new()
+        .streams("9bZkp7q19f0".parse()?)
+        .await?
+        .filter(|x| x.is_audio())
+        .min_by_key(|x| x.bitrate())
+        .expect("No audio stream");
+    let total = stream.content_length().await?;
+
+    let path = std::env::temp_dir().join("ytextract-resume-test");
+    let part = std::env::temp_dir().join("ytextract-resume-test.part");
+    let _ = std::fs::remove_file(&path);
+
+    // Download the first half and abort
+    let mut file = std::fs::File::create(&part)?;
+    let mut half = Box::pin(stream.get_range(0..total / 2).await?);
+    while let Some(bytes) = half.next().await {
+        file.write_all(&bytes?)?;
+    }
+    drop(file);
+    assert_eq!(std::fs::metadata(&part)?.len(), total / 2);
+
+    let written = stream.resume_download_to(&path).await?;
+
+    assert_eq!(written, total);
+    assert_eq!(std::fs::metadata(&path)?.len(), total);
+    assert!(!part.exists());
+
+    std::fs::remove_file(&path)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn projection() -> Result<(), Box<dyn std::error::Error>> {
+    let mut streams = Client::new()
+        .streams("-xNN-bJQ4vI".parse()?)
+        .await?
+        .filter_map(ytextract::Stream::into_video);
+
+    assert!(streams.any(|x| x.projection() != ytextract::stream::Projection::Rectangular));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn hdr_streams() -> Result<(), Box<dyn std::error::Error>> {
+    let mut streams = Client::new()
+        .streams("vX2vsvdq8nw".parse()?)
+        .await?
+        .filter_map(ytextract::Stream::into_video);
+
+    assert!(streams.any(|x| x.is_hdr()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn without_fallback() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::builder().stream_fallback(false).build();
+    let mut streams = client.streams("9bZkp7q19f0".parse()?).await?;
+
+    assert!(streams.next().is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn refresh() -> Result<(), Box<dyn std::error::Error>> {
+    let id = "9bZkp7q19f0".parse()?;
+    let stream = Client::new().streams(id).await?.next().expect("No stream");
+
+    let refreshed = stream.refresh().await?;
+
+    assert_eq!(refreshed.video_id(), id);
+    assert_eq!(refreshed.itag(), stream.itag());
+    assert_eq!(refreshed.mime_type(), stream.mime_type());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn request_headers() -> Result<(), Box<dyn std::error::Error>> {
+    let streams = Client::new().streams("9bZkp7q19f0".parse()?).await?;
+
+    for stream in streams {
+        let headers = stream.request_headers();
+        let web = stream
+            .url()
+            .query_pairs()
+            .any(|(key, value)| key == "c" && value.starts_with("WEB"));
+
+        assert_eq!(headers.contains_key(reqwest::header::ORIGIN), web);
+        assert_eq!(headers.contains_key(reqwest::header::REFERER), web);
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "unstable")]
+mod parts {
+    use serde_json::json;
+    use ytextract::{Client, Video};
+
+    #[cfg(feature = "serde")]
+    use crate::common::round_trip;
+    use crate::common::{
+        next_response, parts, serve_api, serve_broken_stream, serve_response, serve_stream,
+        serve_unranged_stream, streams_response, streams_response_expiring, video, VIDEO,
+    };
+
+    /// A [`Video`] with the streams of the player response `response`.
+    fn with_streams(response: serde_json::Value) -> ytextract::Result<Video> {
+        video(response, next_response(VIDEO))
+    }
+
+    #[tokio::test]
+    async fn age_restricted_embedded() -> Result<(), Box<dyn std::error::Error>> {
+        let (url, requests) = serve_api(|_, body| {
+            if body["context"]["client"]["clientName"] == "ANDROID" {
+                json!({
+                    "playabilityStatus": {
+                        "status": "LOGIN_REQUIRED",
+                        "reason": "Sign in to confirm your age"
+                    }
+                })
+            } else {
+                streams_response()
+            }
+        });
+        let client = Client::builder()
+            .api_url(url)
+            .stream_fallback(false)
+            .build();
+
+        assert!(client.streams(VIDEO.parse()?).await?.next().is_some());
+
+        let requests: Vec<_> = requests.try_iter().collect();
+        assert_eq!(requests.len(), 2);
+        assert!(requests.iter().all(|(endpoint, _)| endpoint == "player"));
+
+        // Age-restricted videos are retried with the embedded player
+        assert_eq!(
+            requests[1].1["context"],
+            json!({
+                "client": {
+                    "hl": "en",
+                    "gl": "US",
+                    "clientName": "TVHTML5_SIMPLY_EMBEDDED_PLAYER",
+                    "clientVersion": "2.0",
+                    "clientScreen": "EMBED"
+                },
+                "thirdParty": { "embedUrl": "https://www.youtube.com/" }
+            })
+        );
+        assert_eq!(requests[1].1["videoId"], VIDEO);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn audio_tracks() -> Result<(), Box<dyn std::error::Error>> {
+        use ytextract::stream::StreamsExt;
+
+        let audio = |itag: u64, id: &str, name: &str, default: bool| {
+            json!({
+                "itag": itag,
+                "url": format!("https://rr1---sn-abcdefgh.googlevideo.com/videoplayback?itag={}", itag),
+                "mimeType": "audio/webm; codecs=\"opus\"",
+                "bitrate": 160000,
+                "audioQuality": "AUDIO_QUALITY_MEDIUM",
+                "audioSampleRate": "48000",
+                "audioChannels": 2,
+                "audioTrack": { "id": id, "displayName": name, "audioIsDefault": default }
+            })
+        };
+
+        let mut response = streams_response();
+        response["streamingData"]["adaptiveFormats"] = json!([
+            response["streamingData"]["adaptiveFormats"][0].clone(),
+            audio(251, "en-US.4", "English (United States) original", true),
+            audio(251, "hi.3", "Hindi", false),
+            audio(251, "pt-BR.3", "Portuguese (Brazil)", false),
+        ]);
+        let video = with_streams(response)?;
+
+        let tracks: Vec<_> = video
+            .streams()
+            .await?
+            .filter_map(|x| match x {
+                ytextract::Stream::Audio(audio) => audio
+                    .track()
+                    .map(|x| (x.language().to_string(), x.is_default())),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            tracks,
+            [
+                ("en-US".to_string(), true),
+                ("hi".to_string(), false),
+                ("pt-BR".to_string(), false)
+            ]
+        );
+
+        let streams: Vec<_> = video.streams().await?.audio_language("hi").collect();
+        assert_eq!(streams.len(), 2);
+        assert!(streams[0].is_video());
+        match &streams[1] {
+            ytextract::Stream::Audio(audio) => {
+                assert_eq!(audio.track().map(|x| x.display_name()), Some("Hindi"))
+            }
+            _ => panic!("Expected a audio stream"),
+        }
+
+        // The region is optional
+        assert_eq!(video.streams().await?.audio_language("en").count(), 2);
+        assert_eq!(video.streams().await?.audio_language("en-us").count(), 2);
+        assert_eq!(video.streams().await?.audio_language("de").count(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn muxed() -> Result<(), Box<dyn std::error::Error>> {
+        let mut response = streams_response();
+        response["streamingData"]["formats"] = json!([
+            {
+                "itag": 18,
+                "url": "https://rr1---sn-abcdefgh.googlevideo.com/videoplayback?itag=18",
+                "mimeType": "video/mp4; codecs=\"avc1.42001E, mp4a.40.2\"",
+                "bitrate": 500000,
+                "width": 640,
+                "height": 360,
+                "fps": 30,
+                "qualityLabel": "360p",
+                "audioQuality": "AUDIO_QUALITY_LOW",
+                "audioSampleRate": "44100",
+                "audioChannels": 2
+            }
+        ]);
+        let video = with_streams(response)?;
+
+        let streams: Vec<_> = video.streams().await?.collect();
+        assert_eq!(streams.len(), 3);
+        assert!(streams[1].is_video());
+        assert!(streams[2].is_audio());
+
+        assert_eq!(streams[0].itag(), 18);
+        assert_eq!(streams[0].video_id(), VIDEO.parse()?);
+
+        let best = streams
+            .iter()
+            .cloned()
+            .filter_map(ytextract::Stream::into_video)
+            .max_by_key(|x| x.quality())
+            .expect("No video stream");
+        assert_eq!(best.quality(), ytextract::stream::VideoQuality::P1080);
+        assert_eq!(best.quality_label(), "1080p");
+        assert!(streams[0].clone().into_video().is_none());
+        assert!(streams[2].clone().into_audio().is_some());
+        assert_eq!(streams[0].container(), ytextract::stream::Container::Mp4);
+        assert_eq!(
+            streams[0].codecs(),
+            [
+                ytextract::stream::Codec::Avc1,
+                ytextract::stream::Codec::Mp4a
+            ]
+        );
+
+        match &streams[0] {
+            ytextract::Stream::Muxed(muxed) => {
+                assert!(!streams[0].is_audio());
+                assert!(!streams[0].is_video());
+                assert!(streams[0].is_muxed());
+                assert_eq!((muxed.width(), muxed.height(), muxed.fps()), (640, 360, 30));
+                assert_eq!((muxed.sample_rate(), muxed.channels()), (44100, 2));
+            }
+            _ => panic!("Expected a muxed stream"),
+        }
+
+        #[cfg(feature = "serde")]
+        assert!(matches!(
+            streams[0].snapshot().kind,
+            ytextract::snapshot::StreamKind::Muxed { width: 640, .. }
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn audio_quality() -> Result<(), Box<dyn std::error::Error>> {
+        use ytextract::stream::{AudioQuality, StreamsExt};
+
+        let video = with_streams(streams_response())?;
+
+        let audio = video
+            .streams()
+            .await?
+            .best_audio()
+            .expect("No audio stream");
+        assert_eq!(audio.quality(), AudioQuality::Medium);
+        assert_eq!(audio.loudness_db(), Some(-2.5));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn projection() -> Result<(), Box<dyn std::error::Error>> {
+        use ytextract::stream::{Projection, StereoLayout};
+
+        let format = |projection: Option<&str>, layout: Option<&str>| {
+            let mut format = streams_response()["streamingData"]["adaptiveFormats"][0].clone();
+            if let Some(projection) = projection {
+                format["projectionType"] = projection.into();
+            }
+            if let Some(layout) = layout {
+                format["stereoLayout"] = layout.into();
+            }
+            format
+        };
+
+        let mut response = streams_response();
+        response["streamingData"]["adaptiveFormats"] = json!([
+            format(None, None),
+            format(Some("RECTANGULAR"), None),
+            format(Some("EQUIRECTANGULAR"), Some("STEREO_LAYOUT_TOP_BOTTOM")),
+            format(Some("EQUIRECTANGULAR_THREED_TOP_BOTTOM"), None),
+            format(Some("MESH"), Some("STEREO_LAYOUT_LEFT_RIGHT")),
+            format(Some("CUBEMAP"), Some("STEREO_LAYOUT_MONO")),
+        ]);
+        let video = with_streams(response)?;
+
+        let layouts: Vec<_> = video
+            .streams()
+            .await?
+            .filter_map(ytextract::Stream::into_video)
+            .map(|x| (x.projection(), x.stereo_layout()))
+            .collect();
+        assert_eq!(
+            layouts,
+            [
+                (Projection::Rectangular, None),
+                (Projection::Rectangular, None),
+                (Projection::Equirectangular, Some(StereoLayout::TopBottom)),
+                (Projection::Equirectangular, Some(StereoLayout::TopBottom)),
+                (Projection::Mesh, Some(StereoLayout::LeftRight)),
+                (Projection::Other("CUBEMAP".to_string()), None),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn hdr() -> Result<(), Box<dyn std::error::Error>> {
+        use ytextract::stream::StreamsExt;
+
+        let mut sdr = streams_response()["streamingData"]["adaptiveFormats"][0].clone();
+        sdr["colorInfo"] = json!({
+            "primaries": "COLOR_PRIMARIES_BT709",
+            "transferCharacteristics": "COLOR_TRANSFER_CHARACTERISTICS_BT709",
+            "matrixCoefficients": "COLOR_MATRIX_COEFFICIENTS_BT709"
+        });
+        let mut hdr = sdr.clone();
+        hdr["itag"] = 337.into();
+        hdr["height"] = 2160.into();
+        hdr["qualityLabel"] = "2160p60 HDR".into();
+        hdr["colorInfo"] = json!({
+            "primaries": "COLOR_PRIMARIES_BT2020",
+            "transferCharacteristics": "COLOR_TRANSFER_CHARACTERISTICS_SMPTEST2084",
+            "matrixCoefficients": "COLOR_MATRIX_COEFFICIENTS_BT2020_NCL"
+        });
+        let mut hlg = hdr.clone();
+        hlg["itag"] = 338.into();
+        hlg["colorInfo"] =
+            json!({ "transferCharacteristics": "COLOR_TRANSFER_CHARACTERISTICS_ARIB_STD_B67" });
+
+        let mut response = streams_response();
+        response["streamingData"]["adaptiveFormats"] = json!([sdr, hdr, hlg]);
+        let video = with_streams(response)?;
+
+        let streams: Vec<_> = video
+            .streams()
+            .await?
+            .filter_map(ytextract::Stream::into_video)
+            .collect();
+        assert!(!streams[0].is_hdr());
+        assert!(streams[1].is_hdr());
+        assert!(streams[2].is_hdr());
+        assert_eq!(
+            streams[1].color_info().and_then(|x| x.primaries()),
+            Some("COLOR_PRIMARIES_BT2020")
+        );
+        assert_eq!(streams[2].color_info().and_then(|x| x.primaries()), None);
+
+        let video = &video;
+        let best = |hdr| async move {
+            Ok::<_, ytextract::Error>(
+                video
+                    .streams()
+                    .await?
+                    .select()
+                    .prefer_hdr(hdr)
+                    .best()
+                    .map(|x| x.itag()),
+            )
+        };
+        assert_eq!(best(false).await?, Some(137));
+        assert!(matches!(best(true).await?, Some(337) | Some(338)));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn selection() -> Result<(), Box<dyn std::error::Error>> {
+        use ytextract::stream::{Codec, Container, StreamsExt};
+
+        let video = |itag: u64, mime: &str, height: u64, fps: u64, bitrate: u64| {
+            json!({
+                "itag": itag,
+                "url": format!("https://rr1---sn-abcdefgh.googlevideo.com/videoplayback?itag={}", itag),
+                "mimeType": mime,
+                "bitrate": bitrate,
+                "width": height * 16 / 9,
+                "height": height,
+                "fps": fps,
+                "qualityLabel": format!("{}p", height),
+            })
+        };
+        let audio = |itag: u64, mime: &str, bitrate: u64| {
+            json!({
+                "itag": itag,
+                "url": format!("https://rr1---sn-abcdefgh.googlevideo.com/videoplayback?itag={}", itag),
+                "mimeType": mime,
+                "bitrate": bitrate,
+                "audioQuality": "AUDIO_QUALITY_MEDIUM",
+                "audioSampleRate": "48000",
+                "audioChannels": 2
+            })
+        };
+        let mp4 = "video/mp4; codecs=\"avc1.640028\"";
+        let webm = "video/webm; codecs=\"vp9\"";
+
+        let mut response = streams_response();
+        response["streamingData"]["adaptiveFormats"] = json!([
+            video(137, mp4, 1080, 30, 4_000_000),
+            video(248, webm, 1080, 30, 3_000_000),
+            video(303, webm, 1080, 60, 5_000_000),
+            video(271, webm, 1440, 30, 9_000_000),
+            video(136, mp4, 720, 30, 2_000_000),
+            audio(140, "audio/mp4; codecs=\"mp4a.40.2\"", 128_000),
+            audio(251, "audio/webm; codecs=\"opus\"", 160_000),
+            audio(249, "audio/webm; codecs=\"opus\"", 50_000),
+        ]);
+        let video = with_streams(response)?;
+
+        let itag = |x: Option<ytextract::Stream>| x.map(|x| x.itag());
+
+        assert_eq!(
+            video.streams().await?.best_video().map(|x| x.itag()),
+            Some(271)
+        );
+        assert_eq!(
+            video.streams().await?.best_audio().map(|x| x.itag()),
+            Some(251)
+        );
+        assert_eq!(
+            video.streams().await?.worst_audio().map(|x| x.itag()),
+            Some(249)
+        );
+
+        // The higher frame rate wins over the higher bitrate
+        assert_eq!(
+            itag(video.streams().await?.select().max_height(1080).best()),
+            Some(303)
+        );
+        assert_eq!(
+            itag(
+                video
+                    .streams()
+                    .await?
+                    .select()
+                    .container(Container::Mp4)
+                    .best()
+            ),
+            Some(137)
+        );
+        // A preferred codec wins over a better stream
+        assert_eq!(
+            itag(
+                video
+                    .streams()
+                    .await?
+                    .select()
+                    .prefer_codec(Codec::Avc1)
+                    .best()
+            ),
+            Some(137)
+        );
+        assert_eq!(
+            itag(
+                video
+                    .streams()
+                    .await?
+                    .filter(|x| x.is_audio())
+                    .select()
+                    .container(Container::WebM)
+                    .best()
+            ),
+            Some(251)
+        );
+        assert_eq!(
+            itag(
+                video
+                    .streams()
+                    .await?
+                    .select()
+                    .container(Container::ThreeGp)
+                    .best()
+            ),
+            None
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn byte_ranges() -> Result<(), Box<dyn std::error::Error>> {
+        use ytextract::stream::ByteRange;
+
+        let video = with_streams(streams_response())?;
+        let streams: Vec<_> = video.streams().await?.collect();
+
+        let init = ByteRange { start: 0, end: 740 };
+        assert_eq!(streams[0].init_range(), Some(init));
+        assert_eq!(init.range(), 0..741);
+        assert_eq!(
+            streams[0].index_range(),
+            Some(ByteRange {
+                start: 741,
+                end: 3992
+            })
+        );
+        assert_eq!(streams[0].average_bitrate(), Some(2_500_000));
+
+        assert_eq!(streams[1].init_range(), None);
+        assert_eq!(streams[1].index_range(), None);
+        assert_eq!(streams[1].average_bitrate(), None);
+
+        Ok(())
+    }
+
+    /// A [`Video`] with a single OTF audio stream of `len` bytes served on `port`.
+    fn served_video(port: u16, len: usize) -> Result<Video, Box<dyn std::error::Error>> {
+        video_of(served_response(port, len))
+    }
+
+    /// The player response of [`served_video`].
+    fn served_response(port: u16, len: usize) -> serde_json::Value {
+        let mut response = streams_response();
+        response["streamingData"]["adaptiveFormats"] = json!([{
+            "itag": 251,
+            "url": format!("http://127.0.0.1:{}/videoplayback?expire=4102444800&itag=251", port),
+            "mimeType": "audio/webm; codecs=\"opus\"",
+            "bitrate": 160000,
+            "contentLength": len.to_string(),
+            "type": "FORMAT_STREAM_TYPE_OTF",
+            "audioQuality": "AUDIO_QUALITY_MEDIUM",
+            "audioSampleRate": "48000",
+            "audioChannels": 2
+        }]);
+        response
+    }
+
+    /// A [`Video`] with the streams of `response` that only requests the
+    /// streams themselves, without the check for 403s.
+    fn video_of(response: serde_json::Value) -> Result<Video, Box<dyn std::error::Error>> {
+        let parts = parts(response, next_response(VIDEO));
+        let client = Client::builder().stream_fallback(false).build();
+        Ok(Video::from_parts(parts, client)?)
+    }
+
+    #[tokio::test]
+    async fn content_length_head() -> Result<(), Box<dyn std::error::Error>> {
+        let unknown_length = |port| {
+            let mut response = served_response(port, 0);
+            response["streamingData"]["adaptiveFormats"][0]
+                .as_object_mut()
+                .unwrap()
+                .remove("contentLength");
+            video_of(response)
+        };
+
+        let video = unknown_length(serve_response(
+            "HTTP/1.1 200 OK\r\nContent-Length: 10\r\nConnection: close\r\n\r\n",
+        ))?;
+        let stream = video.streams().await?.next().expect("No stream");
+        assert_eq!(stream.content_length().await?, 10);
+
+        let video = unknown_length(serve_response(
+            "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\nConnection: close\r\n\r\n",
+        ))?;
+        let stream = video.streams().await?.next().expect("No stream");
+        assert!(matches!(
+            stream.content_length().await,
+            Err(ytextract::Error::Stream(
+                ytextract::stream::Error::UnknownContentLength
+            ))
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn chunked() -> Result<(), Box<dyn std::error::Error>> {
+        use futures::StreamExt;
+
+        const BODY: &[u8] = b"0123456789";
+        let chunked = |port| served_video(port, BODY.len());
+
+        let (port, requests) = serve_stream(BODY, true);
+        let video = chunked(port)?;
+        let stream = video.streams().await?.next().expect("No stream");
+        assert!(stream.request_headers().contains_key("range"));
+
+        let chunks = stream
+            .get_chunked(4)
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // The first chunk ended early, its missing bytes are requested next
+        assert_eq!(
+            chunks.iter().map(|x| x.len()).collect::<Vec<_>>(),
+            [2, 4, 4]
+        );
+        assert_eq!(chunks.concat(), BODY);
+
+        let requests: Vec<_> = requests.try_iter().collect();
+        assert_eq!(requests.len(), 3);
+        for request in &requests {
+            // The `range` parameter is not combined with a `Range` header
+            assert!(request.contains("range="));
+            assert!(!request.to_lowercase().contains("\r\nrange:"));
+        }
+
+        // A chunk size of 0 does not panic
+        let (port, _) = serve_stream(BODY, false);
+        let video = chunked(port)?;
+        let stream = video.streams().await?.next().expect("No stream");
+        let chunks = stream
+            .get_chunked(0)
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(chunks.concat(), BODY);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn download_interrupted() -> Result<(), Box<dyn std::error::Error>> {
+        use ytextract::stream::DownloadOptions;
+
+        const BODY: &[u8] = b"0123456789";
+
+        for keep_partial in [false, true] {
+            let video = served_video(serve_broken_stream(BODY), BODY.len())?;
+            let stream = video.streams().await?.next().expect("No stream");
+
+            let path = std::env::temp_dir().join(format!(
+                "ytextract-interrupted-test-{}-{}",
+                keep_partial,
+                std::process::id()
+            ));
+            let part = std::path::PathBuf::from(format!("{}.part", path.display()));
+            let meta = std::path::PathBuf::from(format!("{}.part.meta", path.display()));
+            let _ = std::fs::remove_file(&part);
+
+            let options = DownloadOptions::new().keep_partial(keep_partial);
+            assert!(stream.download(&path, &options).await.is_err());
+
+            assert!(!path.exists());
+            if keep_partial {
+                // The bytes received before the connection broke are kept
+                assert_eq!(std::fs::read(&part)?, &BODY[..BODY.len() / 2]);
+                assert!(meta.exists());
+
+                // and resumed by the next download of the same stream
+                let (port, requests) = serve_stream(BODY, false);
+                let video = served_video(port, BODY.len())?;
+                let stream = video.streams().await?.next().expect("No stream");
+                assert_eq!(stream.resume_download_to(&path).await?, BODY.len() as u64);
+                assert_eq!(std::fs::read(&path)?, BODY);
+                assert!(requests.recv()?.contains("range=5-9"));
+
+                std::fs::remove_file(&path)?;
+            } else {
+                assert!(!part.exists());
+            }
+            assert!(!part.exists() && !meta.exists());
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn download_stale_part() -> Result<(), Box<dyn std::error::Error>> {
+        const BODY: &[u8] = b"0123456789";
+
+        let path =
+            std::env::temp_dir().join(format!("ytextract-stale-part-test-{}", std::process::id()));
+        let part = std::path::PathBuf::from(format!("{}.part", path.display()));
+        let meta = std::path::PathBuf::from(format!("{}.part.meta", path.display()));
+
+        // A partial download of another stream, and one of an unknown stream
+        for stale in [Some("dQw4w9WgXcQ 140 10"), None] {
+            std::fs::write(&part, "abcde")?;
+            match stale {
+                Some(stale) => std::fs::write(&meta, stale)?,
+                None => {
+                    let _ = std::fs::remove_file(&meta);
+                }
+            }
+
+            let (port, requests) = serve_stream(BODY, false);
+            let video = served_video(port, BODY.len())?;
+            let stream = video.streams().await?.next().expect("No stream");
+            assert_eq!(stream.resume_download_to(&path).await?, BODY.len() as u64);
+
+            // is not appended to
+            assert_eq!(std::fs::read(&path)?, BODY);
+            assert!(requests.recv()?.contains("range=0-9"));
+            assert!(!part.exists() && !meta.exists());
+            std::fs::remove_file(&path)?;
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn range_ignored() -> Result<(), Box<dyn std::error::Error>> {
+        use futures::StreamExt;
+
+        // The whole body is sent for the range of its first half
+        const BODY: &[u8] = b"0123456789";
+        let video = served_video(serve_unranged_stream(BODY), BODY.len() / 2)?;
+        let stream = video.streams().await?.next().expect("No stream");
+
+        fn exceeded<T>(result: ytextract::Result<T>) -> bool {
+            matches!(
+                result,
+                Err(ytextract::Error::Stream(
+                    ytextract::stream::Error::RangeExceeded { expected: 5 }
+                ))
+            )
+        }
+
+        let chunks: Vec<_> = stream.get_chunked(5).collect().await;
+        assert_eq!(chunks.len(), 1);
+        assert!(exceeded(chunks.into_iter().next().unwrap()));
+
+        let path = std::env::temp_dir().join(format!(
+            "ytextract-range-ignored-test-{}",
+            std::process::id()
+        ));
+        assert!(exceeded(stream.download_to(&path).await));
+        assert!(!path.exists());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn request_headers() -> Result<(), Box<dyn std::error::Error>> {
+        use futures::StreamExt;
+        use reqwest::header::{ORIGIN, RANGE, REFERER};
+
+        const BODY: &[u8] = b"0123456789";
+
+        let (port, requests) = serve_stream(BODY, false);
+        let format = |itag: u32, client: &str, kind: &str| {
+            json!({
+                "itag": itag,
+                "url": format!(
+                    "http://127.0.0.1:{}/videoplayback?expire=4102444800&itag={}&c={}",
+                    port, itag, client
+                ),
+                "mimeType": "audio/webm; codecs=\"opus\"",
+                "bitrate": 160000,
+                "contentLength": BODY.len().to_string(),
+                "type": kind,
+                "audioQuality": "AUDIO_QUALITY_MEDIUM",
+                "audioSampleRate": "48000",
+                "audioChannels": 2
+            })
+        };
+        let mut response = streams_response();
+        response["streamingData"]["adaptiveFormats"] = json!([
+            format(251, "WEB", "FORMAT_STREAM_TYPE_OTF"),
+            format(250, "ANDROID", "FORMAT_STREAM_TYPE_DEFAULT"),
+        ]);
+        let parts = parts(response, next_response(VIDEO));
+        let client = Client::builder().stream_fallback(false).build();
+        let video = Video::from_parts(parts, client)?;
+        let streams: Vec<_> = video.streams().await?.collect();
+
+        let otf = streams
+            .iter()
+            .find(|x| x.itag() == 251)
+            .expect("No OTF stream");
+        let headers = otf.request_headers();
+        assert_eq!(headers[RANGE], "bytes=0-");
+        assert_eq!(headers[ORIGIN], "https://www.youtube.com");
+        assert_eq!(headers[REFERER], "https://www.youtube.com");
+
+        let plain = streams.iter().find(|x| x.itag() == 250).expect("No stream");
+        assert!(plain.request_headers().is_empty());
+
+        // The headers are sent with the requests of a stream
+        let bytes = otf
+            .get()
+            .await?
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(bytes.concat(), BODY);
+
+        let request = requests.try_recv()?.to_lowercase();
+        assert!(request.contains("\r\nrange: bytes=0-\r\n"));
+        assert!(request.contains("\r\norigin: https://www.youtube.com\r\n"));
+        assert!(request.contains("\r\nreferer: https://www.youtube.com\r\n"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fallback() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::{BufRead, Write};
+        use ytextract::video::IdMismatch;
+
+        // Answers every request with a 403, like expired ANDROID urls
+        let forbidden = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let forbidden_port = forbidden.local_addr()?.port();
+        std::thread::spawn(move || {
+            for stream in forbidden.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = std::io::BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                while reader.read_line(&mut request).unwrap() > 2 {}
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 403 Forbidden\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                );
+            }
+        });
+        let (working_port, _) = serve_stream(b"0123456789", false);
+
+        let with_port = |response: &mut serde_json::Value, port: u16| {
+            response["streamingData"]["adaptiveFormats"][0]["url"] = json!(format!(
+                "http://127.0.0.1:{}/videoplayback?expire=4102444800&itag=251",
+                port
+            ));
+        };
+        let streams = |policy, returned: &'static str| async move {
+            let (url, requests) = serve_api(move |_, _| {
+                let mut response = streams_response();
+                with_port(&mut response, working_port);
+                response["videoDetails"]["videoId"] = json!(returned);
+                response
+            });
+            let client = Client::builder().api_url(url).id_mismatch(policy).build();
+
+            let mut response = streams_response();
+            with_port(&mut response, forbidden_port);
+            let parts = parts(response, next_response(VIDEO));
+            let ports = Video::from_parts(parts, client)?
+                .streams()
+                .await?
+                .filter_map(|x| x.url().port())
+                .collect::<Vec<_>>();
+            Ok::<_, Box<dyn std::error::Error>>((ports, requests.try_iter().count()))
+        };
+
+        // The streams that came with the video are checked as well
+        let (ports, requests) = streams(IdMismatch::Error, VIDEO).await?;
+        assert_eq!(ports, [working_port]);
+        assert_eq!(requests, 1);
+
+        // The streams of a different video are not used in their place
+        let (ports, requests) = streams(IdMismatch::Error, "9bZkp7q19f0").await?;
+        assert_eq!(ports, [forbidden_port]);
+        assert_eq!(requests, 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn expiry() -> Result<(), Box<dyn std::error::Error>> {
+        let expired = |result: ytextract::Result<()>| {
+            matches!(
+                result,
+                Err(ytextract::Error::Stream(ytextract::stream::Error::Expired(
+                    _
+                )))
+            )
+        };
+
+        let (response, _) = streams_response_expiring();
+        let video = with_streams(response)?;
+        let stream = video.streams().await?.next().expect("No stream");
+        std::thread::sleep(std::time::Duration::from_secs(2));
+
+        assert!(stream.is_expired());
+        assert!(expired(stream.content_length().await.map(|_| ())));
+        assert!(expired(stream.get().await.map(|_| ())));
+        assert!(expired(stream.get_range(0..10).await.map(|_| ())));
+
+        // Expires in 2100
+        let video = with_streams(streams_response())?;
+        let stream = video.streams().await?.next().expect("No stream");
+
+        assert!(!stream.is_expired());
+        assert_eq!(stream.content_length().await?, 123_456);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn expired_parts() -> Result<(), Box<dyn std::error::Error>> {
+        // Responses that were fetched long ago carry expired urls
+        let response = streams_response()
+            .to_string()
+            .replace("expire=4102444800", "expire=1624406400");
+        let video = with_streams(serde_json::from_str(&response)?)?;
+
+        // They are requested again instead of being reused
+        if let Ok(mut streams) = video.streams().await {
+            assert!(streams.all(|x| !x.is_expired()));
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resume_expired() -> Result<(), Box<dyn std::error::Error>> {
+        let (response, timestamp) = streams_response_expiring();
+        let video = with_streams(response)?;
+        let stream = video.streams().await?.next().expect("No stream");
+        std::thread::sleep(std::time::Duration::from_secs(2));
+
+        let path = std::env::temp_dir().join("ytextract-resume-expired-test");
+        let result = stream.resume_download_to(&path).await;
+
+        assert!(matches!(
+            result,
+            Err(ytextract::Error::Stream(ytextract::stream::Error::Expired(expire))) if expire.timestamp() == timestamp
+        ));
+        assert!(!path.exists());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn signature_cipher() -> Result<(), Box<dyn std::error::Error>> {
+        let mut response = streams_response();
+        response["streamingData"]["adaptiveFormats"]
+            .as_array_mut()
+            .unwrap()
+            .push(json!({
+                "itag": 140,
+                "signatureCipher": "s=AAAA&sp=sig&url=https%3A%2F%2Frr1---sn-abcdefgh.googlevideo.com%2Fvideoplayback%3Fitag%3D140",
+                "mimeType": "audio/mp4; codecs=\"mp4a.40.2\"",
+                "bitrate": 130000,
+                "audioQuality": "AUDIO_QUALITY_MEDIUM",
+                "audioSampleRate": "44100",
+                "audioChannels": 2
+            }));
+        let video = with_streams(response)?;
+
+        // Ciphered formats are skipped, the others are still available
+        assert_eq!(video.streams().await?.count(), 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn malformed_format() -> Result<(), Box<dyn std::error::Error>> {
+        let mut response = streams_response();
+        let formats = response["streamingData"]["adaptiveFormats"]
+            .as_array_mut()
+            .unwrap();
+        // Neither audio nor video
+        formats.push(json!({
+            "itag": 999,
+            "url": "https://rr1---sn-abcdefgh.googlevideo.com/videoplayback?itag=999",
+            "mimeType": "text/plain",
+            "bitrate": 1
+        }));
+        // Missing its mime type
+        formats.push(json!({
+            "itag": 140,
+            "url": "https://rr1---sn-abcdefgh.googlevideo.com/videoplayback?itag=140",
+            "bitrate": 130000
+        }));
+        let video = with_streams(response)?;
+
+        // Malformed formats are skipped, the others are still available
+        let itags: Vec<_> = video.streams().await?.map(|x| x.itag()).collect();
+        assert_eq!(itags, [137, 251]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn url_params() -> Result<(), Box<dyn std::error::Error>> {
+        let video = with_streams(streams_response())?;
+        let streams: Vec<_> = video.streams().await?.collect();
+        assert_eq!(streams.len(), 2);
+
+        let expire = chrono::DateTime::from_timestamp(4_102_444_800, 0);
+
+        let video = &streams[0];
+        assert!(video
+            .url_params()
+            .any(|(key, value)| key == "itag" && value == "137"));
+        assert_eq!(video.expires_at(), expire);
+        assert_eq!(video.clen(), Some(123_456));
+        assert_eq!(video.mime().as_deref(), Some("video/mp4"));
+        assert_eq!(video.source().as_deref(), Some("youtube"));
+        assert!(!video.ratebypass());
+
+        // A mismatching `clen` is only logged
+        let audio = &streams[1];
+        assert_eq!(audio.expires_at(), expire);
+        assert_eq!(audio.clen(), Some(654_321));
+        assert_eq!(audio.mime().as_deref(), Some("audio/webm"));
+        assert!(audio.ratebypass());
+
+        #[cfg(feature = "serde")]
+        for stream in &streams {
+            round_trip(stream.snapshot())?;
+        }
+
+        Ok(())
+    }
+}
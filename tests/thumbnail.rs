@@ -0,0 +1,185 @@
+use ytextract::{video::ThumbnailQuality, Client, Thumbnail};
+
+fn thumbnail(url: &str) -> Thumbnail {
+    Thumbnail {
+        url: url.parse().unwrap(),
+        width: 120,
+        height: 90,
+    }
+}
+
+#[test]
+fn placeholder() {
+    for url in [
+        "https://i.ytimg.com/img/no_thumbnail.jpg",
+        "https://s.ytimg.com/yts/img/no_thumbnail-vfl4t3-4R.jpg",
+        "https://i.ytimg.com/vi//default.jpg",
+        "https://img.youtube.com/vi/default.jpg",
+        "https://img.youtube.com/vi/7B2PIVSWtJA/default.jpg",
+    ] {
+        assert!(thumbnail(url).is_placeholder(), "{}", url);
+    }
+}
+
+#[test]
+fn not_placeholder() {
+    for url in [
+        "https://i.ytimg.com/vi/7B2PIVSWtJA/default.jpg",
+        "https://i.ytimg.com/vi/7B2PIVSWtJA/hqdefault.jpg?sqp=-oaymwEcCNACELwBSFXyq4qpAw4IARUAAIhCGAFwAcABBg==",
+        "https://yt3.ggpht.com/ytc/AKedOLQ=s88-c-k-c0x00ffffff-no-rj",
+    ] {
+        assert!(!thumbnail(url).is_placeholder(), "{}", url);
+    }
+}
+
+#[test]
+fn from_id() -> Result<(), Box<dyn std::error::Error>> {
+    let id: ytextract::video::Id = "7B2PIVSWtJA".parse()?;
+
+    assert_eq!(
+        id.thumbnail(ThumbnailQuality::MaxRes).as_str(),
+        "https://i.ytimg.com/vi/7B2PIVSWtJA/maxresdefault.jpg"
+    );
+
+    let thumbnails = id.thumbnails();
+    assert_eq!(thumbnails.len(), 5);
+    assert_eq!(
+        thumbnails[0],
+        Thumbnail {
+            url: "https://i.ytimg.com/vi/7B2PIVSWtJA/default.jpg".parse()?,
+            width: 120,
+            height: 90,
+        }
+    );
+    assert_eq!(
+        thumbnails[2].url.as_str(),
+        "https://i.ytimg.com/vi/7B2PIVSWtJA/hqdefault.jpg"
+    );
+    assert!(thumbnails.windows(2).all(|x| x[0].width < x[1].width));
+    assert!(thumbnails.iter().all(|x| !x.is_placeholder()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exists() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::new();
+    let id: ytextract::video::Id = "7B2PIVSWtJA".parse()?;
+
+    for thumbnail in id.thumbnails() {
+        assert!(thumbnail.exists(&client).await?, "{}", thumbnail.url);
+    }
+
+    let missing = thumbnail("https://i.ytimg.com/vi/aaaaaaaaaaa/hqdefault.jpg");
+    assert!(!missing.exists(&client).await?);
+
+    Ok(())
+}
+
+fn sized(url: &str, width: u64, height: u64) -> Thumbnail {
+    Thumbnail {
+        url: url.parse().unwrap(),
+        width,
+        height,
+    }
+}
+
+#[test]
+fn equality() {
+    let small = sized(
+        "https://yt3.ggpht.com/ytc/AKedOLQ=s88-c-k-c0x00ffffff-no-rj",
+        88,
+        88,
+    );
+    let large = sized(
+        "https://yt3.ggpht.com/ytc/AKedOLQ=s176-c-k-c0x00ffffff-no-rj",
+        176,
+        176,
+    );
+
+    // Different sizes are different thumbnails of the same image
+    assert_ne!(small, large);
+    assert!(small.same_image(&large));
+    assert_eq!(small, small.clone());
+    assert!(
+        sized("https://yt3.googleusercontent.com/abc=s900-c-k", 900, 900).same_image(&sized(
+            "https://yt3.googleusercontent.com/abc=s48-c-k",
+            48,
+            48
+        ))
+    );
+
+    assert!(
+        !sized("https://yt3.ggpht.com/ytc/AKedOLQ=s88", 88, 88).same_image(&sized(
+            "https://yt3.ggpht.com/ytc/BBBBBBB=s88",
+            88,
+            88
+        ))
+    );
+    // Only the size suffix of ggpht urls is ignored
+    assert!(
+        !thumbnail("https://i.ytimg.com/vi/7B2PIVSWtJA/hqdefault.jpg?sqp=a").same_image(
+            &thumbnail("https://i.ytimg.com/vi/7B2PIVSWtJA/hqdefault.jpg?sqp=b")
+        )
+    );
+    assert!(
+        !thumbnail("https://i.ytimg.com/vi/7B2PIVSWtJA/default.jpg").same_image(&thumbnail(
+            "https://i.ytimg.com/vi/7B2PIVSWtJA/hqdefault.jpg"
+        ))
+    );
+}
+
+#[test]
+fn hash() {
+    let set: std::collections::HashSet<_> = vec![
+        sized("https://yt3.ggpht.com/ytc/AKedOLQ=s88-c-k", 88, 88),
+        sized("https://yt3.ggpht.com/ytc/AKedOLQ=s88-c-k", 88, 88),
+        sized("https://yt3.ggpht.com/ytc/AKedOLQ=s176-c-k", 176, 176),
+    ]
+    .into_iter()
+    .collect();
+
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn ordering() {
+    let small = sized("https://i.ytimg.com/vi/7B2PIVSWtJA/default.jpg", 120, 90);
+    let large = sized("https://i.ytimg.com/vi/7B2PIVSWtJA/hqdefault.jpg", 480, 360);
+    assert!(small < large);
+
+    // Same area, the wider one is larger
+    let tall = sized("https://i.ytimg.com/a.jpg", 90, 160);
+    let wide = sized("https://i.ytimg.com/b.jpg", 160, 90);
+    assert!(tall < wide);
+
+    // Same size, ordered by url to stay consistent with equality
+    let a = sized("https://i.ytimg.com/a.jpg", 160, 90);
+    let b = sized("https://i.ytimg.com/b.jpg", 160, 90);
+    assert!(a < b);
+    assert_eq!(a.cmp(&a.clone()), std::cmp::Ordering::Equal);
+
+    // Same width and area, but a different height
+    let empty = sized("https://i.ytimg.com/a.jpg", 0, 90);
+    let flat = sized("https://i.ytimg.com/a.jpg", 0, 0);
+    assert_ne!(empty, flat);
+    assert!(flat < empty);
+}
+
+#[test]
+fn dedup() {
+    let thumbnails = ytextract::dedup_thumbnails(vec![
+        sized("https://yt3.ggpht.com/ytc/AKedOLQ=s176-c-k", 176, 176),
+        sized("https://yt3.ggpht.com/ytc/AKedOLQ=s88-c-k", 88, 88),
+        sized("https://yt3.ggpht.com/ytc/AKedOLQ=s48-c-k", 48, 48),
+        sized("https://yt3.ggpht.com/ytc/OTHER=s48-c-k", 48, 48),
+    ]);
+
+    assert_eq!(thumbnails.len(), 2);
+    assert_eq!(
+        thumbnails[0].url.as_str(),
+        "https://yt3.ggpht.com/ytc/OTHER=s48-c-k"
+    );
+    // The largest duplicate is kept
+    assert_eq!(thumbnails[1].width, 176);
+}
@@ -0,0 +1,80 @@
+use ytextract::types::{Badge, CaptionCue, PlaylistId, VideoId};
+
+#[test]
+fn serde() -> Result<(), Box<dyn std::error::Error>> {
+    let id: VideoId = "nI2e-J6fsuk".parse()?;
+    assert_eq!(serde_json::to_value(id)?, "nI2e-J6fsuk");
+    assert_eq!(serde_json::from_value::<VideoId>("nI2e-J6fsuk".into())?, id);
+
+    let id: PlaylistId = "PLCSusC_jlo14BH5hHnOh9b0O18HtGT3eP".parse()?;
+    assert_eq!(
+        serde_json::to_value(&id)?,
+        "PLCSusC_jlo14BH5hHnOh9b0O18HtGT3eP"
+    );
+    assert_eq!(
+        serde_json::from_value::<PlaylistId>(serde_json::to_value(&id)?)?,
+        id
+    );
+
+    let badge = serde_json::to_value(Badge::Verified)?;
+    assert_eq!(serde_json::from_value::<Badge>(badge)?, Badge::Verified);
+
+    let unknown = Badge::Unknown(String::from("BADGE_STYLE_TYPE_MEMBERS_ONLY"));
+    let badge = serde_json::to_value(&unknown)?;
+    assert_eq!(serde_json::from_value::<Badge>(badge)?, unknown);
+
+    let cue = CaptionCue {
+        start: std::time::Duration::from_millis(1500),
+        duration: std::time::Duration::from_secs(2),
+        text: String::from("Hello"),
+    };
+    let json = serde_json::to_string(&cue)?;
+    assert_eq!(serde_json::from_str::<CaptionCue>(&json)?, cue);
+
+    Ok(())
+}
+
+#[cfg(feature = "schemars")]
+mod schema {
+    use schemars::schema_for;
+    use ytextract::types::{ChannelId, Counts, Thumbnail, VideoId};
+
+    #[test]
+    fn id() {
+        let schema = serde_json::to_value(schema_for!(VideoId)).unwrap();
+        assert_eq!(schema["type"], "string");
+        assert_eq!(schema["minLength"], 11);
+        assert_eq!(schema["maxLength"], 11);
+
+        let schema = serde_json::to_value(schema_for!(ChannelId)).unwrap();
+        assert_eq!(schema["minLength"], 24);
+    }
+
+    #[test]
+    fn thumbnail() {
+        let schema = serde_json::to_value(schema_for!(Thumbnail)).unwrap();
+        assert_eq!(schema["properties"]["url"]["type"], "string");
+        assert_eq!(schema["properties"]["width"]["type"], "integer");
+    }
+
+    #[test]
+    fn counts() {
+        let schema = serde_json::to_value(schema_for!(Counts)).unwrap();
+        let properties = schema["properties"].as_object().unwrap();
+
+        for field in [
+            "views",
+            "likes",
+            "comments",
+            "channel_subscribers",
+            "observed_at",
+        ] {
+            assert!(properties.contains_key(field), "Missing field {}", field);
+        }
+        assert_eq!(
+            schema["properties"]["views"]["description"],
+            "The amount of views, see [`Video::views`]."
+        );
+        assert_eq!(schema["properties"]["observed_at"]["format"], "date-time");
+    }
+}
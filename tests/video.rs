@@ -1,6 +1,9 @@
 use futures::StreamExt;
 use ytextract::Client;
 
+#[cfg(feature = "unstable")]
+mod common;
+
 #[tokio::test]
 async fn get() -> Result<(), Box<dyn std::error::Error>> {
     let video = Client::new()
@@ -13,7 +16,7 @@ async fn get() -> Result<(), Box<dyn std::error::Error>> {
     );
 
     assert_eq!(video.id(), "7B2PIVSWtJA".parse()?);
-    assert_eq!(video.duration(), std::time::Duration::from_secs(1358));
+    assert_eq!(video.duration(), Some(std::time::Duration::from_secs(1358)));
     assert_eq!(
         video.keywords(),
         &vec![
@@ -53,11 +56,20 @@ async fn get() -> Result<(), Box<dyn std::error::Error>> {
     assert_eq!(channel.id(), channel.upgrade().await?.id());
     assert!(!video.description().is_empty());
     assert!(video.views() >= 1_068_917);
-    //assert!(video.likes() >= Some(51_745));
+    assert!(video.likes() >= Some(51_745));
+    assert!(video.ratings_allowed());
+    assert!(!video.has_paid_promotion());
     assert!(!video.live());
+    assert!(!video.is_upcoming());
+    assert_eq!(video.family_safe(), Some(true));
+    assert!(!video.age_restricted());
+    assert_eq!(video.scheduled_start(), None);
+    assert_eq!(video.waiting_count(), None);
+    assert_eq!(video.live_status(), ytextract::video::LiveStatus::NotLive);
     assert!(!video.thumbnails().is_empty());
-    assert_eq!(video.date(), chrono::NaiveDate::from_ymd(2021, 4, 14));
+    assert_eq!(video.date(), chrono::NaiveDate::from_ymd_opt(2021, 4, 14));
     assert!(video.hashtags().next().is_none());
+    assert_eq!(video.category(), Some("Science & Technology"));
 
     let mut streams = video.streams().await?;
     assert!(streams.next().is_some());
@@ -85,10 +97,19 @@ async fn eq_channel() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[tokio::test]
+async fn location() -> Result<(), Box<dyn std::error::Error>> {
+    let video = Client::new().video("9bZkp7q19f0".parse()?).await?;
+    assert_eq!(video.location(), None);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn likes_not_allowed() -> Result<(), Box<dyn std::error::Error>> {
     let video = Client::new().video("9Jg_Fwc0QOY".parse()?).await?;
     assert_eq!(video.likes(), None);
+    assert!(!video.ratings_allowed());
 
     Ok(())
 }
@@ -134,12 +155,1533 @@ async fn related() -> Result<(), Box<dyn std::error::Error>> {
 
     let video = Client::new().video(id).await?;
 
-    video
+    let related = video
         .related()
         .expect("No related videos found")
         .take(100)
         .collect::<Vec<_>>()
-        .await;
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for item in &related {
+        if let ytextract::video::Related::Video(video) = item {
+            if video.is_live() {
+                assert_eq!(video.views(), None);
+                assert_eq!(video.published_text(), None);
+            }
+        }
+    }
+
+    let video = related
+        .iter()
+        .find_map(|x| match x {
+            ytextract::video::Related::Video(video) => Some(video),
+            _ => None,
+        })
+        .expect("No related video found");
+    let channel = video.channel();
+    assert!(channel.thumbnails().next().is_some());
+    assert_eq!(channel.id(), Some(channel.upgrade().await?.id()));
+
+    Ok(())
+}
+
+mod playlist_context {
+    use ytextract::Client;
+
+    #[tokio::test]
+    async fn none() -> Result<(), Box<dyn std::error::Error>> {
+        let video = Client::new().video("1_ozXudbN-4".parse()?).await?;
+        assert_eq!(video.playlist_context(), None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn api() -> Result<(), Box<dyn std::error::Error>> {
+        let playlist: ytextract::playlist::Id = "PLCSusC_jlo14F22jss8ZtDLbpmRQIVLzr".parse()?;
+        let video = Client::new()
+            .video_in_playlist("1_ozXudbN-4".parse()?, playlist.clone())
+            .await?;
+        assert_eq!(video.id(), "1_ozXudbN-4".parse()?);
+        assert_eq!(video.playlist_context(), Some(&playlist));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn url() -> Result<(), Box<dyn std::error::Error>> {
+        let video = Client::new()
+            .video_from_url(
+                "https://www.youtube.com/watch?v=1_ozXudbN-4&list=PLCSusC_jlo14F22jss8ZtDLbpmRQIVLzr",
+            )
+            .await?;
+        assert_eq!(video.id(), "1_ozXudbN-4".parse()?);
+        assert_eq!(
+            video.playlist_context(),
+            Some(&"PLCSusC_jlo14F22jss8ZtDLbpmRQIVLzr".parse()?)
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn url_without_list() -> Result<(), Box<dyn std::error::Error>> {
+        let video = Client::new()
+            .video_from_url("https://youtu.be/1_ozXudbN-4")
+            .await?;
+        assert_eq!(video.id(), "1_ozXudbN-4".parse()?);
+        assert_eq!(video.playlist_context(), None);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn embed() -> Result<(), Box<dyn std::error::Error>> {
+    let video = Client::new().video("7B2PIVSWtJA".parse()?).await?;
+
+    assert!(video.embeddable());
+    let embed = video.embed_info().expect("No embed info");
+    assert_eq!(
+        embed.url.as_str(),
+        "https://www.youtube.com/embed/7B2PIVSWtJA"
+    );
+    assert!(embed.width > 0 && embed.height > 0);
+    assert_eq!(
+        video.embed_html(640, 360).as_deref(),
+        Some(
+            r#"<iframe width="640" height="360" src="https://www.youtube.com/embed/7B2PIVSWtJA" frameborder="0" allowfullscreen></iframe>"#
+        )
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn embed_restricted() -> Result<(), Box<dyn std::error::Error>> {
+    let video = Client::new().video("MeJVWBSsPAY".parse()?).await?;
+
+    assert!(!video.embeddable());
+    assert!(video.embed_info().is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn concurrent() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::new();
+    let id = "7B2PIVSWtJA".parse()?;
+
+    let videos = futures::future::try_join_all((0..20).map(|_| {
+        let client = client.clone();
+        tokio::spawn(async move { client.video(id).await })
+    }))
+    .await?;
+
+    for video in videos {
+        assert_eq!(video?.id(), id);
+    }
+
+    Ok(())
+}
+
+mod rich_metadata {
+    use ytextract::{video::RichMetadataKind, Client};
+
+    #[tokio::test]
+    async fn game() -> Result<(), Box<dyn std::error::Error>> {
+        let video = Client::new().video("MmB9b5njVbA".parse()?).await?;
+        let game = video
+            .rich_metadata()
+            .into_iter()
+            .find(|x| x.kind == RichMetadataKind::Game)
+            .expect("No game found");
+        assert_eq!(game.title, "Minecraft");
+        assert!(game.browse_id.is_some());
+        assert!(!game.thumbnails.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn music() -> Result<(), Box<dyn std::error::Error>> {
+        let video = Client::new().video("9bZkp7q19f0".parse()?).await?;
+        let rich_metadata = video.rich_metadata();
+        let music = rich_metadata
+            .iter()
+            .find(|x| x.kind == RichMetadataKind::Music)
+            .expect("No music found");
+        assert!(!music.title.is_empty());
+        assert!(rich_metadata
+            .iter()
+            .all(|x| x.kind != RichMetadataKind::Game));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn none() -> Result<(), Box<dyn std::error::Error>> {
+        let video = Client::new().video("7B2PIVSWtJA".parse()?).await?;
+        assert!(video.rich_metadata().is_empty());
+        Ok(())
+    }
+}
+
+mod chapters {
+    use ytextract::Client;
+
+    #[tokio::test]
+    async fn chaptered() -> Result<(), Box<dyn std::error::Error>> {
+        let video = Client::new().video("rfscVS0vtbw".parse()?).await?;
+        let chapters = video.chapters();
+
+        assert!(chapters.len() > 1);
+        assert_eq!(chapters[0].start, std::time::Duration::ZERO);
+        assert!(chapters.windows(2).all(|x| x[0].start <= x[1].start));
+        assert!(chapters.iter().all(|x| !x.title.is_empty()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn none() -> Result<(), Box<dyn std::error::Error>> {
+        let video = Client::new().video("9bZkp7q19f0".parse()?).await?;
+        assert!(video.chapters().is_empty());
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn unknown_renderers() -> Result<(), Box<dyn std::error::Error>> {
+    use std::sync::{Arc, Mutex};
+
+    let reported = Arc::new(Mutex::new(Vec::new()));
+    let client = {
+        let reported = Arc::clone(&reported);
+        Client::builder()
+            .on_unknown_renderer(move |context, value| {
+                reported.lock().unwrap().push((context.to_string(), value));
+            })
+            .build()
+    };
+
+    let video = client.video("9bZkp7q19f0".parse()?).await?;
+    assert_eq!(video.id(), "9bZkp7q19f0".parse()?);
+
+    for (context, value) in reported.lock().unwrap().iter() {
+        assert!(context.starts_with("next."));
+        assert!(value.is_object());
+    }
+
+    Ok(())
+}
+
+mod date {
+    use ytextract::Client;
+
+    macro_rules! define_test {
+        ($fn:ident, $id:literal) => {
+            #[tokio::test]
+            async fn $fn() -> Result<(), Box<dyn std::error::Error>> {
+                let video = Client::new().video($id.parse()?).await?;
+                let today = chrono::Utc::now().date_naive();
+                assert!(video.date().expect("No date") <= today);
+                assert!(video.upload_date().expect("No upload date") <= today);
+                Ok(())
+            }
+        };
+    }
+
+    define_test!(normal, "7B2PIVSWtJA");
+    define_test!(premiere, "vv-Fqm6Qtj4");
+    define_test!(live_stream_recording, "rsAAeyAr-9Y");
+}
+
+#[tokio::test]
+async fn requested_id() -> Result<(), Box<dyn std::error::Error>> {
+    let id = "7B2PIVSWtJA".parse()?;
+    let client = Client::builder()
+        .id_mismatch(ytextract::video::IdMismatch::Error)
+        .build();
+
+    let video = client.video(id).await?;
+    assert_eq!(video.requested_id(), id);
+    assert_eq!(video.id(), id);
+    assert!(client.streams(id).await?.next().is_some());
 
     Ok(())
 }
+
+mod duration {
+    use ytextract::Client;
+
+    #[tokio::test]
+    async fn live_stream() -> Result<(), Box<dyn std::error::Error>> {
+        let video = Client::new().video("5qap5aO4i9A".parse()?).await?;
+        assert_eq!(video.duration(), None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn normal() -> Result<(), Box<dyn std::error::Error>> {
+        let video = Client::new().video("9bZkp7q19f0".parse()?).await?;
+        assert_eq!(
+            video.duration(),
+            Some(std::time::Duration::from_secs(4 * 60 + 12))
+        );
+        Ok(())
+    }
+}
+
+mod comments {
+    use futures::StreamExt;
+    use ytextract::Client;
+
+    #[tokio::test]
+    async fn popular() -> Result<(), Box<dyn std::error::Error>> {
+        let video = Client::new().video("9bZkp7q19f0".parse()?).await?;
+
+        let comments = video
+            .comments()
+            .take(40)
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+
+        assert_eq!(comments.len(), 40);
+        assert!(video.comment_count() > Some(1_000_000));
+        for comment in comments {
+            assert!(!comment.id().is_empty());
+            assert!(!comment.published().is_empty());
+            assert!(comment.likes().is_some());
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn disabled() -> Result<(), Box<dyn std::error::Error>> {
+        let video = Client::new().video("9Jg_Fwc0QOY".parse()?).await?;
+        assert_eq!(video.comments().count().await, 0);
+        assert_eq!(video.comment_count(), None);
+        Ok(())
+    }
+}
+
+mod captions {
+    use ytextract::{video::caption::CaptionCues, Client};
+
+    #[tokio::test]
+    async fn fetch() -> Result<(), Box<dyn std::error::Error>> {
+        let video = Client::new().video("YltHGKX80Y8".parse()?).await?;
+
+        let caption = video.captions().next().expect("No captions");
+        let cues = caption.fetch().await?;
+        assert!(!cues.is_empty());
+
+        let srt = cues.to_srt();
+        assert!(srt.starts_with("1\n00:"));
+        assert!(srt.contains(" --> "));
+
+        let vtt = cues.to_vtt();
+        assert!(vtt.starts_with("WEBVTT\n\n"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn translated() -> Result<(), Box<dyn std::error::Error>> {
+        let video = Client::new().video("YltHGKX80Y8".parse()?).await?;
+
+        let caption = video
+            .captions()
+            .find(|x| x.is_translatable())
+            .expect("No translatable captions");
+        assert!(!caption.fetch_translated("de").await?.is_empty());
+
+        Ok(())
+    }
+}
+
+mod counts {
+    use ytextract::{video::Counts, Client};
+
+    #[tokio::test]
+    async fn get() -> Result<(), Box<dyn std::error::Error>> {
+        let before = chrono::Utc::now();
+        let video = Client::new().video("9bZkp7q19f0".parse()?).await?;
+        let counts = video.counts();
+
+        assert_eq!(counts.views, video.views());
+        assert_eq!(counts.likes, video.likes());
+        assert_eq!(counts.comments, video.comment_count());
+        assert_eq!(counts.channel_subscribers, video.channel().subscribers());
+        assert!(counts.observed_at >= before);
+
+        Ok(())
+    }
+
+    #[test]
+    fn serde() -> Result<(), Box<dyn std::error::Error>> {
+        let counts = Counts {
+            views: 4_000_000_000,
+            likes: Some(27_000_000),
+            comments: None,
+            channel_subscribers: Some(18_000_000),
+            observed_at: "2021-06-23T12:00:00Z".parse()?,
+        };
+
+        let json = serde_json::to_value(&counts)?;
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "views": 4_000_000_000u64,
+                "likes": 27_000_000,
+                "comments": null,
+                "channel_subscribers": 18_000_000,
+                "observed_at": "2021-06-23T12:00:00Z",
+            })
+        );
+        assert_eq!(serde_json::from_value::<Counts>(json)?, counts);
+
+        Ok(())
+    }
+}
+
+mod transcript {
+    use assert_matches::assert_matches;
+    use ytextract::{error::Youtube, Client, Error};
+
+    #[tokio::test]
+    async fn chapters() -> Result<(), Box<dyn std::error::Error>> {
+        let video = Client::new().video("rfscVS0vtbw".parse()?).await?;
+
+        let transcript = video.transcript().await?;
+        assert!(!transcript.is_empty());
+        assert!(transcript.windows(2).all(|x| x[0].start <= x[1].start));
+        assert!(transcript.iter().any(|x| x.chapter.is_some()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unavailable() -> Result<(), Box<dyn std::error::Error>> {
+        let video = Client::new().video("5qap5aO4i9A".parse()?).await?;
+
+        assert_matches!(
+            video.transcript().await,
+            Err(Error::Youtube(Youtube::TranscriptUnavailable))
+        );
+
+        Ok(())
+    }
+}
+
+mod music {
+    use ytextract::Client;
+
+    #[tokio::test]
+    async fn music_video() -> Result<(), Box<dyn std::error::Error>> {
+        let video = Client::new().video("9bZkp7q19f0".parse()?).await?;
+
+        let music = video.music().expect("No music metadata");
+        assert!(music.song.is_some());
+        assert!(music.artist.is_some());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn none() -> Result<(), Box<dyn std::error::Error>> {
+        let video = Client::new().video("7B2PIVSWtJA".parse()?).await?;
+        assert_eq!(video.music(), None);
+        Ok(())
+    }
+}
+
+mod live {
+    use ytextract::Client;
+
+    #[tokio::test]
+    async fn live_stream() -> Result<(), Box<dyn std::error::Error>> {
+        let video = Client::new().video("5qap5aO4i9A".parse()?).await?;
+
+        let details = video.live_details().expect("No live details");
+        assert!(details.is_live_now);
+        assert_eq!(details.ended_at, None);
+        assert!(details.concurrent_viewers.is_some());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn recording() -> Result<(), Box<dyn std::error::Error>> {
+        let video = Client::new().video("rsAAeyAr-9Y".parse()?).await?;
+
+        let details = video.live_details().expect("No live details");
+        assert!(!details.is_live_now);
+        assert!(details.ended_at > Some(details.started_at));
+        assert_eq!(details.concurrent_viewers, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn not_live() -> Result<(), Box<dyn std::error::Error>> {
+        let video = Client::new().video("7B2PIVSWtJA".parse()?).await?;
+        assert_eq!(video.live_details(), None);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn streams_downloadable() -> Result<(), Box<dyn std::error::Error>> {
+    let video = Client::new().video("9bZkp7q19f0".parse()?).await?;
+
+    let stream = video
+        .streams()
+        .await?
+        .filter(|x| x.is_audio())
+        .min_by_key(|x| x.bitrate())
+        .expect("No audio stream");
+
+    let path = std::env::temp_dir().join("ytextract-video-streams-test");
+    let _ = std::fs::remove_file(&path);
+
+    let written = stream
+        .download(&path, &ytextract::stream::DownloadOptions::new())
+        .await?;
+    assert_eq!(written, stream.content_length().await?);
+
+    std::fs::remove_file(&path)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn refresh() -> Result<(), Box<dyn std::error::Error>> {
+    let mut video = Client::new().video("7B2PIVSWtJA".parse()?).await?;
+
+    let previous = video.refresh().await?;
+
+    assert_eq!(previous, video);
+    assert!(video.views() >= previous.views());
+    assert!(video.fetched_at() >= previous.fetched_at());
+    assert_eq!(video.published(), previous.published());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn is_short() -> Result<(), Box<dyn std::error::Error>> {
+    let video = Client::new().video("7B2PIVSWtJA".parse()?).await?;
+
+    assert!(!video.is_short().await?);
+    // The result is cached
+    assert!(!video.is_short().await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn age_restricted() -> Result<(), Box<dyn std::error::Error>> {
+    let video = Client::new().video("SkRSXFQerZs".parse()?).await?;
+
+    assert!(video.age_restricted());
+    assert_eq!(video.family_safe(), Some(false));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn end_screen() -> Result<(), Box<dyn std::error::Error>> {
+    let video = Client::new().video("7B2PIVSWtJA".parse()?).await?;
+
+    let elements = video.end_screen();
+    assert!(!elements.is_empty());
+    for element in elements {
+        assert!(element.start() <= element.end());
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn cards() -> Result<(), Box<dyn std::error::Error>> {
+    let video = Client::new().video("7B2PIVSWtJA".parse()?).await?;
+
+    let duration = video.duration().expect("No duration");
+    for card in video.cards() {
+        assert!(card.cue() <= duration);
+    }
+
+    Ok(())
+}
+
+mod availability {
+    use ytextract::{video::Availability, Client};
+
+    #[tokio::test]
+    async fn playable() -> Result<(), Box<dyn std::error::Error>> {
+        let availability = Client::new().is_available("9bZkp7q19f0".parse()?).await?;
+        assert_eq!(availability, Availability::Playable);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn nonexistent() -> Result<(), Box<dyn std::error::Error>> {
+        let availability = Client::new().is_available("aaaaaaaaaaa".parse()?).await?;
+        assert!(matches!(availability, Availability::Unplayable(_)));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn bulk() -> Result<(), Box<dyn std::error::Error>> {
+        let playable = "9bZkp7q19f0".parse()?;
+        let nonexistent = "aaaaaaaaaaa".parse()?;
+
+        let availability = Client::new()
+            .are_available([playable, nonexistent, playable], 2)
+            .await;
+
+        assert_eq!(availability.len(), 2);
+        assert_eq!(
+            availability[&playable].as_ref().ok(),
+            Some(&Availability::Playable)
+        );
+        assert!(matches!(
+            availability[&nonexistent],
+            Ok(Availability::Unplayable(_))
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn bulk_no_concurrency() -> Result<(), Box<dyn std::error::Error>> {
+        let id = "9bZkp7q19f0".parse()?;
+
+        let availability = Client::new().are_available([id, id], 0).await;
+
+        assert_eq!(availability.len(), 1);
+        assert!(availability.contains_key(&id));
+        Ok(())
+    }
+}
+
+#[cfg(feature = "unstable")]
+mod parts {
+    use serde_json::json;
+    use ytextract::{Client, Video};
+
+    #[cfg(feature = "serde")]
+    use crate::common::round_trip;
+    use crate::common::{
+        next_response, parts, player_response, serve_api, video, watch_next, CHANNEL, PLAYLIST,
+        VIDEO,
+    };
+
+    #[test]
+    fn get() -> Result<(), Box<dyn std::error::Error>> {
+        let video = video(player_response(), next_response(VIDEO))?;
+
+        assert_eq!(video.id(), VIDEO.parse()?);
+        assert_eq!(
+            video.title(),
+            "I Sent Corridor Digital the WORST VFX Workstation"
+        );
+        assert_eq!(video.duration(), Some(std::time::Duration::from_secs(1358)));
+        assert_eq!(video.channel().id(), CHANNEL.parse()?);
+        assert_eq!(video.views(), 1_068_917);
+        assert_eq!(video.category(), Some("Science & Technology"));
+        assert_eq!(video.date(), chrono::NaiveDate::from_ymd_opt(2021, 6, 22));
+        assert_eq!(video.family_safe(), Some(true));
+        assert!(video.related().is_none());
+
+        assert_eq!(video.published(), video.date());
+        assert!(video.fetched_at() <= chrono::Utc::now());
+
+        #[cfg(feature = "serde")]
+        {
+            let snapshot = video.snapshot();
+            assert_eq!(snapshot.id, video.id());
+            assert_eq!(snapshot.fetched_at, video.fetched_at());
+            assert_eq!(snapshot.channel.id, CHANNEL.parse()?);
+            round_trip(snapshot)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn age_restricted() -> Result<(), Box<dyn std::error::Error>> {
+        // Not family safe, but playable without confirming
+        let mut player = player_response();
+        player["microformat"]["playerMicroformatRenderer"]["isFamilySafe"] = false.into();
+        let not_family_safe = video(player, next_response(VIDEO))?;
+        assert_eq!(not_family_safe.family_safe(), Some(false));
+        assert!(!not_family_safe.age_restricted());
+
+        let mut player = player_response();
+        player["playabilityStatus"]["desktopLegacyAgeGateReason"] = 1.into();
+        assert!(video(player, next_response(VIDEO))?.age_restricted());
+
+        Ok(())
+    }
+
+    #[test]
+    fn topic_channel() -> Result<(), Box<dyn std::error::Error>> {
+        let channel_name = |author: &str| -> Result<_, Box<dyn std::error::Error>> {
+            let mut player = player_response();
+            player["videoDetails"]["author"] = json!(author);
+            let video = video(player, next_response(VIDEO))?;
+            let channel = video.channel();
+            Ok((
+                channel.name().to_string(),
+                channel.display_name().to_string(),
+                channel.is_auto_generated(),
+            ))
+        };
+
+        assert_eq!(
+            channel_name("Daft Punk - Topic")?,
+            ("Daft Punk - Topic".into(), "Daft Punk".into(), true)
+        );
+        assert_eq!(
+            channel_name("Linus Tech Tips")?,
+            ("Linus Tech Tips".into(), "Linus Tech Tips".into(), false)
+        );
+        assert_eq!(
+            channel_name("Off Topic")?,
+            ("Off Topic".into(), "Off Topic".into(), false)
+        );
+        assert_eq!(
+            channel_name("The-Topic")?,
+            ("The-Topic".into(), "The-Topic".into(), false)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn location() -> Result<(), Box<dyn std::error::Error>> {
+        let next = watch_next(json!([{
+            "videoSecondaryInfoRenderer": {
+                "owner": { "videoOwnerRenderer": { "thumbnail": { "thumbnails": [] } } },
+                "metadataRowContainer": {
+                    "metadataRowContainerRenderer": {
+                        "rows": [{
+                            "metadataRowRenderer": {
+                                "title": { "simpleText": "Location" },
+                                "contents": [{ "runs": [{ "text": "Tokyo" }] }]
+                            }
+                        }]
+                    }
+                }
+            }
+        }]));
+        assert_eq!(video(player_response(), next)?.location(), Some("Tokyo"));
+
+        let video = video(player_response(), next_response(VIDEO))?;
+        assert_eq!(video.location(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn likes_entity() -> Result<(), Box<dyn std::error::Error>> {
+        let likes =
+            |like_count_entity: serde_json::Value| -> Result<_, Box<dyn std::error::Error>> {
+                let mut next = watch_next(json!([{
+                    "videoPrimaryInfoRenderer": {
+                        "videoActions": {
+                            "menuRenderer": {
+                                "topLevelButtons": [{
+                                    "segmentedLikeDislikeButtonViewModel": {
+                                        "likeCountEntity": { "key": "like-key" }
+                                    }
+                                }]
+                            }
+                        },
+                        "dateText": { "simpleText": "Jun 22, 2021" }
+                    }
+                }]));
+                next["frameworkUpdates"] = json!({
+                    "entityBatchUpdate": {
+                        "mutations": [
+                            {
+                                "entityKey": "other-key",
+                                "type": "ENTITY_MUTATION_TYPE_REPLACE",
+                                "payload": { "likeStatusEntity": { "likeStatus": "INDIFFERENT" } }
+                            },
+                            {
+                                "entityKey": "like-key",
+                                "type": "ENTITY_MUTATION_TYPE_REPLACE",
+                                "payload": { "likeCountEntity": like_count_entity }
+                            },
+                            { "entityKey": "deleted-key", "type": "ENTITY_MUTATION_TYPE_DELETE" }
+                        ]
+                    }
+                });
+                Ok(video(player_response(), next)?.likes())
+            };
+
+        assert_eq!(
+            likes(json!({
+                "likeCountIfIndifferentNumber": "4457",
+                "expandedLikeCountIfIndifferent": { "content": "4,457" },
+                "likeCountIfLiked": { "content": "4.4K" }
+            }))?,
+            Some(4457)
+        );
+        assert_eq!(
+            likes(json!({ "expandedLikeCountIfIndifferent": { "content": "4.457" } }))?,
+            Some(4457)
+        );
+        assert_eq!(likes(json!({}))?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn likes_label() -> Result<(), Box<dyn std::error::Error>> {
+        let likes = |label: &str| -> Result<_, Box<dyn std::error::Error>> {
+            let next = watch_next(json!([{
+                "videoPrimaryInfoRenderer": {
+                    "videoActions": {
+                        "menuRenderer": {
+                            "topLevelButtons": [{
+                                "toggleButtonRenderer": { "accessibility": { "label": label } }
+                            }]
+                        }
+                    },
+                    "dateText": { "simpleText": "Jun 22, 2021" }
+                }
+            }]));
+            Ok(video(player_response(), next)?.likes())
+        };
+
+        assert_eq!(likes("4,457 likes")?, Some(4457));
+        assert_eq!(likes("4.457 „Mag ich“-Bewertungen")?, Some(4457));
+        // Abbreviated counts are not read as their digits
+        assert_eq!(likes("1.2M likes")?, Some(1_200_000));
+        assert_eq!(likes("1,2 Mio. „Mag ich“-Bewertungen")?, None);
+        assert_eq!(likes("I like this")?, None);
+
+        Ok(())
+    }
+
+    /// [`player_response`] without a machine readable publish date.
+    fn player_without_date() -> serde_json::Value {
+        let mut player = player_response();
+        player["microformat"]["playerMicroformatRenderer"]
+            .as_object_mut()
+            .unwrap()
+            .remove("publishDate");
+        player
+    }
+
+    #[test]
+    fn watch_next_web() -> Result<(), Box<dyn std::error::Error>> {
+        let next = watch_next(json!([
+            {
+                "videoPrimaryInfoRenderer": {
+                    "videoActions": {
+                        "menuRenderer": {
+                            "topLevelButtons": [{
+                                "toggleButtonRenderer": {
+                                    "accessibility": { "label": "4,457 likes" }
+                                }
+                            }]
+                        }
+                    },
+                    "superTitleLink": { "runs": [{ "text": "#vfx" }] },
+                    "dateText": { "simpleText": "Jun 22, 2021" },
+                    "viewCount": {
+                        "videoViewCountRenderer": {
+                            "viewCount": { "simpleText": "1,234 waiting" }
+                        }
+                    }
+                }
+            },
+            {
+                "videoSecondaryInfoRenderer": {
+                    "owner": {
+                        "videoOwnerRenderer": {
+                            "thumbnail": {
+                                "thumbnails": [{
+                                    "url": "https://yt3.ggpht.com/avatar=s48",
+                                    "width": 48,
+                                    "height": 48
+                                }]
+                            },
+                            "subscriberCountText": { "simpleText": "13.8M subscribers" }
+                        }
+                    }
+                }
+            }
+        ]));
+        let video = video(player_without_date(), next)?;
+
+        assert_eq!(video.likes(), Some(4457));
+        assert_eq!(video.date(), chrono::NaiveDate::from_ymd_opt(2021, 6, 22));
+        assert_eq!(video.channel().subscribers(), Some(13_800_000));
+        assert_eq!(video.channel().thumbnails().count(), 1);
+        assert_eq!(video.hashtags().collect::<Vec<_>>(), ["#vfx"]);
+        assert_eq!(video.waiting_count(), Some(1234));
+
+        Ok(())
+    }
+
+    #[test]
+    fn date_text() -> Result<(), Box<dyn std::error::Error>> {
+        // Premieres and past livestreams without a machine readable publish date
+        for date_text in [
+            "Jun 22, 2021",
+            "Premiered Jun 22, 2021",
+            "Streamed live on Jun 22, 2021",
+        ] {
+            let next = watch_next(json!([{
+                "videoPrimaryInfoRenderer": {
+                    "videoActions": { "menuRenderer": { "topLevelButtons": [] } },
+                    "dateText": { "simpleText": date_text }
+                }
+            }]));
+            let video = video(player_without_date(), next)?;
+
+            assert_eq!(
+                video.date(),
+                chrono::NaiveDate::from_ymd_opt(2021, 6, 22),
+                "{}",
+                date_text
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn watch_next_android() -> Result<(), Box<dyn std::error::Error>> {
+        let next = json!({
+            "contents": {
+                "singleColumnWatchNextResults": {
+                    "results": {
+                        "results": {
+                            "contents": [{
+                                "slimVideoMetadataSectionRenderer": {
+                                    "contents": [
+                                        {
+                                            "slimVideoInformationRenderer": {
+                                                "dateText": { "runs": [{ "text": "Jun 22, 2021" }] }
+                                            }
+                                        },
+                                        {
+                                            "slimVideoActionBarRenderer": {
+                                                "buttons": [{
+                                                    "slimMetadataToggleButtonRenderer": {
+                                                        "isLike": true,
+                                                        "button": {
+                                                            "toggleButtonRenderer": {
+                                                                "defaultText": {
+                                                                    "accessibility": {
+                                                                        "accessibilityData": {
+                                                                            "label": "4,457 likes"
+                                                                        }
+                                                                    }
+                                                                }
+                                                            }
+                                                        }
+                                                    }
+                                                }]
+                                            }
+                                        },
+                                        {
+                                            "slimOwnerRenderer": {
+                                                "thumbnail": { "thumbnails": [] },
+                                                "expandedSubtitle": {
+                                                    "runs": [{ "text": "13.8M subscribers" }]
+                                                }
+                                            }
+                                        },
+                                        { "slimVideoDescriptionRenderer": { "description": {} } }
+                                    ]
+                                }
+                            }]
+                        }
+                    }
+                }
+            }
+        });
+        let video = video(player_without_date(), next)?;
+
+        assert_eq!(video.likes(), Some(4457));
+        assert_eq!(video.date(), chrono::NaiveDate::from_ymd_opt(2021, 6, 22));
+        assert_eq!(video.channel().subscribers(), Some(13_800_000));
+        // Only shown by the WEB client
+        assert_eq!(video.hashtags().count(), 0);
+        assert_eq!(video.waiting_count(), None);
+        assert!(video.rich_metadata().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn watch_next_incomplete() -> Result<(), Box<dyn std::error::Error>> {
+        // Neither the primary nor the secondary info is present
+        let video = video(player_response(), watch_next(json!([])))?;
+
+        assert_eq!(video.likes(), None);
+        assert_eq!(video.channel().subscribers(), None);
+        assert_eq!(video.hashtags().count(), 0);
+        assert_eq!(video.waiting_count(), None);
+        assert!(video.rich_metadata().is_empty());
+        assert!(video.live_details().is_none());
+        assert!(!format!("{:?}", video).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn date_unknown() -> Result<(), Box<dyn std::error::Error>> {
+        // No publish date and no watch page to fall back to
+        let mut player = player_response();
+        player.as_object_mut().unwrap().remove("microformat");
+        let video = video(player, next_response(VIDEO))?;
+
+        assert_eq!(video.date(), None);
+        assert_eq!(video.published(), None);
+        assert!(!format!("{:?}", video).is_empty());
+
+        #[cfg(feature = "serde")]
+        {
+            let snapshot = video.snapshot();
+            assert_eq!(snapshot.date, None);
+            round_trip(snapshot)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn comment_count() -> Result<(), Box<dyn std::error::Error>> {
+        let comment_count = |text: &str| -> Result<_, Box<dyn std::error::Error>> {
+            let mut next = next_response(VIDEO);
+            next["engagementPanels"] = json!([{
+                "engagementPanelSectionListRenderer": {
+                    "panelIdentifier": "engagement-panel-comments-section",
+                    "header": {
+                        "engagementPanelTitleHeaderRenderer": {
+                            "contextualInfo": { "runs": [{ "text": text }] }
+                        }
+                    }
+                }
+            }]);
+            Ok(video(player_response(), next)?.comment_count())
+        };
+
+        assert_eq!(comment_count("4,457")?, Some(4457));
+        assert_eq!(comment_count("1.2K")?, Some(1200));
+        assert_eq!(comment_count("")?, None);
+        assert_eq!(comment_count("Comments are turned off")?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn paid_promotion() -> Result<(), Box<dyn std::error::Error>> {
+        let has_paid_promotion = |overlay: Option<serde_json::Value>| {
+            let mut player = player_response();
+            if let Some(overlay) = overlay {
+                player["paidContentOverlay"] = overlay;
+            }
+            video(player, next_response(VIDEO)).map(|x| x.has_paid_promotion())
+        };
+
+        assert!(has_paid_promotion(Some(json!({
+            "paidContentOverlayRenderer": {
+                "text": { "runs": [{ "text": "Includes paid promotion" }] },
+                "durationMs": "10000"
+            }
+        })))?);
+        assert!(!has_paid_promotion(Some(json!({})))?);
+        assert!(!has_paid_promotion(None)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn cards() -> Result<(), Box<dyn std::error::Error>> {
+        use std::time::Duration;
+        use ytextract::video::Card;
+
+        let card = |cue: &str, content: serde_json::Value| {
+            json!({
+                "cardRenderer": {
+                    "teaser": {
+                        "simpleCardTeaserRenderer": { "message": { "simpleText": "Teaser" } }
+                    },
+                    "cueRanges": [{ "startCardActiveMs": cue }],
+                    "content": content
+                }
+            })
+        };
+
+        let mut player = player_response();
+        player["cards"] = json!({
+            "cardCollectionRenderer": {
+                "cards": [
+                    card("1000", json!({
+                        "videoInfoCardContentRenderer": {
+                            "action": { "watchEndpoint": { "videoId": "dQw4w9WgXcQ" } },
+                            "videoTitle": { "simpleText": "A video" }
+                        }
+                    })),
+                    card("2000", json!({
+                        "playlistInfoCardContentRenderer": {
+                            "action": { "watchEndpoint": { "playlistId": PLAYLIST } },
+                            "playlistTitle": { "simpleText": "A playlist" }
+                        }
+                    })),
+                    card("3000", json!({
+                        "simpleCardContentRenderer": {
+                            "command": { "urlEndpoint": { "url": "https://example.com/" } },
+                            "title": { "simpleText": "A link" }
+                        }
+                    })),
+                    card("4000", json!({ "pollRenderer": {} })),
+                    card("5000", json!({ "collaboratorInfoCardContentRenderer": {} })),
+                ]
+            }
+        });
+        let cards = video(player, next_response(VIDEO))?.cards();
+
+        assert!(!cards.is_empty());
+        assert_eq!(
+            cards,
+            [
+                Card::Video {
+                    id: "dQw4w9WgXcQ".parse()?,
+                    title: "A video".into(),
+                    teaser: "Teaser".into(),
+                    cue: Duration::from_secs(1),
+                },
+                Card::Playlist {
+                    id: PLAYLIST.parse()?,
+                    title: "A playlist".into(),
+                    teaser: "Teaser".into(),
+                    cue: Duration::from_secs(2),
+                },
+                Card::Link {
+                    url: "https://example.com/".parse()?,
+                    title: "A link".into(),
+                    teaser: "Teaser".into(),
+                    cue: Duration::from_secs(3),
+                },
+                Card::Poll {
+                    teaser: "Teaser".into(),
+                    cue: Duration::from_secs(4),
+                },
+                Card::Other {
+                    teaser: "Teaser".into(),
+                    cue: Duration::from_secs(5),
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn malformed_cards() -> Result<(), Box<dyn std::error::Error>> {
+        let with = |cards: serde_json::Value, endscreen: serde_json::Value| {
+            let mut player = player_response();
+            player["cards"] = cards;
+            player["endscreen"] = endscreen;
+            video(player, next_response(VIDEO))
+        };
+
+        // Malformed items are skipped, the others are kept
+        let video = with(
+            json!({
+                "cardCollectionRenderer": {
+                    "cards": [
+                        { "cardRenderer": { "cueRanges": [{ "startCardActiveMs": "soon" }] } },
+                        { "cardRenderer": { "cueRanges": [{ "startCardActiveMs": "1000" }] } }
+                    ]
+                }
+            }),
+            json!({
+                "endscreenRenderer": {
+                    "elements": [
+                        { "endscreenElementRenderer": { "style": "VIDEO" } },
+                        {
+                            "endscreenElementRenderer": {
+                                "style": "WEBSITE",
+                                "startMs": "1000",
+                                "endMs": "2000"
+                            }
+                        }
+                    ]
+                }
+            }),
+        )?;
+        assert_eq!(video.cards().len(), 1);
+        assert_eq!(video.end_screen().len(), 1);
+
+        // So are malformed containers
+        let video = with(json!({ "cards": [] }), json!([]))?;
+        assert!(video.cards().is_empty());
+        assert!(video.end_screen().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_renderers() -> Result<(), Box<dyn std::error::Error>> {
+        use std::sync::{Arc, Mutex};
+
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let client = {
+            let reported = Arc::clone(&reported);
+            Client::builder()
+                .on_unknown_renderer(move |context, value| {
+                    reported.lock().unwrap().push((context.to_string(), value));
+                })
+                .build()
+        };
+
+        let next = watch_next(json!([
+            { "someNewRenderer": { "title": { "simpleText": "New" } } },
+            {
+                "videoPrimaryInfoRenderer": {
+                    "videoActions": { "menuRenderer": { "topLevelButtons": [] } },
+                    "dateText": { "simpleText": "Jun 22, 2021" }
+                }
+            },
+            {
+                "videoSecondaryInfoRenderer": {
+                    "owner": {
+                        "videoOwnerRenderer": {
+                            "thumbnail": { "thumbnails": [] },
+                            "subscriberCountText": { "simpleText": "13.8M subscribers" }
+                        }
+                    }
+                }
+            }
+        ]));
+        let video = Video::from_parts(parts(player_response(), next.clone()), client)?;
+
+        // The unknown renderer is skipped, the known ones still parse
+        assert_eq!(video.channel().subscribers(), Some(13_800_000));
+        assert_eq!(
+            *reported.lock().unwrap(),
+            [(
+                String::from("next.results"),
+                json!({ "someNewRenderer": { "title": { "simpleText": "New" } } })
+            )]
+        );
+
+        // Without a hook, unknown renderers are skipped all the same
+        let video = Video::from_parts(parts(player_response(), next), Client::new())?;
+        assert_eq!(video.channel().subscribers(), Some(13_800_000));
+
+        Ok(())
+    }
+
+    #[test]
+    fn mismatch() {
+        assert!(matches!(
+            video(player_response(), next_response("9bZkp7q19f0")),
+            Err(ytextract::Error::IdMismatch { .. })
+        ));
+
+        // The ANDROID response carries the id as well
+        let next = json!({
+            "contents": {
+                "singleColumnWatchNextResults": { "results": { "results": { "contents": [] } } }
+            },
+            "currentVideoEndpoint": { "watchEndpoint": { "videoId": "9bZkp7q19f0" } }
+        });
+        assert!(matches!(
+            video(player_response(), next),
+            Err(ytextract::Error::IdMismatch { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn related_continuation() -> Result<(), Box<dyn std::error::Error>> {
+        use futures::StreamExt;
+        use ytextract::video::Related;
+
+        let radio = |id: &str| {
+            json!({
+                "compactRadioRenderer": {
+                    "playlistId": id,
+                    "thumbnail": { "thumbnails": [] },
+                    "title": { "simpleText": id }
+                }
+            })
+        };
+
+        let (url, requests) = serve_api(move |_, _| {
+            json!({
+                "onResponseReceivedEndpoints": [{
+                    "appendContinuationItemsAction": { "continuationItems": [radio("RD3")] }
+                }]
+            })
+        });
+        let mut next = watch_next(json!([]));
+        next["contents"]["twoColumnWatchNextResults"]["secondaryResults"] = json!({
+            "secondaryResults": {
+                "results": [
+                    radio("RD1"),
+                    {
+                        "continuationItemRenderer": {
+                            "continuationEndpoint": { "continuationCommand": { "token": "next" } }
+                        }
+                    },
+                    radio("RD2"),
+                ]
+            }
+        });
+        let client = Client::builder().api_url(url).build();
+        let video = Video::from_parts(parts(player_response(), next), client)?;
+
+        // The items after the continuation are yielded before its items
+        let related: Vec<_> = video.related().expect("No related items").collect().await;
+        let titles: Vec<_> = related
+            .iter()
+            .map(|x| match x {
+                Ok(Related::Radio(radio)) => radio.title().to_string(),
+                _ => panic!("Expected a radio"),
+            })
+            .collect();
+        assert_eq!(titles, ["RD1", "RD2", "RD3"]);
+
+        let (_, body) = requests.recv()?;
+        assert_eq!(body["continuation"], "next");
+        assert!(requests.try_recv().is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn mismatch_policy() -> Result<(), Box<dyn std::error::Error>> {
+        use ytextract::video::IdMismatch;
+
+        let requested: ytextract::video::Id = "9bZkp7q19f0".parse()?;
+        let returned: ytextract::video::Id = VIDEO.parse()?;
+
+        // YouTube answers with `VIDEO` for every id
+        let client = |policy| {
+            let (url, requests) = serve_api(|endpoint, _| match endpoint {
+                "player" => player_response(),
+                _ => next_response(VIDEO),
+            });
+            let client = Client::builder().api_url(url).id_mismatch(policy).build();
+            (client, requests)
+        };
+
+        let (follow, requests) = client(IdMismatch::Follow);
+        let video = follow.video(requested).await?;
+        assert_eq!(video.id(), returned);
+        assert_eq!(video.requested_id(), requested);
+        // The rest of the video is requested for the returned id
+        let next: Vec<_> = requests
+            .try_iter()
+            .filter(|(endpoint, _)| endpoint == "next")
+            .collect();
+        assert!(!next.is_empty());
+        assert!(next.iter().all(|(_, body)| body["videoId"] == VIDEO));
+
+        let (error, _) = client(IdMismatch::Error);
+        assert!(matches!(
+            error.video(requested).await,
+            Err(ytextract::Error::IdMismatch { requested: r, returned: x }) if r == requested && x == returned
+        ));
+
+        // Responses fetched elsewhere have no requested id, their parts have to
+        // agree regardless of the policy
+        for policy in [IdMismatch::Follow, IdMismatch::Error] {
+            let mut player = player_response();
+            player["videoDetails"]["videoId"] = json!(requested.to_string());
+            let client = Client::builder().id_mismatch(policy).build();
+            assert!(matches!(
+                Video::from_parts(parts(player, next_response(VIDEO)), client),
+                Err(ytextract::Error::IdMismatch { requested: r, returned: x }) if r == requested && x == returned
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn coalesced() -> Result<(), Box<dyn std::error::Error>> {
+        let (url, received) = serve_api(|endpoint, _| {
+            // Keep the requests in flight long enough to overlap
+            std::thread::sleep(std::time::Duration::from_millis(200));
+            match endpoint {
+                "player" => player_response(),
+                _ => next_response(VIDEO),
+            }
+        });
+        let client = Client::builder().api_url(url).build();
+        let id: ytextract::video::Id = VIDEO.parse()?;
+
+        let videos = futures::future::join_all((0..20).map(|_| client.video(id)))
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(videos.len(), 20);
+        assert!(videos.iter().all(|x| x.id() == id));
+
+        // Every distinct request was only sent once
+        let requests: Vec<_> = received.try_iter().collect();
+        let player = |requests: &[(String, serde_json::Value)]| {
+            requests
+                .iter()
+                .filter(|(endpoint, _)| endpoint == "player")
+                .count()
+        };
+        assert_eq!(player(&requests), 1);
+        for (i, request) in requests.iter().enumerate() {
+            assert!(!requests[i + 1..].contains(request));
+        }
+
+        // Requests that are no longer in flight are sent again
+        client.video(id).await?;
+        assert_eq!(player(&received.try_iter().collect::<Vec<_>>()), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn timeout() -> Result<(), Box<dyn std::error::Error>> {
+        use std::error::Error as _;
+
+        // Accepts connections, but never answers
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let url = format!("http://{}/", listener.local_addr()?).parse()?;
+        std::thread::spawn(move || {
+            let connections: Vec<_> = listener.incoming().collect();
+            drop(connections);
+        });
+
+        let client = Client::builder()
+            .api_url(url)
+            .timeout(std::time::Duration::from_millis(100))
+            .build();
+        let err = client.video(VIDEO.parse()?).await.unwrap_err();
+
+        match &err {
+            ytextract::Error::Timeout {
+                endpoint, attempts, ..
+            } => {
+                assert_eq!(*endpoint, "player");
+                assert_eq!(*attempts, 6);
+            }
+            err => panic!("Expected a timeout, got {:?}", err),
+        }
+        // The error of the last attempt is shared with coalesced requests
+        let source = err.source().expect("No source");
+        assert!(source
+            .downcast_ref::<std::sync::Arc<reqwest::Error>>()
+            .expect("Source is not a reqwest::Error")
+            .is_timeout());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn is_short() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::{BufRead, Write};
+
+        // Serves Shorts as they are, redirects regular videos to their watch page
+        // and everything else to a consent page
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let url = format!("http://{}/", listener.local_addr()?).parse()?;
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = std::io::BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                while reader.read_line(&mut request).unwrap() > 2 {}
+
+                let path = request.split(' ').nth(1).unwrap_or_default().to_string();
+                let location = match path.strip_prefix("/shorts/") {
+                    Some(VIDEO) | None => None,
+                    Some("9bZkp7q19f0") => Some("/watch?v=9bZkp7q19f0".to_string()),
+                    Some(id) => Some(format!("/consent/m?continue=/shorts/{}", id)),
+                };
+                let _ = match location {
+                    Some(location) => write!(
+                        stream,
+                        "HTTP/1.1 303 See Other\r\nLocation: {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                        location
+                    ),
+                    None => write!(
+                        stream,
+                        "HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    ),
+                };
+            }
+        });
+
+        let client = Client::builder().web_url(url).build();
+        let video = |id: &str| {
+            let mut player = player_response();
+            player["videoDetails"]["videoId"] = json!(id);
+            Video::from_parts(parts(player, next_response(id)), client.clone())
+        };
+
+        let short = video(VIDEO)?;
+        assert!(short.is_short().await?);
+        // The result is cached
+        assert!(short.is_short().await?);
+
+        assert!(!video("9bZkp7q19f0")?.is_short().await?);
+
+        // A consent page does not tell either way
+        assert!(matches!(
+            video("dQw4w9WgXcQ")?.is_short().await,
+            Err(ytextract::Error::Youtube(ytextract::error::Youtube::UnexpectedRedirect(url)))
+                if url.path() == "/consent/m"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn unavailable() {
+        let player = json!({
+            "playabilityStatus": { "status": "ERROR", "reason": "Video unavailable" }
+        });
+
+        assert!(matches!(
+            video(player, json!({})),
+            Err(ytextract::Error::Youtube(_))
+        ));
+    }
+
+    #[test]
+    fn malformed() {
+        let malformed = |live: bool| {
+            let mut player = player_response();
+            player["videoDetails"]
+                .as_object_mut()
+                .unwrap()
+                .remove("title");
+            if live {
+                player["playabilityStatus"]["liveStreamability"] =
+                    json!({ "liveStreamabilityRenderer": {} });
+            }
+            video(player, next_response(VIDEO))
+        };
+
+        // A playable response is malformed, not a error from YouTube
+        assert!(matches!(
+            malformed(false),
+            Err(ytextract::Error::Deserialization { .. })
+        ));
+        assert!(matches!(
+            malformed(true),
+            Err(ytextract::Error::Deserialization { .. })
+        ));
+    }
+}